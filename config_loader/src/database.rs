@@ -0,0 +1,101 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::REDACTED;
+
+#[derive(Clone, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct MssqlConfig {
+    pub host: String,
+    pub port: u16,
+    pub username: String,
+    pub password: String,
+    pub database: String,
+    pub pool_size: Option<u32>,
+    pub min_idle: Option<u32>,
+    /// In seconds
+    pub connection_timeout: Option<u64>,
+    /// In seconds; connections older than this are closed. `0` disables the limit.
+    pub max_lifetime: Option<u64>,
+    /// In seconds; idle connections beyond `min_idle` are closed after this. `0` disables it.
+    pub idle_timeout: Option<u64>,
+    /// TLS level to negotiate; unset keeps the driver default.
+    pub encryption: Option<EncryptionLevel>,
+    /// Accept the server certificate without validating it. Only for test servers.
+    #[serde(default)]
+    pub trust_cert: bool,
+}
+
+/// Encryption requested from SQL Server during login.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EncryptionLevel {
+    /// Encrypt only the login packet.
+    Off,
+    /// Encrypt everything if the server supports it.
+    On,
+    /// Do not encrypt anything.
+    NotSupported,
+    /// Encrypt everything and fail if the server cannot.
+    Required,
+}
+
+impl MssqlConfig {
+    /// Config for `database` on `host:port`, leaving pool settings at their defaults.
+    pub fn new(
+        host: impl Into<String>,
+        port: u16,
+        username: impl Into<String>,
+        password: impl Into<String>,
+        database: impl Into<String>,
+    ) -> Self {
+        Self {
+            host: host.into(),
+            port,
+            username: username.into(),
+            password: password.into(),
+            database: database.into(),
+            pool_size: None,
+            min_idle: None,
+            connection_timeout: None,
+            max_lifetime: None,
+            idle_timeout: None,
+            encryption: None,
+            trust_cert: false,
+        }
+    }
+}
+
+impl fmt::Debug for MssqlConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MssqlConfig")
+            .field("host", &self.host)
+            .field("port", &self.port)
+            .field("username", &self.username)
+            .field("password", &REDACTED)
+            .field("database", &self.database)
+            .field("pool_size", &self.pool_size)
+            .field("min_idle", &self.min_idle)
+            .field("connection_timeout", &self.connection_timeout)
+            .field("max_lifetime", &self.max_lifetime)
+            .field("idle_timeout", &self.idle_timeout)
+            .field("encryption", &self.encryption)
+            .field("trust_cert", &self.trust_cert)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debug_redacts_password() {
+        let config = MssqlConfig::new("localhost", 1433, "sa", "hunter2", "market");
+
+        let debug = format!("{:?}", config);
+        assert!(!debug.contains("hunter2"));
+        assert!(debug.contains("password: \"***\""));
+        assert!(debug.contains("username: \"sa\""));
+    }
+}
@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::REDACTED;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct KafkaConfig {
+    pub enabled: bool,
+    pub client_id: String,
+    pub servers: String,
+    /// librdkafka `security.protocol`, e.g. `sasl_ssl`.
+    #[serde(default)]
+    pub security_protocol: Option<String>,
+    #[serde(default)]
+    pub sasl: Option<KafkaSaslConfig>,
+}
+
+impl KafkaConfig {
+    /// Enabled config for `servers` without SASL.
+    pub fn new(client_id: impl Into<String>, servers: impl Into<String>) -> Self {
+        Self {
+            enabled: true,
+            client_id: client_id.into(),
+            servers: servers.into(),
+            security_protocol: None,
+            sasl: None,
+        }
+    }
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct KafkaSaslConfig {
+    /// librdkafka `sasl.mechanisms`, e.g. `SCRAM-SHA-512`.
+    pub mechanism: String,
+    pub username: String,
+    pub password: String,
+}
+
+impl KafkaSaslConfig {
+    pub fn new(
+        mechanism: impl Into<String>,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        Self {
+            mechanism: mechanism.into(),
+            username: username.into(),
+            password: password.into(),
+        }
+    }
+}
+
+impl fmt::Debug for KafkaSaslConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KafkaSaslConfig")
+            .field("mechanism", &self.mechanism)
+            .field("username", &self.username)
+            .field("password", &REDACTED)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debug_redacts_sasl_password() {
+        let mut config = KafkaConfig::new("app", "localhost:9092");
+        config.sasl = Some(KafkaSaslConfig::new("PLAIN", "svc", "hunter2"));
+
+        let debug = format!("{:?}", config);
+        assert!(!debug.contains("hunter2"));
+        assert!(debug.contains("password: \"***\""));
+    }
+}
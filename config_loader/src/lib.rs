@@ -1,13 +1,185 @@
-pub type Hello = String;
+use serde::{Deserialize, Serialize};
+
+pub mod database;
+pub mod kafka;
+pub mod loader;
+pub mod logging;
+pub mod redis;
+pub use loader::{HttpSource, load_config, load_config_async, load_validated_config};
+
+// re-export for convenience
+pub use config::{Config, ConfigBuilder, ConfigError, Environment, File, FileFormat};
+
+/// Placeholder rendered in place of credentials in `Debug` output.
+pub(crate) const REDACTED: &str = "***";
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct BaseAppConfig {
+    pub name: String,
+    pub version: Option<String>,
+    pub env: Option<String>,
+    /// timezone offset in hours from UTC (e.g., 7 for UTC+7)
+    pub timezone: Option<i8>,
+}
+
+impl BaseAppConfig {
+    /// Valid UTC offsets in hours, from UTC-12 to UTC+14.
+    pub const TIMEZONE_RANGE: std::ops::RangeInclusive<i8> = -12..=14;
+
+    /// Check that the timezone offset is in range and that `env` names a known environment.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if let Some(offset) = self.timezone
+            && !Self::TIMEZONE_RANGE.contains(&offset)
+        {
+            return Err(ConfigError::Message(format!(
+                "timezone offset {} is outside {}..={}",
+                offset,
+                Self::TIMEZONE_RANGE.start(),
+                Self::TIMEZONE_RANGE.end()
+            )));
+        }
+
+        if let Some(env) = &self.env
+            && let Env::Unknown(other) = Env::from(env.clone())
+        {
+            return Err(ConfigError::Message(format!("unknown env '{}'", other)));
+        }
+
+        Ok(())
+    }
+}
+
+impl AsRef<BaseAppConfig> for BaseAppConfig {
+    fn as_ref(&self) -> &BaseAppConfig {
+        self
+    }
+}
+
+/// Deployment environment. Serializes to its canonical lowercase name and deserializes through
+/// the same aliases as `From<String>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Env {
+    Development,
+    Staging,
+    Production,
+    Unknown(String),
+}
+
+impl From<String> for Env {
+    fn from(value: String) -> Self {
+        match value.to_lowercase().as_str() {
+            "development" | "dev" | "sit" => Env::Development,
+            "staging" | "stg" => Env::Staging,
+            "production" | "prod" => Env::Production,
+            other => Env::Unknown(other.to_string()),
+        }
+    }
+}
+
+impl Env {
+    /// Canonical name used when serializing.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Env::Development => "dev",
+            Env::Staging => "staging",
+            Env::Production => "production",
+            Env::Unknown(other) => other,
+        }
+    }
+}
+
+impl Serialize for Env {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Env {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Env::from)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct RemoteConfig {
+    pub config: _RemoteConfig,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct _RemoteConfig {
+    pub url: String,
+}
 
-// trigger a build
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn app_config(env: Option<&str>, timezone: Option<i8>) -> BaseAppConfig {
+        BaseAppConfig {
+            name: "app".to_string(),
+            version: None,
+            env: env.map(str::to_string),
+            timezone,
+        }
+    }
+
+    #[test]
+    fn test_validate_accepts_valid_timezone_and_env() {
+        assert!(app_config(Some("prod"), Some(7)).validate().is_ok());
+        assert!(app_config(None, Some(-12)).validate().is_ok());
+        assert!(app_config(None, Some(14)).validate().is_ok());
+        assert!(app_config(None, None).validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_out_of_range_timezone() {
+        let err = app_config(None, Some(50)).validate().unwrap_err();
+        assert!(err.to_string().contains("timezone offset 50"));
+        assert!(app_config(None, Some(-13)).validate().is_err());
+    }
+
+    #[test]
+    fn test_env_deserializes_aliases() {
+        use serde::de::{IntoDeserializer, value::Error};
+
+        let env = Env::deserialize("sit".into_deserializer()).map_err(|e: Error| e);
+        assert_eq!(env.unwrap(), Env::Development);
+
+        let env = Env::deserialize("PROD".into_deserializer()).map_err(|e: Error| e);
+        assert_eq!(env.unwrap(), Env::Production);
+    }
+
+    #[test]
+    fn test_env_unknown_round_trips() {
+        use serde::de::{IntoDeserializer, value::Error};
+
+        let env = Env::deserialize("qa".into_deserializer()).map_err(|e: Error| e);
+        assert_eq!(env.unwrap(), Env::Unknown("qa".to_string()));
+
+        #[derive(Serialize)]
+        struct Wrapper {
+            env: Env,
+        }
+
+        let config = Config::try_from(&Wrapper {
+            env: Env::Unknown("qa".to_string()),
+        })
+        .unwrap();
+        assert_eq!(config.get_string("env").unwrap(), "qa");
+
+        let config = Config::try_from(&Wrapper {
+            env: Env::Development,
+        })
+        .unwrap();
+        assert_eq!(config.get_string("env").unwrap(), "dev");
+    }
+
     #[test]
-    fn it_works() {
-        let result = Hello::from("Hello, world!");
-        assert_eq!(result, "Hello, world!");
+    fn test_validate_rejects_unknown_env() {
+        let err = app_config(Some("qa"), None).validate().unwrap_err();
+        assert!(err.to_string().contains("unknown env 'qa'"));
     }
 }
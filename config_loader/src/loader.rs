@@ -0,0 +1,286 @@
+use crate::BaseAppConfig;
+use async_trait::async_trait;
+use config::{
+    AsyncSource, Config, ConfigBuilder, ConfigError, File, FileFormat, FileStoredFormat, Format,
+    Map, Value, ValueKind, builder::AsyncState,
+};
+use serde::de::DeserializeOwned;
+use std::{
+    fmt::Debug,
+    io::{Error, ErrorKind},
+    path::PathBuf,
+};
+
+#[derive(Debug)]
+pub struct HttpSource<F: Format> {
+    uri: String,
+    format: F,
+}
+
+#[async_trait]
+impl<F: Format + Send + Sync + Debug> AsyncSource for HttpSource<F> {
+    async fn collect(&self) -> Result<Map<String, Value>, ConfigError> {
+        reqwest::get(&self.uri)
+            .await
+            .map_err(|e| ConfigError::Foreign(Box::new(e)))? // error conversion is possible from custom AsyncSource impls
+            .text()
+            .await
+            .map_err(|e| ConfigError::Foreign(Box::new(e)))
+            .and_then(|text| {
+                self.format
+                    .parse(Some(&self.uri), &text)
+                    .map_err(ConfigError::Foreign)
+            })
+    }
+}
+
+pub fn load_config<T>(path: &str) -> Result<T, ConfigError>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let mut config_path = PathBuf::from(path);
+
+    config_path =
+        std::fs::canonicalize(&config_path).map_err(|e| ConfigError::Foreign(Box::new(e)))?;
+
+    let settings = Config::builder()
+        .add_source(File::from(config_path))
+        .build()?;
+
+    settings
+        .try_deserialize::<T>()
+        .map_err(|e| ConfigError::Foreign(Box::new(e)))
+}
+
+/// Load configuration from a file and run [`BaseAppConfig::validate`] on the result.
+pub fn load_validated_config<T>(path: &str) -> Result<T, ConfigError>
+where
+    T: serde::de::DeserializeOwned + AsRef<BaseAppConfig>,
+{
+    let config = load_config::<T>(path)?;
+    config.as_ref().validate()?;
+    Ok(config)
+}
+
+/// Load configuration asynchronously from a remote HTTP endpoint
+pub async fn load_config_async<T>(uri: &str, format: FileFormat) -> Result<T, ConfigError>
+where
+    T: DeserializeOwned + Send,
+{
+    let config = ConfigBuilder::<AsyncState>::default()
+        .add_async_source(HttpSource {
+            uri: uri.into(),
+            format,
+        })
+        .build()
+        .await?;
+
+    config.try_deserialize()
+}
+
+#[derive(Debug, Clone)]
+pub struct PropertiesFile;
+
+impl Format for PropertiesFile {
+    fn parse(
+        &self,
+        uri: Option<&String>,
+        text: &str,
+    ) -> Result<Map<String, Value>, Box<dyn std::error::Error + Send + Sync>> {
+        let mut result = Map::new();
+        let mut lines = text.lines().enumerate();
+
+        while let Some((lineno, line)) = lines.next() {
+            let mut line = line.trim().to_string();
+
+            // Skip empty lines and comments (# or !)
+            if line.is_empty() || line.starts_with('#') || line.starts_with('!') {
+                continue;
+            }
+
+            // Join continuation lines ending with an unescaped backslash
+            while ends_with_continuation(&line) {
+                line.pop();
+                match lines.next() {
+                    Some((_, next)) => line.push_str(next.trim_start()),
+                    None => break,
+                }
+            }
+
+            // Split key=value on the first unescaped '='
+            let (key, value) = match split_unescaped(&line, '=') {
+                Some((k, v)) => (unescape(k.trim()), unescape(v.trim())),
+                None => {
+                    return Err(Box::new(Error::new(
+                        ErrorKind::InvalidData,
+                        format!("Invalid line {}: '{}'", lineno + 1, line),
+                    )));
+                }
+            };
+
+            insert_nested(&mut result, uri, &key, value).map_err(|message| {
+                Box::new(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("Invalid line {}: {}", lineno + 1, message),
+                ))
+            })?;
+        }
+
+        Ok(result)
+    }
+}
+
+/// A line continues onto the next one when it ends with an odd number of backslashes.
+fn ends_with_continuation(line: &str) -> bool {
+    line.bytes().rev().take_while(|&b| b == b'\\').count() % 2 == 1
+}
+
+/// Split on the first occurrence of `sep` that is not preceded by a backslash escape.
+fn split_unescaped(line: &str, sep: char) -> Option<(&str, &str)> {
+    let mut escaped = false;
+
+    for (i, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            c if c == sep => return Some((&line[..i], &line[i + c.len_utf8()..])),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Resolve `\=` and `\\` escapes. Other backslashes are kept as-is so values like
+/// Windows paths survive untouched.
+fn unescape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '\\'
+            && let Some(&next @ ('=' | '\\')) = chars.peek()
+        {
+            out.push(next);
+            chars.next();
+        } else {
+            out.push(c);
+        }
+    }
+
+    out
+}
+
+/// Insert `value` under a dotted `key`, creating nested tables for each segment.
+fn insert_nested(
+    table: &mut Map<String, Value>,
+    uri: Option<&String>,
+    key: &str,
+    value: String,
+) -> Result<(), String> {
+    let mut segments: Vec<&str> = key.split('.').collect();
+    if segments.iter().any(|s| s.is_empty()) {
+        return Err(format!("empty segment in key '{}'", key));
+    }
+
+    let leaf = segments.pop().expect("split yields at least one segment");
+    let mut current = table;
+
+    for segment in segments {
+        let entry = current
+            .entry(segment.to_string())
+            .or_insert_with(|| Value::new(uri, ValueKind::Table(Map::new())));
+
+        current = match &mut entry.kind {
+            ValueKind::Table(nested) => nested,
+            _ => {
+                return Err(format!(
+                    "key '{}' conflicts with value at '{}'",
+                    key, segment
+                ));
+            }
+        };
+    }
+
+    if let Some(Value {
+        kind: ValueKind::Table(_),
+        ..
+    }) = current.get(leaf)
+    {
+        return Err(format!("key '{}' conflicts with nested keys", key));
+    }
+
+    current.insert(leaf.to_string(), Value::new(uri, ValueKind::String(value)));
+
+    Ok(())
+}
+
+impl FileStoredFormat for PropertiesFile {
+    fn file_extensions(&self) -> &'static [&'static str] {
+        &["properties"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    struct Database {
+        host: String,
+        port: u16,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Settings {
+        name: String,
+        database: Database,
+    }
+
+    fn parse(text: &str) -> Map<String, Value> {
+        PropertiesFile.parse(None, text).unwrap()
+    }
+
+    #[test]
+    fn test_properties_nested_keys() {
+        let text = "name=app\ndatabase.host=localhost\ndatabase.port=1433\n";
+
+        let settings: Settings = Config::builder()
+            .add_source(File::from_str(text, PropertiesFile))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap();
+
+        assert_eq!(settings.name, "app");
+        assert_eq!(settings.database.host, "localhost");
+        assert_eq!(settings.database.port, 1433);
+    }
+
+    #[test]
+    fn test_properties_escaped_equals() {
+        let map = parse("query=a\\=b\\=c\nkey\\=with\\=eq=value");
+
+        assert_eq!(map["query"].clone().into_string().unwrap(), "a=b=c");
+        assert_eq!(map["key=with=eq"].clone().into_string().unwrap(), "value");
+    }
+
+    #[test]
+    fn test_properties_line_continuation() {
+        let map = parse("list=one, \\\n    two, \\\n    three\npath=C:\\\\\n");
+
+        assert_eq!(
+            map["list"].clone().into_string().unwrap(),
+            "one, two, three"
+        );
+        assert_eq!(map["path"].clone().into_string().unwrap(), "C:\\");
+    }
+
+    #[test]
+    fn test_properties_conflicting_keys() {
+        assert!(PropertiesFile.parse(None, "a=1\na.b=2").is_err());
+        assert!(PropertiesFile.parse(None, "a.b=1\na=2").is_err());
+        assert!(PropertiesFile.parse(None, "a..b=1").is_err());
+    }
+}
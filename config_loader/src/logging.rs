@@ -0,0 +1,219 @@
+use serde::{Deserialize, Serialize};
+use tracing_subscriber::{
+    EnvFilter, filter::LevelFilter, layer::SubscriberExt, util::SubscriberInitExt,
+};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct LoggerConfig {
+    pub max_level: String,
+    /// Output format for console logs; defaults to [`LogFormat::Pretty`].
+    #[serde(default)]
+    pub format: LogFormat,
+    pub file: Option<FileLoggerConfig>,
+    pub otel: Option<OtelConfig>,
+}
+
+impl Default for LoggerConfig {
+    fn default() -> Self {
+        Self {
+            max_level: "INFO".to_string(),
+            format: LogFormat::default(),
+            file: None,
+            otel: None,
+        }
+    }
+}
+
+/// Console log output format.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    /// Human-readable, ANSI-coloured lines.
+    #[default]
+    Pretty,
+    /// One JSON object per event, for log shippers.
+    Json,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct FileLoggerConfig {
+    pub max_size: u64,
+    pub path: String,
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct OtelConfig {
+    pub endpoint: String,
+    pub enabled: bool,
+}
+
+/// Error from [`init`].
+#[derive(Debug, thiserror::Error)]
+pub enum LoggingInitError {
+    #[error("invalid max_level '{0}'")]
+    InvalidLevel(String),
+    #[error("a global tracing subscriber is already installed")]
+    AlreadyInitialized,
+    #[error("otel output is enabled but config_loader was built without the `otel` feature")]
+    OtelUnavailable,
+    #[error("failed to build the otel span exporter: {0}")]
+    OtelExporter(String),
+}
+
+/// Install a console `tracing` subscriber from `config`: an env filter at `max_level`, which
+/// `RUST_LOG` overrides when set, and pretty or JSON lines per `format`.
+///
+/// With `otel.enabled`, spans are also exported over OTLP/gRPC to `otel.endpoint`, and the W3C
+/// trace-context propagator is installed so the contexts carried by `TraceData` link up across
+/// services. That needs the `otel` feature and a Tokio runtime; the exporter batches, so the
+/// last spans of a process that exits at once may be lost. File output is only set up by
+/// `logger::setup_logging`, which is an alternative to this function, not an addition: it
+/// keeps a subscriber that is already installed.
+///
+/// An existing global subscriber is never replaced; that returns
+/// [`LoggingInitError::AlreadyInitialized`].
+pub fn init(config: &LoggerConfig) -> Result<(), LoggingInitError> {
+    let level: LevelFilter = config
+        .max_level
+        .parse()
+        .map_err(|_| LoggingInitError::InvalidLevel(config.max_level.clone()))?;
+    let filter = EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| EnvFilter::default().add_directive(level.into()));
+
+    let otel = config.otel.as_ref().filter(|otel| otel.enabled);
+    #[cfg(feature = "otel")]
+    let tracer_provider = otel.map(otel::tracer_provider).transpose()?;
+    #[cfg(not(feature = "otel"))]
+    if otel.is_some() {
+        return Err(LoggingInitError::OtelUnavailable);
+    }
+
+    let subscriber = tracing_subscriber::registry().with(filter);
+    #[cfg(feature = "otel")]
+    let subscriber = subscriber.with(tracer_provider.as_ref().map(otel::layer));
+
+    let console = tracing_subscriber::fmt::layer().with_target(true);
+    let (json, pretty) = match config.format {
+        LogFormat::Json => (Some(console.json()), None),
+        LogFormat::Pretty => (None, Some(console)),
+    };
+    let subscriber = subscriber.with(tracing_subscriber::Layer::and_then(json, pretty));
+
+    subscriber
+        .try_init()
+        .map_err(|_| LoggingInitError::AlreadyInitialized)?;
+
+    // only made global once the subscriber that exports its spans is in place
+    #[cfg(feature = "otel")]
+    if let Some(provider) = tracer_provider {
+        otel::install(provider);
+    }
+    Ok(())
+}
+
+#[cfg(feature = "otel")]
+mod otel {
+    use super::{LoggingInitError, OtelConfig};
+    use opentelemetry::trace::TracerProvider as _;
+    use opentelemetry_otlp::{Protocol, WithExportConfig};
+    use opentelemetry_sdk::{
+        propagation::TraceContextPropagator,
+        trace::{SdkTracerProvider, Tracer},
+    };
+    use tracing_opentelemetry::OpenTelemetryLayer;
+    use tracing_subscriber::registry::LookupSpan;
+
+    pub(super) fn tracer_provider(
+        config: &OtelConfig,
+    ) -> Result<SdkTracerProvider, LoggingInitError> {
+        let exporter = opentelemetry_otlp::SpanExporter::builder()
+            .with_tonic()
+            .with_endpoint(&config.endpoint)
+            .with_protocol(Protocol::Grpc)
+            .build()
+            .map_err(|e| LoggingInitError::OtelExporter(e.to_string()))?;
+
+        Ok(SdkTracerProvider::builder()
+            .with_batch_exporter(exporter)
+            .build())
+    }
+
+    pub(super) fn layer<S>(provider: &SdkTracerProvider) -> OpenTelemetryLayer<S, Tracer>
+    where
+        S: tracing::Subscriber + for<'a> LookupSpan<'a>,
+    {
+        tracing_opentelemetry::layer().with_tracer(provider.tracer("config_loader"))
+    }
+
+    pub(super) fn install(provider: SdkTracerProvider) {
+        opentelemetry::global::set_text_map_propagator(TraceContextPropagator::new());
+        opentelemetry::global::set_tracer_provider(provider);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_init_twice_returns_error() {
+        let config = LoggerConfig::default();
+
+        init(&config).unwrap();
+        assert!(matches!(
+            init(&config),
+            Err(LoggingInitError::AlreadyInitialized)
+        ));
+    }
+
+    #[test]
+    fn test_init_rejects_unknown_level() {
+        let config = LoggerConfig {
+            max_level: "LOUD".to_string(),
+            ..LoggerConfig::default()
+        };
+
+        match init(&config) {
+            Err(LoggingInitError::InvalidLevel(level)) => assert_eq!(level, "LOUD"),
+            other => panic!("expected InvalidLevel, got {other:?}"),
+        }
+    }
+
+    #[cfg(not(feature = "otel"))]
+    #[test]
+    fn test_init_with_otel_needs_feature() {
+        let config = LoggerConfig {
+            otel: Some(OtelConfig {
+                endpoint: "http://127.0.0.1:4317".to_string(),
+                enabled: true,
+            }),
+            ..LoggerConfig::default()
+        };
+
+        assert!(matches!(
+            init(&config),
+            Err(LoggingInitError::OtelUnavailable)
+        ));
+    }
+
+    #[cfg(feature = "otel")]
+    #[test]
+    fn test_init_rejects_bad_otel_endpoint() {
+        let config = LoggerConfig {
+            otel: Some(OtelConfig {
+                endpoint: "not a uri".to_string(),
+                enabled: true,
+            }),
+            ..LoggerConfig::default()
+        };
+
+        assert!(matches!(
+            init(&config),
+            Err(LoggingInitError::OtelExporter(_))
+        ));
+    }
+}
@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::REDACTED;
+
+#[derive(Clone, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct RedisConfig {
+    pub mode: RedisMode,
+    pub host: String,
+    pub port: u16,
+    pub database: Option<u8>,
+    pub username: Option<String>,
+    pub password: Option<String>,
+}
+
+impl RedisConfig {
+    /// Single-node config for `host:port` on the default database without credentials.
+    pub fn new(host: impl Into<String>, port: u16) -> Self {
+        Self {
+            mode: RedisMode::Single,
+            host: host.into(),
+            port,
+            database: None,
+            username: None,
+            password: None,
+        }
+    }
+}
+
+impl fmt::Debug for RedisConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RedisConfig")
+            .field("mode", &self.mode)
+            .field("host", &self.host)
+            .field("port", &self.port)
+            .field("database", &self.database)
+            .field("username", &self.username)
+            .field("password", &self.password.as_ref().map(|_| REDACTED))
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum RedisMode {
+    Single,
+    Sentinel,
+    Cluster,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debug_redacts_password() {
+        let config = RedisConfig {
+            mode: RedisMode::Single,
+            host: "localhost".to_string(),
+            port: 6379,
+            database: Some(0),
+            username: Some("default".to_string()),
+            password: Some("hunter2".to_string()),
+        };
+
+        let debug = format!("{:?}", config);
+        assert!(!debug.contains("hunter2"));
+        assert!(debug.contains("password: Some(\"***\")"));
+
+        let config = RedisConfig {
+            password: None,
+            ..config
+        };
+        assert!(format!("{:?}", config).contains("password: None"));
+    }
+}
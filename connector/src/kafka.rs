@@ -0,0 +1,197 @@
+use crate::{ConnectionError, ConnectionResult};
+use config_loader::kafka::KafkaConfig;
+use rdkafka::{
+    ClientConfig,
+    consumer::{Consumer, StreamConsumer},
+    error::{KafkaError, RDKafkaErrorCode},
+    producer::{FutureProducer, Producer},
+};
+use std::time::Duration;
+use tokio::task::{JoinError, spawn_blocking};
+
+pub use rdkafka;
+
+/// How long to wait for broker metadata when verifying a new client can connect.
+pub const KAFKA_CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Create a producer and verify at least one broker answers a metadata request.
+///
+/// Blocks the calling thread for up to [`KAFKA_CONNECT_TIMEOUT`]; from async code use
+/// [`create_producer_async`].
+pub fn create_producer(config: KafkaConfig) -> ConnectionResult<FutureProducer> {
+    let producer: FutureProducer = client_config(&config)
+        .create()
+        .map_err(|e| map_kafka_error(e, &config.servers))?;
+
+    producer
+        .client()
+        .fetch_metadata(None, KAFKA_CONNECT_TIMEOUT)
+        .map_err(|e| map_kafka_error(e, &config.servers))?;
+
+    Ok(producer)
+}
+
+/// Create a consumer in `group_id` and verify at least one broker answers a metadata request.
+///
+/// Blocks the calling thread for up to [`KAFKA_CONNECT_TIMEOUT`]; from async code use
+/// [`create_consumer_async`].
+pub fn create_consumer(config: KafkaConfig, group_id: &str) -> ConnectionResult<StreamConsumer> {
+    let consumer: StreamConsumer = client_config(&config)
+        .set("group.id", group_id)
+        .create()
+        .map_err(|e| map_kafka_error(e, &config.servers))?;
+
+    consumer
+        .fetch_metadata(None, KAFKA_CONNECT_TIMEOUT)
+        .map_err(|e| map_kafka_error(e, &config.servers))?;
+
+    Ok(consumer)
+}
+
+/// [`create_producer`] on the blocking thread pool, so the metadata check does not stall the
+/// async runtime.
+pub async fn create_producer_async(config: KafkaConfig) -> ConnectionResult<FutureProducer> {
+    spawn_blocking(move || create_producer(config))
+        .await
+        .map_err(map_join_error)?
+}
+
+/// [`create_consumer`] on the blocking thread pool, so the metadata check does not stall the
+/// async runtime.
+pub async fn create_consumer_async(
+    config: KafkaConfig,
+    group_id: &str,
+) -> ConnectionResult<StreamConsumer> {
+    let group_id = group_id.to_owned();
+    spawn_blocking(move || create_consumer(config, &group_id))
+        .await
+        .map_err(map_join_error)?
+}
+
+fn map_join_error(err: JoinError) -> ConnectionError {
+    ConnectionError::Other {
+        message: format!("kafka client setup task failed: {err}").into(),
+    }
+}
+
+fn client_config(config: &KafkaConfig) -> ClientConfig {
+    let mut client_config = ClientConfig::new();
+    client_config
+        .set("bootstrap.servers", &config.servers)
+        .set("client.id", &config.client_id);
+
+    if let Some(protocol) = &config.security_protocol {
+        client_config.set("security.protocol", protocol);
+    }
+
+    if let Some(sasl) = &config.sasl {
+        client_config
+            .set("sasl.mechanisms", &sasl.mechanism)
+            .set("sasl.username", &sasl.username)
+            .set("sasl.password", &sasl.password);
+    }
+
+    client_config
+}
+
+/// Map an rdkafka error onto the matching [`ConnectionError`] variant.
+fn map_kafka_error(err: KafkaError, servers: &str) -> ConnectionError {
+    if let KafkaError::ClientCreation(message) = err {
+        return ConnectionError::invalid_config(message);
+    }
+
+    match err.rdkafka_error_code() {
+        Some(RDKafkaErrorCode::OperationTimedOut | RDKafkaErrorCode::RequestTimedOut) => {
+            ConnectionError::timeout()
+        }
+        Some(RDKafkaErrorCode::BrokerTransportFailure | RDKafkaErrorCode::AllBrokersDown) => {
+            ConnectionError::refused()
+        }
+        Some(RDKafkaErrorCode::Resolve) => ConnectionError::dns_failed(servers),
+        Some(RDKafkaErrorCode::Authentication | RDKafkaErrorCode::SaslAuthenticationFailed) => {
+            ConnectionError::auth_failed(err.to_string())
+        }
+        Some(RDKafkaErrorCode::SSL) => ConnectionError::Tls {
+            message: err.to_string().into(),
+        },
+        _ => ConnectionError::Other {
+            message: err.to_string().into(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use config_loader::kafka::KafkaSaslConfig;
+
+    #[test]
+    fn test_client_config_threads_sasl() {
+        let mut config = KafkaConfig::new("app", "broker:9092");
+        config.security_protocol = Some("sasl_ssl".to_string());
+        config.sasl = Some(KafkaSaslConfig::new("SCRAM-SHA-512", "svc", "secret"));
+
+        let client_config = client_config(&config);
+        assert_eq!(client_config.get("bootstrap.servers"), Some("broker:9092"));
+        assert_eq!(client_config.get("security.protocol"), Some("sasl_ssl"));
+        assert_eq!(client_config.get("sasl.mechanisms"), Some("SCRAM-SHA-512"));
+        assert_eq!(client_config.get("sasl.username"), Some("svc"));
+        assert_eq!(client_config.get("sasl.password"), Some("secret"));
+    }
+
+    #[test]
+    fn test_map_kafka_error_codes() {
+        assert!(matches!(
+            map_kafka_error(
+                KafkaError::MetadataFetch(RDKafkaErrorCode::OperationTimedOut),
+                "b:9092"
+            ),
+            ConnectionError::Timeout
+        ));
+        assert!(matches!(
+            map_kafka_error(
+                KafkaError::MetadataFetch(RDKafkaErrorCode::Resolve),
+                "b:9092"
+            ),
+            ConnectionError::DnsResolutionFailed { .. }
+        ));
+        assert!(matches!(
+            map_kafka_error(KafkaError::ClientCreation("bad".into()), "b:9092"),
+            ConnectionError::InvalidConfig { .. }
+        ));
+    }
+
+    #[test]
+    fn test_producer_with_bad_broker_is_mapped() {
+        let config = KafkaConfig::new("test", "127.0.0.1:1");
+
+        let Err(err) = create_producer(config) else {
+            panic!("expected connection error");
+        };
+        assert!(
+            matches!(err, ConnectionError::Timeout | ConnectionError::Refused),
+            "unexpected error: {err:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_async_clients_with_bad_broker_are_mapped() {
+        let config = KafkaConfig::new("test", "127.0.0.1:1");
+
+        let Err(err) = create_producer_async(config.clone()).await else {
+            panic!("expected connection error");
+        };
+        assert!(
+            matches!(err, ConnectionError::Timeout | ConnectionError::Refused),
+            "unexpected error: {err:?}"
+        );
+
+        let Err(err) = create_consumer_async(config, "test-group").await else {
+            panic!("expected connection error");
+        };
+        assert!(
+            matches!(err, ConnectionError::Timeout | ConnectionError::Refused),
+            "unexpected error: {err:?}"
+        );
+    }
+}
@@ -0,0 +1,10 @@
+#[cfg(feature = "kafka")]
+pub mod kafka;
+#[cfg(feature = "mssql")]
+pub mod mssql;
+#[cfg(feature = "redis")]
+pub mod redis;
+
+pub mod error;
+pub use error::ConnectionError;
+pub type ConnectionResult<T> = Result<T, ConnectionError>;
@@ -0,0 +1,834 @@
+use crate::{ConnectionError, ConnectionResult};
+use bb8::{Pool, PooledConnection};
+use bb8_tiberius::ConnectionManager;
+use config_loader::database::{EncryptionLevel, MssqlConfig};
+use data_types::backoff::ExponentialBackoff;
+use std::{
+    future::Future,
+    panic::{AssertUnwindSafe, catch_unwind, resume_unwind},
+    pin::pin,
+    task::Poll,
+    time::Duration,
+};
+use tiberius::Config;
+
+pub type MssqlPool = Pool<ConnectionManager>;
+pub type MssqlClient<'a> = PooledConnection<'a, ConnectionManager>;
+pub use tiberius::Query;
+
+/// Upper bound on a single [`health_check`] probe, including connection checkout.
+pub const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Pooled connections are closed after this long unless `MssqlConfig::max_lifetime` is set.
+pub const DEFAULT_MAX_LIFETIME: Duration = Duration::from_secs(30 * 60);
+
+/// Idle connections beyond `min_idle` are closed after this long unless
+/// `MssqlConfig::idle_timeout` is set.
+pub const DEFAULT_IDLE_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+pub async fn create_mssql_client(config: MssqlConfig) -> ConnectionResult<MssqlPool> {
+    let host = config.host.clone();
+    let manager = ConnectionManager::new(tiberius_config(&config));
+
+    let pool = pool_builder(&config)
+        .build(manager)
+        .await
+        .map_err(|e| map_mssql_error(&e, Some(&host)))?;
+
+    Ok(pool)
+}
+
+/// [`create_mssql_client`] bounded by `overall`, for servers whose port is filtered rather
+/// than refused, where pool startup could otherwise wait indefinitely.
+///
+/// Returns [`ConnectionError::Timeout`] once `overall` has elapsed.
+pub async fn create_mssql_client_with_timeout(
+    config: MssqlConfig,
+    overall: Duration,
+) -> ConnectionResult<MssqlPool> {
+    with_overall_timeout(overall, create_mssql_client(config)).await
+}
+
+async fn with_overall_timeout<T>(
+    overall: Duration,
+    fut: impl Future<Output = ConnectionResult<T>>,
+) -> ConnectionResult<T> {
+    tokio::time::timeout(overall, fut)
+        .await
+        .map_err(|_| ConnectionError::timeout())?
+}
+
+/// Driver settings from `config`.
+fn tiberius_config(config: &MssqlConfig) -> Config {
+    let mut mssql_config = Config::new();
+    mssql_config.host(config.host.as_str());
+    mssql_config.port(config.port);
+    mssql_config.authentication(tiberius::AuthMethod::sql_server(
+        &config.username,
+        &config.password,
+    ));
+    mssql_config.database(&config.database);
+    if let Some(level) = config.encryption {
+        mssql_config.encryption(match level {
+            EncryptionLevel::Off => tiberius::EncryptionLevel::Off,
+            EncryptionLevel::On => tiberius::EncryptionLevel::On,
+            EncryptionLevel::NotSupported => tiberius::EncryptionLevel::NotSupported,
+            EncryptionLevel::Required => tiberius::EncryptionLevel::Required,
+        });
+    }
+    if config.trust_cert {
+        mssql_config.trust_cert();
+    }
+    mssql_config
+}
+
+/// Pool settings from `config`.
+fn pool_builder(config: &MssqlConfig) -> bb8::Builder<ConnectionManager> {
+    let (max_lifetime, idle_timeout) = pool_lifetimes(config);
+
+    Pool::builder()
+        .max_size(config.pool_size.unwrap_or(10))
+        .min_idle(config.min_idle)
+        .connection_timeout(Duration::from_secs(config.connection_timeout.unwrap_or(30)))
+        .max_lifetime(max_lifetime)
+        .idle_timeout(idle_timeout)
+}
+
+/// `(max_lifetime, idle_timeout)` from `config`: unset values fall back to
+/// [`DEFAULT_MAX_LIFETIME`] and [`DEFAULT_IDLE_TIMEOUT`], `0` disables the limit.
+fn pool_lifetimes(config: &MssqlConfig) -> (Option<Duration>, Option<Duration>) {
+    let resolve = |secs: Option<u64>, default: Duration| match secs {
+        None => Some(default),
+        Some(0) => None,
+        Some(secs) => Some(Duration::from_secs(secs)),
+    };
+    (
+        resolve(config.max_lifetime, DEFAULT_MAX_LIFETIME),
+        resolve(config.idle_timeout, DEFAULT_IDLE_TIMEOUT),
+    )
+}
+
+/// Cheap liveness probe for readiness endpoints: checks out a connection and runs `SELECT 1`.
+///
+/// Gives up after [`HEALTH_CHECK_TIMEOUT`] so a hung server cannot block the caller.
+pub async fn health_check(pool: &MssqlPool) -> ConnectionResult<()> {
+    let probe = async {
+        let mut conn = pool.get().await.map_err(|e| match e {
+            bb8::RunError::TimedOut => ConnectionError::timeout(),
+            bb8::RunError::User(e) => map_mssql_error(&e, None),
+        })?;
+
+        conn.simple_query("SELECT 1")
+            .await
+            .map_err(|e| map_tiberius_error(&e, None))?
+            .into_row()
+            .await
+            .map_err(|e| map_tiberius_error(&e, None))?;
+
+        Ok(())
+    };
+
+    with_overall_timeout(HEALTH_CHECK_TIMEOUT, probe).await
+}
+
+/// Run `f` inside a transaction on a pooled connection.
+///
+/// Issues `BEGIN TRAN`, then `COMMIT` if `f` returns `Ok` or `ROLLBACK` if it returns `Err` or
+/// panics. A panic is resumed after the rollback; a failed rollback never masks the original
+/// error. If the returned future is dropped before the transaction ended, the rollback runs on
+/// a spawned task before the connection goes back to the pool.
+pub async fn with_transaction<F, T>(pool: &MssqlPool, f: F) -> ConnectionResult<T>
+where
+    F: AsyncFnOnce(&mut MssqlClient<'_>) -> ConnectionResult<T>,
+{
+    let conn = pool.get_owned().await.map_err(|e| match e {
+        bb8::RunError::TimedOut => ConnectionError::timeout(),
+        bb8::RunError::User(e) => map_mssql_error(&e, None),
+    })?;
+    // armed until COMMIT/ROLLBACK succeeded, so a cancellation at any await rolls back
+    let mut guard = TransactionGuard { conn: Some(conn) };
+
+    guard
+        .conn()
+        .simple_query("BEGIN TRAN")
+        .await
+        .map_err(|e| map_tiberius_error(&e, None))?
+        .into_results()
+        .await
+        .map_err(|e| map_tiberius_error(&e, None))?;
+
+    let outcome = catch_unwind_async(f(guard.conn())).await;
+
+    let finish = finish_statement(&outcome);
+    let finished = async {
+        guard
+            .conn()
+            .simple_query(finish)
+            .await?
+            .into_results()
+            .await?;
+        Ok::<_, tiberius::error::Error>(())
+    }
+    .await;
+    if finished.is_ok() {
+        guard.disarm();
+    }
+
+    match outcome {
+        Ok(Ok(value)) => finished
+            .map(|_| value)
+            .map_err(|e| map_tiberius_error(&e, None)),
+        Ok(Err(e)) => Err(e),
+        Err(panic) => resume_unwind(panic),
+    }
+}
+
+/// Statement ending a [`with_transaction`]: `COMMIT` only if `f` returned `Ok`.
+fn finish_statement<T>(outcome: &std::thread::Result<ConnectionResult<T>>) -> &'static str {
+    match outcome {
+        Ok(Ok(_)) => "COMMIT",
+        _ => "ROLLBACK",
+    }
+}
+
+/// Connection inside a [`with_transaction`] that rolls back if dropped while still armed.
+///
+/// Drop cannot wait, so the rollback runs on a spawned task that owns the connection and
+/// returns it to the pool afterwards. If that rollback fails too the connection is most likely
+/// dead, and bb8 discards it on its next checkout test.
+struct TransactionGuard {
+    conn: Option<MssqlClient<'static>>,
+}
+
+impl TransactionGuard {
+    fn conn(&mut self) -> &mut MssqlClient<'static> {
+        self.conn
+            .as_mut()
+            .expect("connection present until the guard is dropped")
+    }
+
+    /// The transaction has ended; give the connection back to the pool as it is.
+    fn disarm(mut self) {
+        self.conn.take();
+    }
+}
+
+impl Drop for TransactionGuard {
+    fn drop(&mut self) {
+        let Some(mut conn) = self.conn.take() else {
+            return;
+        };
+        // without a runtime the pool cannot hand the connection out again anyway
+        if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+            runtime.spawn(async move {
+                if let Ok(stream) = conn.simple_query("IF @@TRANCOUNT > 0 ROLLBACK TRAN").await {
+                    let _ = stream.into_results().await;
+                }
+            });
+        }
+    }
+}
+
+/// Run `f` on a pooled connection, retrying transient failures up to `attempts` times in total.
+///
+/// Deadlock victims, lock timeouts, busy or unavailable databases and connections reset
+/// mid-query are retried after an exponential backoff; any other error is returned at once.
+/// Once all attempts failed, the last transient error is returned, so a deadlock can still be
+/// told apart from a reset. `f` always runs at least once.
+pub async fn execute_with_retry<F, T>(
+    pool: &MssqlPool,
+    attempts: usize,
+    mut f: F,
+) -> ConnectionResult<T>
+where
+    F: AsyncFnMut(&mut MssqlClient<'_>) -> Result<T, tiberius::error::Error>,
+{
+    let backoff =
+        ExponentialBackoff::new(Duration::from_millis(50), Duration::from_secs(2), 2.0, 0.2);
+
+    retry_transient(attempts, backoff, async || {
+        let mut conn = pool.get().await.map_err(AttemptError::checkout)?;
+        f(&mut conn).await.map_err(AttemptError::query)
+    })
+    .await
+}
+
+/// Failure of one [`execute_with_retry`] attempt, already mapped, and whether to retry it.
+struct AttemptError {
+    transient: bool,
+    error: ConnectionError,
+}
+
+impl AttemptError {
+    fn checkout(err: bb8::RunError<bb8_tiberius::Error>) -> Self {
+        match err {
+            bb8::RunError::TimedOut => Self {
+                transient: true,
+                error: ConnectionError::timeout(),
+            },
+            bb8::RunError::User(e) => Self {
+                transient: match &e {
+                    bb8_tiberius::Error::Io(io) => is_transient_io(io.kind()),
+                    bb8_tiberius::Error::Tiberius(e) => is_transient(e),
+                },
+                error: map_mssql_error(&e, None),
+            },
+        }
+    }
+
+    fn query(err: tiberius::error::Error) -> Self {
+        Self {
+            transient: is_transient(&err),
+            error: map_tiberius_error(&err, None),
+        }
+    }
+}
+
+async fn retry_transient<T>(
+    attempts: usize,
+    mut backoff: ExponentialBackoff,
+    mut op: impl AsyncFnMut() -> Result<T, AttemptError>,
+) -> ConnectionResult<T> {
+    let attempts = attempts.max(1);
+    let mut attempt = 1;
+    loop {
+        match op().await {
+            Ok(value) => return Ok(value),
+            Err(e) if !e.transient || attempt == attempts => return Err(e.error),
+            Err(_) => tokio::time::sleep(backoff.next_delay()).await,
+        }
+        attempt += 1;
+    }
+}
+
+/// Poll `fut` to completion, capturing a panic instead of unwinding through the caller.
+async fn catch_unwind_async<F: Future>(fut: F) -> std::thread::Result<F::Output> {
+    let mut fut = pin!(fut);
+    std::future::poll_fn(
+        |cx| match catch_unwind(AssertUnwindSafe(|| fut.as_mut().poll(cx))) {
+            Ok(Poll::Ready(output)) => Poll::Ready(Ok(output)),
+            Ok(Poll::Pending) => Poll::Pending,
+            Err(panic) => Poll::Ready(Err(panic)),
+        },
+    )
+    .await
+}
+
+/// SQL Server error numbers reported when a login is refused.
+const LOGIN_FAILURE_CODES: [u32; 4] = [
+    18452, // untrusted domain login
+    18456, // login failed for user
+    18486, // account locked out
+    18488, // password must be changed
+];
+
+fn is_login_failure(code: u32) -> bool {
+    LOGIN_FAILURE_CODES.contains(&code)
+}
+
+/// SQL Server error numbers for statements that lost a race rather than being wrong.
+const TRANSIENT_ERROR_CODES: [u32; 4] = [
+    1205,  // chosen as deadlock victim
+    1222,  // lock request timed out
+    40501, // service busy
+    40613, // database unavailable
+];
+
+fn is_transient(err: &tiberius::error::Error) -> bool {
+    match err {
+        tiberius::error::Error::Io { kind, .. } => is_transient_io(*kind),
+        _ => err
+            .code()
+            .is_some_and(|code| TRANSIENT_ERROR_CODES.contains(&code)),
+    }
+}
+
+fn is_transient_io(kind: std::io::ErrorKind) -> bool {
+    use std::io::ErrorKind::*;
+    matches!(
+        kind,
+        ConnectionReset | ConnectionAborted | BrokenPipe | TimedOut | UnexpectedEof
+    )
+}
+
+/// Map a pool/driver error onto the matching [`ConnectionError`] variant.
+///
+/// `host` is `None` on paths that only hold the pool, which does not expose its config; a DNS
+/// failure there is reported as [`ConnectionError::Io`] rather than naming a blank host.
+fn map_mssql_error(err: &bb8_tiberius::Error, host: Option<&str>) -> ConnectionError {
+    match err {
+        bb8_tiberius::Error::Io(e) => map_io_error(e.kind(), &e.to_string(), host)
+            .unwrap_or_else(|| ConnectionError::database(e.to_string())),
+        bb8_tiberius::Error::Tiberius(e) => map_tiberius_error(e, host),
+    }
+}
+
+fn map_tiberius_error(err: &tiberius::error::Error, host: Option<&str>) -> ConnectionError {
+    if let Some(code) = err.code()
+        && is_login_failure(code)
+    {
+        return ConnectionError::auth_failed(err.to_string());
+    }
+
+    if let tiberius::error::Error::Io { kind, message } = err
+        && let Some(mapped) = map_io_error(*kind, message, host)
+    {
+        return mapped;
+    }
+
+    ConnectionError::database(err.to_string())
+}
+
+fn map_io_error(
+    kind: std::io::ErrorKind,
+    message: &str,
+    host: Option<&str>,
+) -> Option<ConnectionError> {
+    match kind {
+        std::io::ErrorKind::TimedOut => Some(ConnectionError::timeout()),
+        std::io::ErrorKind::ConnectionRefused => Some(ConnectionError::refused()),
+        // resolver failures have no dedicated ErrorKind, std reports them by message
+        _ if message.contains("failed to lookup address") => Some(match host {
+            Some(host) => ConnectionError::dns_failed(host),
+            // no host to name, so keep the resolver's own message
+            None => ConnectionError::Io {
+                source: std::io::Error::new(kind, message),
+            },
+        }),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_connection_error_display() {
+        let io_err = std::io::Error::new(std::io::ErrorKind::ConnectionRefused, "refused");
+        let conn_err = ConnectionError::Io { source: io_err };
+
+        let display = conn_err.to_string();
+        assert!(display.contains("I/O error"));
+    }
+
+    #[test]
+    fn test_map_timeout_errors() {
+        let err = bb8_tiberius::Error::Io(std::io::Error::new(
+            std::io::ErrorKind::TimedOut,
+            "connect timed out",
+        ));
+        assert!(matches!(
+            map_mssql_error(&err, Some("db")),
+            ConnectionError::Timeout
+        ));
+
+        let err = bb8_tiberius::Error::Tiberius(tiberius::error::Error::Io {
+            kind: std::io::ErrorKind::TimedOut,
+            message: "read timed out".to_string(),
+        });
+        assert!(matches!(
+            map_mssql_error(&err, Some("db")),
+            ConnectionError::Timeout
+        ));
+    }
+
+    #[test]
+    fn test_map_dns_failure() {
+        let err = bb8_tiberius::Error::Io(std::io::Error::other(
+            "failed to lookup address information: Name or service not known",
+        ));
+        match map_mssql_error(&err, Some("db.invalid")) {
+            ConnectionError::DnsResolutionFailed { hostname } => {
+                assert_eq!(hostname, "db.invalid")
+            }
+            other => panic!("expected DnsResolutionFailed, got {other:?}"),
+        }
+
+        // pooled paths do not know the host and must not report a blank one
+        match map_mssql_error(&err, None) {
+            ConnectionError::Io { source } => {
+                assert!(source.to_string().contains("failed to lookup address"))
+            }
+            other => panic!("expected Io, got {other:?}"),
+        }
+    }
+
+    /// Fake server accepting an unencrypted PRELOGIN, then answering LOGIN7 with server
+    /// error `code`, the way SQL Server rejects bad credentials.
+    async fn login_error_server(code: u32) -> u16 {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        async fn read_packet(socket: &mut tokio::net::TcpStream) {
+            let mut header = [0u8; 8];
+            socket.read_exact(&mut header).await.unwrap();
+            let len = u16::from_be_bytes([header[2], header[3]]) as usize;
+            socket.read_exact(&mut vec![0u8; len - 8]).await.unwrap();
+        }
+
+        async fn write_reply(socket: &mut tokio::net::TcpStream, payload: &[u8]) {
+            let len = (payload.len() + 8) as u16;
+            let [hi, lo] = len.to_be_bytes();
+            // tabular result, end of message
+            let mut packet = vec![0x04, 0x01, hi, lo, 0, 0, 1, 0];
+            packet.extend_from_slice(payload);
+            socket.write_all(&packet).await.unwrap();
+        }
+
+        fn utf16(text: &str) -> Vec<u8> {
+            text.encode_utf16().flat_map(u16::to_le_bytes).collect()
+        }
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+
+            read_packet(&mut socket).await;
+            // one option, ENCRYPTION at offset 6 = NOT_SUP
+            write_reply(&mut socket, &[0x01, 0x00, 0x06, 0x00, 0x01, 0xff, 0x02]).await;
+
+            read_packet(&mut socket).await;
+            let message = "Login failed for user 'sa'.";
+            let mut error = code.to_le_bytes().to_vec();
+            error.extend([1, 14]); // state, class
+            error.extend((message.len() as u16).to_le_bytes());
+            error.extend(utf16(message));
+            error.push(4);
+            error.extend(utf16("fake"));
+            error.push(0); // procedure
+            error.extend(1u32.to_le_bytes()); // line
+
+            let mut reply = vec![0xaa];
+            reply.extend((error.len() as u16).to_le_bytes());
+            reply.extend(error);
+            // DONE with the error bit set
+            reply.extend([0xfd, 0x02, 0x00, 0x00, 0x00]);
+            reply.extend(0u64.to_le_bytes());
+            write_reply(&mut socket, &reply).await;
+        });
+
+        port
+    }
+
+    #[tokio::test]
+    async fn test_login_failure_maps_to_authentication_failed() {
+        use tokio_util::compat::TokioAsyncWriteCompatExt;
+
+        let port = login_error_server(18456).await;
+        let mut config = Config::new();
+        config.host("127.0.0.1");
+        config.port(port);
+        config.authentication(tiberius::AuthMethod::sql_server("sa", "wrong"));
+        config.encryption(tiberius::EncryptionLevel::NotSupported);
+
+        let tcp = tokio::net::TcpStream::connect(config.get_addr())
+            .await
+            .unwrap();
+        let Err(err) = tiberius::Client::connect(config, tcp.compat_write()).await else {
+            panic!("expected the login to be rejected");
+        };
+
+        assert_eq!(err.code(), Some(18456));
+        assert!(
+            matches!(
+                map_tiberius_error(&err, Some("127.0.0.1")),
+                ConnectionError::AuthenticationFailed { .. }
+            ),
+            "{err:?}"
+        );
+    }
+
+    #[test]
+    fn test_map_login_failure_codes() {
+        assert!(is_login_failure(18456));
+        assert!(is_login_failure(18488));
+        assert!(!is_login_failure(1205));
+    }
+
+    #[test]
+    fn test_map_fallback_to_database_specific() {
+        let err = bb8_tiberius::Error::Tiberius(tiberius::error::Error::Protocol(
+            "unexpected token".into(),
+        ));
+        assert!(matches!(
+            map_mssql_error(&err, Some("db")),
+            ConnectionError::DatabaseSpecific { .. }
+        ));
+    }
+
+    #[test]
+    fn test_tiberius_config_applies_encryption() {
+        let mut config = MssqlConfig::new("db", 1433, "sa", "pw", "market");
+        let default = format!("{:?}", tiberius_config(&config));
+        assert!(default.contains("trust: Default"), "{default}");
+
+        config.encryption = Some(EncryptionLevel::Required);
+        config.trust_cert = true;
+        let tls = format!("{:?}", tiberius_config(&config));
+        assert!(tls.contains("encryption: Required"), "{tls}");
+        assert!(tls.contains("trust: TrustAll"), "{tls}");
+
+        config.encryption = Some(EncryptionLevel::Off);
+        let off = format!("{:?}", tiberius_config(&config));
+        assert!(off.contains("encryption: Off"), "{off}");
+    }
+
+    #[test]
+    fn test_pool_lifetimes_from_config() {
+        let mut config = MssqlConfig::new("db", 1433, "sa", "pw", "market");
+        assert_eq!(
+            pool_lifetimes(&config),
+            (Some(DEFAULT_MAX_LIFETIME), Some(DEFAULT_IDLE_TIMEOUT))
+        );
+
+        config.max_lifetime = Some(120);
+        config.idle_timeout = Some(0);
+        assert_eq!(
+            pool_lifetimes(&config),
+            (Some(Duration::from_secs(120)), None)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_health_check_unreachable_server_times_out() {
+        let mut config = Config::new();
+        config.host("127.0.0.1");
+        config.port(1);
+
+        let pool = Pool::builder()
+            .connection_timeout(Duration::from_millis(200))
+            .build_unchecked(ConnectionManager::new(config));
+
+        let err = health_check(&pool).await.unwrap_err();
+        assert!(matches!(err, ConnectionError::Timeout));
+    }
+
+    #[tokio::test]
+    async fn test_overall_timeout_elapsed() {
+        let err = with_overall_timeout(
+            Duration::ZERO,
+            std::future::pending::<ConnectionResult<()>>(),
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(err, ConnectionError::Timeout));
+
+        let ok = with_overall_timeout(Duration::ZERO, async { Ok(7) }).await;
+        assert_eq!(ok.unwrap(), 7);
+    }
+
+    /// Requires a host whose port silently drops packets; set `MSSQL_FILTERED_HOST`
+    /// (e.g. a firewalled address). Pool startup never completes, so the overall bound fires.
+    #[tokio::test]
+    #[ignore]
+    async fn test_create_with_timeout_filtered_port() {
+        let mut config = MssqlConfig::new(
+            std::env::var("MSSQL_FILTERED_HOST").unwrap(),
+            1433,
+            "sa",
+            "pw",
+            "master",
+        );
+        config.min_idle = Some(1);
+
+        let err = create_mssql_client_with_timeout(config, Duration::from_millis(500))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ConnectionError::Timeout));
+    }
+
+    fn reset_error() -> tiberius::error::Error {
+        tiberius::error::Error::Io {
+            kind: std::io::ErrorKind::ConnectionReset,
+            message: "connection reset by peer".to_string(),
+        }
+    }
+
+    fn no_delay() -> ExponentialBackoff {
+        ExponentialBackoff::new(Duration::ZERO, Duration::ZERO, 1.0, 0.0)
+    }
+
+    #[tokio::test]
+    async fn test_retry_transient_fails_twice_then_succeeds() {
+        let mut calls = 0;
+        let result = retry_transient(3, no_delay(), async || {
+            calls += 1;
+            if calls < 3 {
+                Err(AttemptError::query(reset_error()))
+            } else {
+                Ok(calls)
+            }
+        })
+        .await;
+
+        assert_eq!(result.unwrap(), 3);
+        assert_eq!(calls, 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_transient_exhausted_and_permanent() {
+        let mut calls = 0;
+        let err = retry_transient(2, no_delay(), async || -> Result<(), _> {
+            calls += 1;
+            Err(AttemptError::query(reset_error()))
+        })
+        .await
+        .unwrap_err();
+        // the last reset is reported, not just that retries ran out
+        match err {
+            ConnectionError::DatabaseSpecific { message } => {
+                assert!(message.contains("connection reset by peer"))
+            }
+            other => panic!("expected the reset, got {other:?}"),
+        }
+        assert_eq!(calls, 2);
+
+        let mut calls = 0;
+        let err = retry_transient(5, no_delay(), async || -> Result<(), _> {
+            calls += 1;
+            Err(AttemptError::query(tiberius::error::Error::Protocol(
+                "unexpected token".into(),
+            )))
+        })
+        .await
+        .unwrap_err();
+        assert!(matches!(err, ConnectionError::DatabaseSpecific { .. }));
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn test_transient_classification() {
+        assert!(is_transient(&reset_error()));
+        assert!(!is_transient(&tiberius::error::Error::Io {
+            kind: std::io::ErrorKind::PermissionDenied,
+            message: "denied".to_string(),
+        }));
+        assert!(!is_transient(&tiberius::error::Error::Protocol(
+            "unexpected token".into()
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_finish_statement_commits_only_on_ok() {
+        assert_eq!(finish_statement(&Ok(Ok(1))), "COMMIT");
+        assert_eq!(
+            finish_statement::<()>(&Ok(Err(ConnectionError::database("abort")))),
+            "ROLLBACK"
+        );
+
+        let panicked = catch_unwind_async(async { panic!("boom") }).await;
+        assert_eq!(finish_statement::<()>(&panicked), "ROLLBACK");
+    }
+
+    #[tokio::test]
+    async fn test_catch_unwind_async_captures_panic() {
+        let ok = catch_unwind_async(async { 7 }).await;
+        assert_eq!(ok.unwrap(), 7);
+
+        let panicked = catch_unwind_async(async { panic!("boom") }).await;
+        assert!(panicked.is_err());
+    }
+
+    /// Requires a reachable SQL Server; set `MSSQL_HOST`, `MSSQL_USER` and `MSSQL_PASSWORD`.
+    ///
+    /// Rows written inside a closure that returns `Err` are rolled back; rows written inside a
+    /// closure that returns `Ok` are committed and visible afterwards.
+    #[tokio::test]
+    #[ignore]
+    async fn test_with_transaction_commit_and_rollback() {
+        let mut config = Config::new();
+        config.host(std::env::var("MSSQL_HOST").unwrap());
+        config.authentication(tiberius::AuthMethod::sql_server(
+            std::env::var("MSSQL_USER").unwrap(),
+            std::env::var("MSSQL_PASSWORD").unwrap(),
+        ));
+        config.trust_cert();
+
+        let pool = Pool::builder()
+            .max_size(1)
+            .build_unchecked(ConnectionManager::new(config));
+
+        with_transaction(&pool, async |conn| {
+            conn.simple_query("CREATE TABLE ##tx_test (id INT)")
+                .await
+                .map_err(|e| ConnectionError::database(e.to_string()))?;
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+        let err = with_transaction(&pool, async |conn| {
+            conn.simple_query("INSERT INTO ##tx_test VALUES (1)")
+                .await
+                .map_err(|e| ConnectionError::database(e.to_string()))?;
+            Err::<(), _>(ConnectionError::database("abort"))
+        })
+        .await;
+        assert!(err.is_err());
+
+        let count = with_transaction(&pool, async |conn| {
+            let row = async {
+                conn.simple_query("SELECT COUNT(*) FROM ##tx_test")
+                    .await?
+                    .into_row()
+                    .await
+            }
+            .await
+            .map_err(|e| ConnectionError::database(e.to_string()))?;
+            Ok(row.and_then(|row| row.get::<i32, _>(0)))
+        })
+        .await
+        .unwrap();
+        assert_eq!(count, Some(0));
+
+        // cancelled mid-transaction: the insert is rolled back before the only pooled
+        // connection is handed out again
+        let cancelled = tokio::time::timeout(
+            Duration::from_millis(200),
+            with_transaction(&pool, async |conn| {
+                conn.simple_query("INSERT INTO ##tx_test VALUES (2)")
+                    .await
+                    .map_err(|e| ConnectionError::database(e.to_string()))?;
+                std::future::pending::<ConnectionResult<()>>().await
+            }),
+        )
+        .await;
+        assert!(cancelled.is_err());
+
+        let (open, count) = with_transaction(&pool, async |conn| {
+            let row = async {
+                conn.simple_query("SELECT @@TRANCOUNT, COUNT(*) FROM ##tx_test")
+                    .await?
+                    .into_row()
+                    .await
+            }
+            .await
+            .map_err(|e| ConnectionError::database(e.to_string()))?
+            .unwrap();
+            Ok((row.get::<i32, _>(0), row.get::<i32, _>(1)))
+        })
+        .await
+        .unwrap();
+        // 1 is this call's own transaction
+        assert_eq!((open, count), (Some(1), Some(0)));
+    }
+
+    /// Requires a reachable SQL Server; set `MSSQL_HOST`, `MSSQL_USER` and `MSSQL_PASSWORD`.
+    #[tokio::test]
+    #[ignore]
+    async fn test_health_check_live_server() {
+        let mut config = Config::new();
+        config.host(std::env::var("MSSQL_HOST").unwrap());
+        config.authentication(tiberius::AuthMethod::sql_server(
+            std::env::var("MSSQL_USER").unwrap(),
+            std::env::var("MSSQL_PASSWORD").unwrap(),
+        ));
+        config.trust_cert();
+
+        let pool = Pool::builder().build_unchecked(ConnectionManager::new(config));
+
+        health_check(&pool).await.unwrap();
+    }
+}
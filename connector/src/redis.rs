@@ -0,0 +1,111 @@
+use crate::{ConnectionError, ConnectionResult};
+use bb8::Pool;
+use bb8_redis::RedisConnectionManager;
+use config_loader::redis::{RedisConfig, RedisMode};
+use redis::{
+    ConnectionAddr, ConnectionInfo, ErrorKind, IntoConnectionInfo, RedisConnectionInfo, RedisError,
+};
+
+pub type RedisPool = Pool<RedisConnectionManager>;
+pub use redis;
+
+/// Build a pool for a single-node Redis described by `config`.
+pub async fn create_redis_pool(config: RedisConfig) -> ConnectionResult<RedisPool> {
+    if config.mode != RedisMode::Single {
+        return Err(ConnectionError::invalid_config(format!(
+            "redis mode {:?} is not supported, only single",
+            config.mode
+        )));
+    }
+
+    let info = ConnectionInfo {
+        addr: ConnectionAddr::Tcp(config.host.clone(), config.port),
+        redis: RedisConnectionInfo {
+            db: config.database.unwrap_or(0) as i64,
+            username: config.username.clone(),
+            password: config.password.clone(),
+            ..Default::default()
+        },
+    };
+
+    create_pool(info, &config.host).await
+}
+
+/// Build a pool from a `redis://` URL.
+pub async fn create_redis_pool_from_url(url: &str) -> ConnectionResult<RedisPool> {
+    create_pool(url, url).await
+}
+
+async fn create_pool<T: IntoConnectionInfo>(info: T, host: &str) -> ConnectionResult<RedisPool> {
+    let manager = RedisConnectionManager::new(info).map_err(|e| map_redis_error(&e, host))?;
+
+    Pool::builder()
+        .build(manager)
+        .await
+        .map_err(|e| map_redis_error(&e, host))
+}
+
+/// Check out a connection and send `PING`.
+pub async fn ping(pool: &RedisPool) -> ConnectionResult<()> {
+    let mut conn = pool.get().await.map_err(|e| match e {
+        bb8::RunError::TimedOut => ConnectionError::timeout(),
+        bb8::RunError::User(e) => map_redis_error(&e, ""),
+    })?;
+
+    redis::cmd("PING")
+        .query_async::<String>(&mut *conn)
+        .await
+        .map_err(|e| map_redis_error(&e, ""))?;
+
+    Ok(())
+}
+
+/// Map a redis error onto the matching [`ConnectionError`] variant.
+fn map_redis_error(err: &RedisError, host: &str) -> ConnectionError {
+    match err.kind() {
+        ErrorKind::AuthenticationFailed => ConnectionError::auth_failed(err.to_string()),
+        ErrorKind::InvalidClientConfig => ConnectionError::invalid_config(err.to_string()),
+        _ if err.is_timeout() => ConnectionError::timeout(),
+        _ if err.is_connection_refusal() => ConnectionError::refused(),
+        // resolver failures have no dedicated ErrorKind, std reports them by message
+        _ if err.to_string().contains("failed to lookup address") => {
+            ConnectionError::dns_failed(host)
+        }
+        _ => ConnectionError::Other {
+            message: err.to_string().into(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_bad_url_is_invalid_config() {
+        let err = create_redis_pool_from_url("not-a-redis-url")
+            .await
+            .map(|_| ())
+            .unwrap_err();
+        assert!(matches!(err, ConnectionError::InvalidConfig { .. }));
+    }
+
+    #[test]
+    fn test_map_auth_failure() {
+        let err = RedisError::from((ErrorKind::AuthenticationFailed, "WRONGPASS"));
+        assert!(matches!(
+            map_redis_error(&err, "localhost"),
+            ConnectionError::AuthenticationFailed { .. }
+        ));
+    }
+
+    /// Requires a Redis server on localhost:6379.
+    #[tokio::test]
+    #[ignore]
+    async fn test_ping_local_redis() {
+        let pool = create_redis_pool(RedisConfig::new("127.0.0.1", 6379))
+            .await
+            .unwrap();
+        ping(&pool).await.unwrap();
+    }
+}
@@ -0,0 +1,89 @@
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+use data_types::string::Alpha8;
+use std::hint::black_box;
+
+const SYMBOLS: [&[u8; 8]; 8] = [
+    b"BBCA    ",
+    b"TLKM    ",
+    b"GOTO    ",
+    b"ASII    ",
+    b"BBRI-R  ",
+    b"ANTM    ",
+    b"BMRI    ",
+    b"UNVR-W2 ",
+];
+
+/// Concatenated space-padded 8-byte symbol fields, as they appear in a packet batch.
+fn padded_symbols(count: usize) -> Vec<u8> {
+    SYMBOLS
+        .iter()
+        .cycle()
+        .take(count)
+        .flat_map(|symbol| symbol.iter().copied())
+        .collect()
+}
+
+fn bench_parse(c: &mut Criterion) {
+    let input = padded_symbols(1024);
+    let mut group = c.benchmark_group("Alpha8 parse");
+    group.throughput(Throughput::Elements(1024));
+
+    group.bench_function("Alpha8::parse", |b| {
+        b.iter(|| {
+            for field in black_box(&input).as_chunks::<8>().0 {
+                black_box(Alpha8::parse(field).unwrap());
+            }
+        })
+    });
+
+    group.bench_function("from_utf8 + trim_end", |b| {
+        b.iter(|| {
+            for field in black_box(&input).as_chunks::<8>().0 {
+                black_box(std::str::from_utf8(field).unwrap().trim_end());
+            }
+        })
+    });
+
+    group.finish();
+}
+
+fn bench_trimmed_str(c: &mut Criterion) {
+    let input = padded_symbols(1024);
+    let parsed: Vec<Alpha8> = input
+        .as_chunks::<8>()
+        .0
+        .iter()
+        .map(|field| Alpha8::parse(field).unwrap())
+        .collect();
+    let mut group = c.benchmark_group("Alpha8 trimmed str");
+    group.throughput(Throughput::Elements(1024));
+
+    group.bench_function("Alpha8::as_trimmed_str (cached len)", |b| {
+        b.iter(|| {
+            for alpha in black_box(&parsed) {
+                black_box(alpha.as_trimmed_str());
+            }
+        })
+    });
+
+    group.bench_function("Alpha8::as_str + trim_end", |b| {
+        b.iter(|| {
+            for alpha in black_box(&parsed) {
+                black_box(alpha.as_str().trim_end());
+            }
+        })
+    });
+
+    group.bench_function("from_utf8 + trim_end", |b| {
+        b.iter(|| {
+            for field in black_box(&input).as_chunks::<8>().0 {
+                black_box(std::str::from_utf8(field).unwrap().trim_end());
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(alpha_benches, bench_parse, bench_trimmed_str);
+criterion_main!(alpha_benches);
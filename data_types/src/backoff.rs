@@ -0,0 +1,168 @@
+use std::{
+    hash::{BuildHasher, RandomState},
+    time::Duration,
+};
+
+/// Exponential backoff shared by the reconnect and retry loops.
+///
+/// Each [`next_delay`](Self::next_delay) returns the current delay and multiplies it for the
+/// following call, capped at `max`. `jitter` in `0.0..=1.0` shortens every returned delay by a
+/// random fraction of up to that much, so clients that failed together do not retry in lockstep.
+#[derive(Debug, Clone)]
+pub struct ExponentialBackoff {
+    initial: Duration,
+    max: Duration,
+    multiplier: f64,
+    jitter: f64,
+    attempt: u32,
+    rng: u64,
+}
+
+/// Delay before attempt `attempt` (counted from 1) without jitter: `initial` multiplied by
+/// `multiplier` once per earlier attempt, capped at `max`.
+pub fn delay_for_attempt(
+    initial: Duration,
+    max: Duration,
+    multiplier: f64,
+    attempt: u32,
+) -> Duration {
+    let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+    Duration::try_from_secs_f64(initial.as_secs_f64() * multiplier.powi(exponent))
+        .map_or(max, |delay| delay.min(max))
+}
+
+impl ExponentialBackoff {
+    /// # Panics
+    /// If `multiplier` is below 1.0 or `jitter` is outside `0.0..=1.0`.
+    pub fn new(initial: Duration, max: Duration, multiplier: f64, jitter: f64) -> Self {
+        assert!(multiplier >= 1.0, "backoff multiplier must be at least 1.0");
+        assert!(
+            (0.0..=1.0).contains(&jitter),
+            "backoff jitter must be within 0.0..=1.0"
+        );
+
+        Self {
+            initial,
+            max,
+            multiplier,
+            jitter,
+            attempt: 0,
+            // xorshift needs a non-zero state
+            rng: RandomState::new().hash_one(0u8) | 1,
+        }
+    }
+
+    /// Delay to wait now; advances the backoff for the next call.
+    pub fn next_delay(&mut self) -> Duration {
+        self.attempt = self.attempt.saturating_add(1);
+        self.jittered_delay(self.attempt)
+    }
+
+    /// Delay before attempt `attempt` (counted from 1), with jitter, for callers that keep their
+    /// own attempt count. Does not advance the backoff.
+    pub fn jittered_delay(&mut self, attempt: u32) -> Duration {
+        let delay = delay_for_attempt(self.initial, self.max, self.multiplier, attempt);
+
+        if self.jitter == 0.0 {
+            return delay;
+        }
+        delay.mul_f64(1.0 - self.jitter * self.next_unit())
+    }
+
+    /// Start over from the initial delay, e.g. after a successful attempt.
+    pub fn reset(&mut self) {
+        self.attempt = 0;
+    }
+
+    pub fn initial(&self) -> Duration {
+        self.initial
+    }
+
+    pub fn max(&self) -> Duration {
+        self.max
+    }
+
+    /// Uniform value in `0.0..1.0` from a xorshift64 generator.
+    fn next_unit(&mut self) -> f64 {
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 7;
+        self.rng ^= self.rng << 17;
+        (self.rng >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(backoff: &mut ExponentialBackoff, n: usize) -> Vec<u128> {
+        (0..n).map(|_| backoff.next_delay().as_millis()).collect()
+    }
+
+    #[test]
+    fn test_grows_by_multiplier() {
+        let mut backoff = ExponentialBackoff::new(
+            Duration::from_millis(100),
+            Duration::from_secs(60),
+            3.0,
+            0.0,
+        );
+        assert_eq!(millis(&mut backoff, 4), [100, 300, 900, 2700]);
+    }
+
+    #[test]
+    fn test_caps_at_max() {
+        let mut backoff = ExponentialBackoff::new(
+            Duration::from_millis(100),
+            Duration::from_millis(500),
+            2.0,
+            0.0,
+        );
+        assert_eq!(millis(&mut backoff, 5), [100, 200, 400, 500, 500]);
+
+        let mut huge = ExponentialBackoff::new(Duration::from_secs(1), Duration::MAX, 1e300, 0.0);
+        huge.next_delay();
+        assert_eq!(huge.next_delay(), Duration::MAX);
+    }
+
+    #[test]
+    fn test_reset_starts_over() {
+        let mut backoff =
+            ExponentialBackoff::new(Duration::from_millis(10), Duration::from_secs(1), 2.0, 0.0);
+        millis(&mut backoff, 3);
+        backoff.reset();
+        assert_eq!(millis(&mut backoff, 2), [10, 20]);
+    }
+
+    #[test]
+    fn test_jitter_stays_within_bounds() {
+        let mut backoff =
+            ExponentialBackoff::new(Duration::from_secs(1), Duration::from_secs(1), 2.0, 0.5);
+        let delays: Vec<_> = (0..100).map(|_| backoff.next_delay()).collect();
+
+        assert!(
+            delays
+                .iter()
+                .all(|d| (Duration::from_millis(500)..=Duration::from_secs(1)).contains(d))
+        );
+        assert!(delays.iter().any(|d| *d != delays[0]));
+    }
+
+    #[test]
+    fn test_delay_for_attempt() {
+        let initial = Duration::from_millis(100);
+        let max = Duration::from_millis(500);
+        let delays: Vec<_> = (1..=5)
+            .map(|n| delay_for_attempt(initial, max, 2.0, n).as_millis())
+            .collect();
+        assert_eq!(delays, [100, 200, 400, 500, 500]);
+        assert_eq!(delay_for_attempt(initial, max, 2.0, 0), initial);
+        assert_eq!(delay_for_attempt(initial, max, 2.0, u32::MAX), max);
+    }
+
+    #[test]
+    #[should_panic(expected = "multiplier")]
+    fn test_rejects_shrinking_multiplier() {
+        ExponentialBackoff::new(Duration::from_secs(1), Duration::from_secs(2), 0.5, 0.0);
+    }
+}
@@ -0,0 +1,78 @@
+use crate::ParseError;
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DataFeedType {
+    #[serde(rename = "ITCH")]
+    Itch,
+    #[serde(rename = "MDF")]
+    Mdf,
+}
+
+impl DataFeedType {
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        match self {
+            DataFeedType::Itch => "ITCH",
+            DataFeedType::Mdf => "MDF",
+        }
+    }
+}
+
+impl fmt::Display for DataFeedType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Parse a feed name case-insensitively, e.g. from CLI arguments.
+impl FromStr for DataFeedType {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value.eq_ignore_ascii_case("ITCH") {
+            Ok(DataFeedType::Itch)
+        } else if value.eq_ignore_ascii_case("MDF") {
+            Ok(DataFeedType::Mdf)
+        } else {
+            Err(ParseError::InvalidEnumString {
+                invalid: value.as_bytes().to_vec(),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_as_str() {
+        assert_eq!(DataFeedType::Itch.as_str(), "ITCH");
+        assert_eq!(DataFeedType::Mdf.as_str(), "MDF");
+    }
+
+    #[test]
+    fn test_from_str_is_case_insensitive() {
+        assert_eq!("itch".parse::<DataFeedType>().unwrap(), DataFeedType::Itch);
+        assert_eq!("Mdf".parse::<DataFeedType>().unwrap(), DataFeedType::Mdf);
+        assert_eq!("ITCH".parse::<DataFeedType>().unwrap(), DataFeedType::Itch);
+    }
+
+    #[test]
+    fn test_from_str_unknown() {
+        let err = "OUCH".parse::<DataFeedType>().unwrap_err();
+        assert!(matches!(
+            err,
+            ParseError::InvalidEnumString { ref invalid } if invalid == b"OUCH"
+        ));
+    }
+
+    #[test]
+    fn test_display_round_trips() {
+        for feed in [DataFeedType::Itch, DataFeedType::Mdf] {
+            assert_eq!(feed.to_string().parse::<DataFeedType>().unwrap(), feed);
+        }
+    }
+}
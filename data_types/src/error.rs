@@ -0,0 +1,451 @@
+use std::borrow::Cow;
+
+use thiserror::Error;
+
+/// A parsing error with optional contextual information.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// A character was invalid during parsing (e.g., unexpected byte).
+    #[error("invalid character: 0x{value:02x}")]
+    InvalidChar { value: u8 },
+
+    /// A character was invalid at a specific position.
+    #[error("invalid character: 0x{value:02x} at position {position}")]
+    InvalidCharAt { value: u8, position: usize },
+
+    /// A date field was invalid or malformed.
+    #[error("invalid date")]
+    InvalidDate,
+
+    /// A date field was invalid at a specific position.
+    #[error("invalid date at position {position}")]
+    InvalidDateAt { position: usize },
+
+    /// Timestamp had an unexpected format or unknown types.
+    #[error("invalid timestamp: {timestamp}")]
+    InvalidTimestamp { timestamp: &'static str },
+
+    /// Timestamp error at a specific position.
+    #[error("invalid timestamp: {timestamp} at position {position}")]
+    InvalidTimestampAt {
+        timestamp: &'static str,
+        position: usize,
+    },
+
+    /// Message type field had an unexpected value.
+    #[error("invalid message type: 0x{value:02x} ('{}')", *.value as char)]
+    InvalidMessageType { value: u8 },
+
+    /// Message type error at a specific position.
+    #[error("invalid message type: 0x{value:02x} ('{}') at position {position}", *.value as char)]
+    InvalidMessageTypeAt { value: u8, position: usize },
+
+    /// A numeric or value field is outside of allowed bounds.
+    #[error("invalid value")]
+    InvalidValue,
+
+    /// Invalid value at a specific position.
+    #[error("invalid value at position {position}")]
+    InvalidValueAt { position: usize },
+
+    /// Enumeration encoding had an unexpected numeric value.
+    #[error("invalid enum value: 0x{value:04x}")]
+    InvalidEnumValue { value: u16 },
+
+    /// Enumeration encoding had an unexpected numeric value at a position.
+    #[error("invalid enum value: 0x{value:04x} at position {position}")]
+    InvalidEnumValueAt { value: u16, position: usize },
+
+    /// Enumeration encoding had an unexpected string value.
+    #[error("invalid enum string: {invalid:?}")]
+    InvalidEnumString { invalid: Vec<u8> },
+
+    /// Enumeration encoding had an unexpected string value at a position.
+    #[error("invalid enum string: {invalid:?} at position {position}")]
+    InvalidEnumStringAt { invalid: Vec<u8>, position: usize },
+
+    /// I/O error occurred while reading/parsing.
+    #[error("I/O error: {source}")]
+    Io {
+        #[from]
+        source: std::io::Error,
+    },
+
+    /// I/O error at a specific position.
+    #[error("I/O error: {source} at position {position}")]
+    IoAt {
+        source: std::io::Error,
+        position: usize,
+    },
+
+    /// UTF-8 decoding error.
+    #[error("UTF-8 error: {source}")]
+    Utf8 {
+        #[from]
+        source: std::str::Utf8Error,
+    },
+
+    /// UTF-8 decoding error at a specific position.
+    #[error("UTF-8 error: {source} at position {position}")]
+    Utf8At {
+        source: std::str::Utf8Error,
+        position: usize,
+    },
+
+    /// Data ended prematurely: not enough bytes to parse a complete record.
+    #[error("incomplete data (needed: {} bytes)", needed.map_or_else(|| "unknown".to_string(), |n| n.to_string()))]
+    Incomplete { needed: Option<usize> },
+
+    /// Incomplete data at a specific position.
+    #[error("incomplete data at position {position} (needed: {} bytes)", needed.map_or_else(|| "unknown".to_string(), |n| n.to_string()))]
+    IncompleteAt {
+        needed: Option<usize>,
+        position: usize,
+    },
+
+    /// Catch-all variant for ad-hoc messages.
+    #[error("{message}")]
+    Custom { message: Cow<'static, str> },
+
+    /// Ad-hoc message at a specific position.
+    #[error("{message} at position {position}")]
+    CustomAt {
+        message: Cow<'static, str>,
+        position: usize,
+    },
+}
+
+impl ParseError {
+    /// Create a custom error with a message.
+    pub fn custom<M>(message: M) -> Self
+    where
+        M: Into<Cow<'static, str>>,
+    {
+        Self::Custom {
+            message: message.into(),
+        }
+    }
+
+    /// Add position context to this error if it doesn't already have it.
+    pub fn with_position(self, position: usize) -> Self {
+        match self {
+            Self::InvalidChar { value } => Self::InvalidCharAt { value, position },
+            Self::InvalidDate => Self::InvalidDateAt { position },
+            Self::InvalidTimestamp { timestamp } => Self::InvalidTimestampAt {
+                timestamp,
+                position,
+            },
+            Self::InvalidMessageType { value } => Self::InvalidMessageTypeAt { value, position },
+            Self::InvalidValue => Self::InvalidValueAt { position },
+            Self::InvalidEnumValue { value } => Self::InvalidEnumValueAt { value, position },
+            Self::InvalidEnumString { invalid } => Self::InvalidEnumStringAt { invalid, position },
+            Self::Incomplete { needed } => Self::IncompleteAt { needed, position },
+            Self::Io { source } => Self::IoAt { source, position },
+            Self::Utf8 { source } => Self::Utf8At { source, position },
+            Self::Custom { message } => Self::CustomAt { message, position },
+            // Already have position
+            other => other,
+        }
+    }
+}
+
+/// `Io` is cloned lossily: the copy is rebuilt from the original's `ErrorKind` and message, so
+/// the raw OS error code and any inner source error are not preserved.
+impl Clone for ParseError {
+    fn clone(&self) -> Self {
+        match self {
+            Self::InvalidChar { value } => Self::InvalidChar { value: *value },
+            Self::InvalidCharAt { value, position } => Self::InvalidCharAt {
+                value: *value,
+                position: *position,
+            },
+            Self::InvalidDate => Self::InvalidDate,
+            Self::InvalidDateAt { position } => Self::InvalidDateAt {
+                position: *position,
+            },
+            Self::InvalidTimestamp { timestamp } => Self::InvalidTimestamp { timestamp },
+            Self::InvalidTimestampAt {
+                timestamp,
+                position,
+            } => Self::InvalidTimestampAt {
+                timestamp,
+                position: *position,
+            },
+            Self::InvalidMessageType { value } => Self::InvalidMessageType { value: *value },
+            Self::InvalidMessageTypeAt { value, position } => Self::InvalidMessageTypeAt {
+                value: *value,
+                position: *position,
+            },
+            Self::InvalidValue => Self::InvalidValue,
+            Self::InvalidValueAt { position } => Self::InvalidValueAt {
+                position: *position,
+            },
+            Self::InvalidEnumValue { value } => Self::InvalidEnumValue { value: *value },
+            Self::InvalidEnumValueAt { value, position } => Self::InvalidEnumValueAt {
+                value: *value,
+                position: *position,
+            },
+            Self::InvalidEnumString { invalid } => Self::InvalidEnumString {
+                invalid: invalid.clone(),
+            },
+            Self::InvalidEnumStringAt { invalid, position } => Self::InvalidEnumStringAt {
+                invalid: invalid.clone(),
+                position: *position,
+            },
+            Self::Io { source } => Self::Io {
+                source: std::io::Error::new(source.kind(), source.to_string()),
+            },
+            Self::IoAt { source, position } => Self::IoAt {
+                source: std::io::Error::new(source.kind(), source.to_string()),
+                position: *position,
+            },
+            Self::Utf8 { source } => Self::Utf8 { source: *source },
+            Self::Utf8At { source, position } => Self::Utf8At {
+                source: *source,
+                position: *position,
+            },
+            Self::Incomplete { needed } => Self::Incomplete { needed: *needed },
+            Self::IncompleteAt { needed, position } => Self::IncompleteAt {
+                needed: *needed,
+                position: *position,
+            },
+            Self::Custom { message } => Self::Custom {
+                message: message.clone(),
+            },
+            Self::CustomAt { message, position } => Self::CustomAt {
+                message: message.clone(),
+                position: *position,
+            },
+        }
+    }
+}
+
+impl From<ParseError> for std::io::Error {
+    fn from(err: ParseError) -> Self {
+        match err {
+            ParseError::Io { source } => source,
+            ParseError::IoAt { ref source, .. } => std::io::Error::new(source.kind(), err),
+            ParseError::Incomplete { .. } | ParseError::IncompleteAt { .. } => {
+                std::io::Error::new(std::io::ErrorKind::UnexpectedEof, err)
+            }
+            other => std::io::Error::new(std::io::ErrorKind::InvalidData, other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::result::ParseResult;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_error_display() {
+        let err = ParseError::InvalidChar { value: 0x41 };
+        assert!(err.to_string().contains("invalid character: 0x41"));
+
+        let err = ParseError::InvalidTimestamp { timestamp: "TS" };
+        assert!(err.to_string().contains("invalid timestamp: TS"));
+
+        let err = ParseError::Incomplete { needed: Some(5) };
+        assert!(err.to_string().contains("incomplete data"));
+    }
+
+    #[test]
+    fn test_parse_error_with_position() {
+        let err = ParseError::InvalidChar { value: 0xFF }.with_position(42);
+        assert!(matches!(
+            err,
+            ParseError::InvalidCharAt {
+                value: 0xFF,
+                position: 42
+            }
+        ));
+        assert!(err.to_string().contains("position 42"));
+
+        let err = ParseError::InvalidDate.with_position(100);
+        assert!(matches!(err, ParseError::InvalidDateAt { position: 100 }));
+        assert!(err.to_string().contains("position 100"));
+    }
+
+    #[test]
+    fn test_with_position_covers_io_utf8_and_custom() {
+        use std::error::Error;
+
+        let io_err = std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "short read");
+        let err = ParseError::from(io_err).with_position(10);
+        assert!(matches!(err, ParseError::IoAt { position: 10, .. }));
+        assert_eq!(err.to_string(), "I/O error: short read at position 10");
+        assert!(err.source().is_some());
+        assert_eq!(
+            std::io::Error::from(err).kind(),
+            std::io::ErrorKind::UnexpectedEof
+        );
+
+        #[allow(invalid_from_utf8)]
+        let Err(utf8_err) = std::str::from_utf8(b"\xFF") else {
+            panic!("Expected UTF-8 error");
+        };
+        let err = ParseError::from(utf8_err).with_position(3);
+        assert!(matches!(err, ParseError::Utf8At { position: 3, .. }));
+        assert!(err.to_string().ends_with("at position 3"));
+
+        let err = ParseError::custom("bad field").with_position(7);
+        assert_eq!(err.to_string(), "bad field at position 7");
+
+        // positions already attached are kept
+        let err = ParseError::InvalidValueAt { position: 1 }.with_position(99);
+        assert!(matches!(err, ParseError::InvalidValueAt { position: 1 }));
+    }
+
+    #[test]
+    fn test_parse_result_ok() {
+        // Test that ParseResult type alias works correctly with Ok variant
+        fn returns_ok() -> ParseResult<i32> {
+            Ok(42)
+        }
+        let result = returns_ok();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), 42);
+    }
+
+    #[test]
+    fn test_parse_result_err() {
+        // Test that ParseResult type alias works correctly with Err variant
+        fn returns_err() -> ParseResult<i32> {
+            Err(ParseError::InvalidValue)
+        }
+        let result = returns_err();
+        assert!(result.is_err());
+        assert!(matches!(result, Err(ParseError::InvalidValue)));
+    }
+
+    #[test]
+    fn test_error_trait() {
+        // Ensure ParseError implements std::error::Error
+        fn assert_error<E: std::error::Error>(_: E) {}
+        assert_error(ParseError::InvalidChar { value: 0x00 });
+    }
+
+    #[test]
+    fn test_error_source_chain() {
+        use std::error::Error;
+
+        // Test that IO errors chain properly
+        let io_err = std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "test");
+        let parse_err = ParseError::from(io_err);
+        assert!(parse_err.source().is_some());
+
+        // Test that UTF-8 errors chain properly
+        let bytes = b"\xFF\xFF";
+        #[allow(invalid_from_utf8)]
+        let Err(utf8_err) = std::str::from_utf8(bytes) else {
+            panic!("Expected UTF-8 error");
+        };
+        let parse_err = ParseError::from(utf8_err);
+        assert!(parse_err.source().is_some());
+    }
+
+    #[test]
+    fn test_from_conversions() {
+        // Test From<io::Error>
+        let io_err = std::io::Error::new(std::io::ErrorKind::NotFound, "test");
+        let err: ParseError = io_err.into();
+        assert!(matches!(err, ParseError::Io { .. }));
+    }
+
+    #[test]
+    fn test_clone_every_variant() {
+        #[allow(invalid_from_utf8)]
+        let Err(utf8_err) = std::str::from_utf8(b"\xFF") else {
+            panic!("Expected UTF-8 error");
+        };
+
+        let errors = vec![
+            ParseError::InvalidChar { value: 0x41 },
+            ParseError::InvalidCharAt {
+                value: 0x41,
+                position: 1,
+            },
+            ParseError::InvalidDate,
+            ParseError::InvalidDateAt { position: 2 },
+            ParseError::InvalidTimestamp { timestamp: "TS" },
+            ParseError::InvalidTimestampAt {
+                timestamp: "TS",
+                position: 3,
+            },
+            ParseError::InvalidMessageType { value: b'Z' },
+            ParseError::InvalidMessageTypeAt {
+                value: b'Z',
+                position: 4,
+            },
+            ParseError::InvalidValue,
+            ParseError::InvalidValueAt { position: 5 },
+            ParseError::InvalidEnumValue { value: 0xBEEF },
+            ParseError::InvalidEnumValueAt {
+                value: 0xBEEF,
+                position: 6,
+            },
+            ParseError::InvalidEnumString {
+                invalid: b"XX".to_vec(),
+            },
+            ParseError::InvalidEnumStringAt {
+                invalid: b"XX".to_vec(),
+                position: 7,
+            },
+            ParseError::from(std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                "short read",
+            )),
+            ParseError::IoAt {
+                source: std::io::Error::new(std::io::ErrorKind::InvalidData, "bad block"),
+                position: 8,
+            },
+            ParseError::from(utf8_err),
+            ParseError::Utf8At {
+                source: utf8_err,
+                position: 9,
+            },
+            ParseError::Incomplete { needed: Some(8) },
+            ParseError::IncompleteAt {
+                needed: None,
+                position: 10,
+            },
+            ParseError::custom("custom"),
+            ParseError::CustomAt {
+                message: "custom".into(),
+                position: 11,
+            },
+        ];
+        let variants: std::collections::HashSet<_> =
+            errors.iter().map(std::mem::discriminant).collect();
+        assert_eq!(variants.len(), 22, "one error per ParseError variant");
+
+        let cloned = errors.clone();
+        assert_eq!(cloned.len(), errors.len());
+        for (original, copy) in errors.iter().zip(&cloned) {
+            assert_eq!(
+                std::mem::discriminant(original),
+                std::mem::discriminant(copy)
+            );
+            assert_eq!(original.to_string(), copy.to_string());
+        }
+
+        let ParseError::Io { source } = &cloned[14] else {
+            panic!("Expected Io variant");
+        };
+        assert_eq!(source.kind(), std::io::ErrorKind::UnexpectedEof);
+        let ParseError::IoAt { source, position } = &cloned[15] else {
+            panic!("Expected IoAt variant");
+        };
+        assert_eq!(source.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(*position, 8);
+    }
+
+    #[test]
+    fn test_custom_error() {
+        let err = ParseError::custom("something went wrong");
+        assert!(err.to_string().contains("something went wrong"));
+    }
+}
@@ -0,0 +1,462 @@
+use serde::{
+    Serialize,
+    ser::{self, Impossible, SerializeStruct, SerializeStructVariant, Serializer},
+};
+use std::{
+    fmt::Display,
+    io::{self, BufWriter, Write},
+    marker::PhantomData,
+};
+
+/// Rows written between automatic flushes, unless set with [`CsvWriter::flush_every`].
+pub const DEFAULT_CSV_FLUSH_ROWS: usize = 1024;
+
+/// Error from [`CsvWriter`].
+#[derive(Debug, thiserror::Error)]
+pub enum ExportError {
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+
+    /// The record cannot be laid out as one CSV row, e.g. it contains a sequence or map.
+    #[error("cannot export as CSV: {0}")]
+    Unsupported(String),
+
+    /// A record produced different columns than the header, e.g. an `Option` of a struct
+    /// that was `None` in the first record.
+    #[error("record columns {found:?} do not match header {expected:?}")]
+    ColumnMismatch {
+        expected: Vec<String>,
+        found: Vec<String>,
+    },
+}
+
+impl ser::Error for ExportError {
+    fn custom<M: Display>(msg: M) -> Self {
+        ExportError::Unsupported(msg.to_string())
+    }
+}
+
+/// Writes serde records as CSV: a header row of field names, then one row per record.
+///
+/// Nested structs are flattened into dotted columns (a `Price` field `bid` becomes `bid.raw`
+/// and `bid.decimals`), newtypes such as timestamps and `Alpha` use their serialized value,
+/// and `None` is an empty cell. Sequences and maps are rejected.
+pub struct CsvWriter<W: Write, T> {
+    writer: BufWriter<W>,
+    header: Option<Vec<String>>,
+    flush_every: usize,
+    unflushed_rows: usize,
+    cells: Vec<(String, String)>,
+    _marker: PhantomData<fn(&T)>,
+}
+
+impl<W: Write, T: Serialize> CsvWriter<W, T> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer: BufWriter::new(writer),
+            header: None,
+            flush_every: DEFAULT_CSV_FLUSH_ROWS,
+            unflushed_rows: 0,
+            cells: Vec::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Flush to the underlying writer after every `rows` records (at least 1).
+    pub fn flush_every(mut self, rows: usize) -> Self {
+        self.flush_every = rows.max(1);
+        self
+    }
+
+    /// Write `record` as a row, preceded by the header row on the first call.
+    pub fn write(&mut self, record: &T) -> Result<(), ExportError> {
+        self.cells.clear();
+        record.serialize(Flattener {
+            prefix: String::new(),
+            cells: &mut self.cells,
+        })?;
+
+        match &self.header {
+            None => {
+                let header: Vec<String> = self.cells.iter().map(|(name, _)| name.clone()).collect();
+                write_row(&mut self.writer, header.iter().map(String::as_str))?;
+                self.header = Some(header);
+            }
+            Some(header) => {
+                if !header.iter().eq(self.cells.iter().map(|(name, _)| name)) {
+                    return Err(ExportError::ColumnMismatch {
+                        expected: header.clone(),
+                        found: self.cells.iter().map(|(name, _)| name.clone()).collect(),
+                    });
+                }
+            }
+        }
+        write_row(
+            &mut self.writer,
+            self.cells.iter().map(|(_, value)| value.as_str()),
+        )?;
+
+        self.unflushed_rows += 1;
+        if self.unflushed_rows >= self.flush_every {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.unflushed_rows = 0;
+        self.writer.flush()
+    }
+
+    /// Flush and return the underlying writer.
+    pub fn into_inner(self) -> Result<W, ExportError> {
+        self.writer
+            .into_inner()
+            .map_err(|e| ExportError::Io(e.into_error()))
+    }
+}
+
+fn write_row<'a>(writer: &mut impl Write, cells: impl Iterator<Item = &'a str>) -> io::Result<()> {
+    for (i, cell) in cells.enumerate() {
+        if i > 0 {
+            writer.write_all(b",")?;
+        }
+        if cell.contains([',', '"', '\n', '\r']) {
+            write!(writer, "\"{}\"", cell.replace('"', "\"\""))?;
+        } else {
+            writer.write_all(cell.as_bytes())?;
+        }
+    }
+    writer.write_all(b"\n")
+}
+
+/// Serializer collecting `(column, cell)` pairs, prefixing nested field names.
+struct Flattener<'a> {
+    prefix: String,
+    cells: &'a mut Vec<(String, String)>,
+}
+
+impl Flattener<'_> {
+    fn push(self, value: impl ToString) -> Result<(), ExportError> {
+        self.cells.push((self.prefix, value.to_string()));
+        Ok(())
+    }
+
+    fn unsupported(&self, what: &str) -> ExportError {
+        ExportError::Unsupported(format!("{what} in column '{}'", self.prefix))
+    }
+}
+
+/// Serializes the fields of a struct under the parent's prefix.
+struct FieldFlattener<'a> {
+    prefix: String,
+    cells: &'a mut Vec<(String, String)>,
+}
+
+impl FieldFlattener<'_> {
+    fn field<V: Serialize + ?Sized>(&mut self, key: &str, value: &V) -> Result<(), ExportError> {
+        let prefix = if self.prefix.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{key}", self.prefix)
+        };
+        value.serialize(Flattener {
+            prefix,
+            cells: self.cells,
+        })
+    }
+}
+
+impl SerializeStruct for FieldFlattener<'_> {
+    type Ok = ();
+    type Error = ExportError;
+
+    fn serialize_field<V: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &V,
+    ) -> Result<(), ExportError> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<(), ExportError> {
+        Ok(())
+    }
+}
+
+impl SerializeStructVariant for FieldFlattener<'_> {
+    type Ok = ();
+    type Error = ExportError;
+
+    fn serialize_field<V: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &V,
+    ) -> Result<(), ExportError> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<(), ExportError> {
+        Ok(())
+    }
+}
+
+impl<'a> Serializer for Flattener<'a> {
+    type Ok = ();
+    type Error = ExportError;
+    type SerializeSeq = Impossible<(), ExportError>;
+    type SerializeTuple = Impossible<(), ExportError>;
+    type SerializeTupleStruct = Impossible<(), ExportError>;
+    type SerializeTupleVariant = Impossible<(), ExportError>;
+    type SerializeMap = Impossible<(), ExportError>;
+    type SerializeStruct = FieldFlattener<'a>;
+    type SerializeStructVariant = FieldFlattener<'a>;
+
+    fn serialize_bool(self, v: bool) -> Result<(), ExportError> {
+        self.push(v)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<(), ExportError> {
+        self.push(v)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<(), ExportError> {
+        self.push(v)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<(), ExportError> {
+        self.push(v)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<(), ExportError> {
+        self.push(v)
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<(), ExportError> {
+        self.push(v)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<(), ExportError> {
+        self.push(v)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<(), ExportError> {
+        self.push(v)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<(), ExportError> {
+        self.push(v)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<(), ExportError> {
+        self.push(v)
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<(), ExportError> {
+        self.push(v)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<(), ExportError> {
+        self.push(v)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<(), ExportError> {
+        self.push(v)
+    }
+
+    fn serialize_char(self, v: char) -> Result<(), ExportError> {
+        self.push(v)
+    }
+
+    fn serialize_str(self, v: &str) -> Result<(), ExportError> {
+        self.push(v)
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<(), ExportError> {
+        self.push(crate::utils::to_hex(v))
+    }
+
+    fn serialize_none(self) -> Result<(), ExportError> {
+        self.push("")
+    }
+
+    fn serialize_some<V: Serialize + ?Sized>(self, value: &V) -> Result<(), ExportError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), ExportError> {
+        self.push("")
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<(), ExportError> {
+        self.push("")
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<(), ExportError> {
+        self.push(variant)
+    }
+
+    fn serialize_newtype_struct<V: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &V,
+    ) -> Result<(), ExportError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<V: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        value: &V,
+    ) -> Result<(), ExportError> {
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, ExportError> {
+        Err(self.unsupported("sequence"))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, ExportError> {
+        Err(self.unsupported("tuple"))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, ExportError> {
+        Err(self.unsupported("tuple struct"))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, ExportError> {
+        Err(self.unsupported("tuple variant"))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, ExportError> {
+        Err(self.unsupported("map"))
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, ExportError> {
+        Ok(FieldFlattener {
+            prefix: self.prefix,
+            cells: self.cells,
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, ExportError> {
+        Ok(FieldFlattener {
+            prefix: self.prefix,
+            cells: self.cells,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{price::Price, string::Alpha, time::UnixNanoseconds};
+
+    #[derive(Serialize)]
+    struct Trade {
+        symbol: Alpha<6>,
+        price: Price,
+        timestamp: UnixNanoseconds,
+        note: Option<String>,
+    }
+
+    #[test]
+    fn test_writes_header_and_rows() {
+        let mut csv = CsvWriter::new(Vec::new());
+        csv.write(&Trade {
+            symbol: Alpha::new(*b"BBCA  "),
+            price: Price::new_with_decimals(925000, 2),
+            timestamp: UnixNanoseconds(1_700_000_000_000_000_000),
+            note: None,
+        })
+        .unwrap();
+        csv.write(&Trade {
+            symbol: Alpha::new(*b"TLKM  "),
+            price: Price::new(3500),
+            timestamp: UnixNanoseconds(1_700_000_000_000_000_001),
+            note: Some("odd, \"lot\"".to_string()),
+        })
+        .unwrap();
+
+        let out = String::from_utf8(csv.into_inner().unwrap()).unwrap();
+        let lines: Vec<_> = out.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                "symbol,price.raw,price.decimals,timestamp,note",
+                "BBCA,925000,2,1700000000000000000,",
+                "TLKM,3500,0,1700000000000000001,\"odd, \"\"lot\"\"\"",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rejects_sequences_and_changed_columns() {
+        #[derive(Serialize)]
+        struct Batch {
+            ids: Vec<u32>,
+        }
+
+        let mut csv = CsvWriter::new(Vec::new());
+        let err = csv.write(&Batch { ids: vec![1] }).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "cannot export as CSV: sequence in column 'ids'"
+        );
+
+        #[derive(Serialize)]
+        struct Quote {
+            bid: Option<Price>,
+        }
+
+        let mut csv = CsvWriter::new(Vec::new());
+        csv.write(&Quote { bid: None }).unwrap();
+        let err = csv
+            .write(&Quote {
+                bid: Some(Price::new(1)),
+            })
+            .unwrap_err();
+        assert!(matches!(err, ExportError::ColumnMismatch { .. }));
+    }
+
+    #[test]
+    fn test_flushes_every_n_rows() {
+        #[derive(Serialize)]
+        struct Row {
+            n: u32,
+        }
+
+        let mut csv = CsvWriter::new(Vec::new()).flush_every(2);
+        csv.write(&Row { n: 1 }).unwrap();
+        assert!(csv.writer.get_ref().is_empty());
+        csv.write(&Row { n: 2 }).unwrap();
+        assert_eq!(csv.writer.get_ref().as_slice(), b"n\n1\n2\n");
+    }
+}
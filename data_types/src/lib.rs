@@ -0,0 +1,336 @@
+pub mod backoff;
+pub mod error;
+pub mod export;
+pub mod price;
+pub mod result;
+pub mod string;
+pub mod time;
+pub mod utils;
+use std::{io, marker::PhantomData};
+
+pub use error::ParseError;
+pub use result::ParseResult;
+
+use crate::{data_feed_type::DataFeedType, time::UnixNanoseconds};
+pub mod data_feed_type;
+pub mod tracing;
+
+/// Commonly used types and traits for message parsers.
+///
+/// ```
+/// use data_types::prelude::*;
+///
+/// struct Trade {
+///     symbol: Alpha4,
+///     price: Price,
+///     date: Date,
+/// }
+///
+/// impl Parsable for Trade {
+///     const BYTE_LEN: usize = 4;
+///
+///     fn parse(b: &[u8]) -> ParseResult<Self> {
+///         let symbol = b
+///             .first_chunk::<4>()
+///             .ok_or(ParseError::Incomplete { needed: Some(Self::BYTE_LEN) })?;
+///         Ok(Trade {
+///             symbol: Alpha4::new(*symbol),
+///             price: Price::new(9250),
+///             date: Date(20240102),
+///         })
+///     }
+/// }
+///
+/// let trade = Trade::parse(b"BBCA").unwrap();
+/// assert_eq!(trade.symbol.as_str(), "BBCA");
+/// assert_eq!(trade.price, Price::new(9250));
+/// assert_eq!(trade.date.to_string(), "2024-01-02");
+/// assert!(matches!(Trade::parse(b"BB"), Err(ParseError::Incomplete { .. })));
+/// ```
+pub mod prelude {
+    pub use crate::{
+        PacketParser, Parsable, ParseError, ParseResult,
+        data_feed_type::DataFeedType,
+        price::Price,
+        string::{
+            Alpha, Alpha3, Alpha4, Alpha8, Alpha10, Alpha12, Alpha16, Alpha32, Alpha40, Alpha64,
+            Alpha100,
+        },
+        time::prelude::*,
+    };
+}
+
+pub trait Parsable: Sized {
+    /// Number of bytes this type requires to parse
+    const BYTE_LEN: usize;
+
+    /// Parse from a byte slice
+    fn parse(b: &[u8]) -> ParseResult<Self>;
+
+    /// Parse from a byte slice, also returning how many bytes were consumed.
+    ///
+    /// Variable-length types override this; `BYTE_LEN` is then their minimum length.
+    fn parse_with_len(b: &[u8]) -> ParseResult<(Self, usize)> {
+        Ok((Self::parse(b)?, Self::BYTE_LEN))
+    }
+}
+
+pub struct PacketContext<'a> {
+    pub feed_type: Option<&'a DataFeedType>,
+    pub last_timestamp: Option<UnixNanoseconds>,
+    /// Sequence number of the packet being parsed, for payloads that repeat it.
+    pub sequence: Option<u64>,
+}
+
+pub trait PacketParser<T> {
+    /// Parse bytes into T using the optional context
+    fn parse(&self, bytes: &[u8], context: PacketContext) -> io::Result<T>;
+}
+
+/// [`PacketParser`] for any [`Parsable`] type, delegating to `T::parse` and converting
+/// [`ParseError`] into `io::Error`.
+///
+/// ```rust,ignore
+/// SoupBinTcpClient::connect(config, sender, Box::new(ParsableParser::<MyMessage>::new())).await?;
+/// ```
+pub struct ParsableParser<T> {
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> ParsableParser<T> {
+    pub fn new() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for ParsableParser<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> std::fmt::Debug for ParsableParser<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ParsableParser")
+            .field("type", &std::any::type_name::<T>())
+            .finish()
+    }
+}
+
+impl<T: Parsable> PacketParser<T> for ParsableParser<T> {
+    #[inline]
+    fn parse(&self, bytes: &[u8], _context: PacketContext) -> io::Result<T> {
+        T::parse(bytes).map_err(io::Error::from)
+    }
+}
+
+/// [`PacketParser`] that picks a registered parser by `context.feed_type`, so one client can
+/// handle mixed feeds.
+///
+/// Fails with `InvalidInput` when the context has no feed type or none is registered for it.
+pub struct ParserRegistry<T> {
+    parsers: Vec<(DataFeedType, Box<dyn PacketParser<T> + Send + Sync>)>,
+}
+
+impl<T> ParserRegistry<T> {
+    pub fn new() -> Self {
+        Self {
+            parsers: Vec::new(),
+        }
+    }
+
+    /// Register `parser` for `feed_type`, replacing any earlier one.
+    pub fn register(
+        mut self,
+        feed_type: DataFeedType,
+        parser: Box<dyn PacketParser<T> + Send + Sync>,
+    ) -> Self {
+        self.parsers
+            .retain(|(registered, _)| *registered != feed_type);
+        self.parsers.push((feed_type, parser));
+        self
+    }
+
+    pub fn contains(&self, feed_type: DataFeedType) -> bool {
+        self.parsers
+            .iter()
+            .any(|(registered, _)| *registered == feed_type)
+    }
+}
+
+impl<T> Default for ParserRegistry<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> std::fmt::Debug for ParserRegistry<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.parsers.iter().map(|(feed_type, _)| feed_type))
+            .finish()
+    }
+}
+
+impl<T> PacketParser<T> for ParserRegistry<T> {
+    #[inline]
+    fn parse(&self, bytes: &[u8], context: PacketContext) -> io::Result<T> {
+        let feed_type = context.feed_type.ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "packet context has no feed type",
+            )
+        })?;
+
+        match self
+            .parsers
+            .iter()
+            .find(|(registered, _)| registered == feed_type)
+        {
+            Some((_, parser)) => parser.parse(bytes, context),
+            None => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("no parser registered for {feed_type} feed"),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Pair {
+        a: u8,
+        b: u8,
+    }
+
+    impl Parsable for Pair {
+        const BYTE_LEN: usize = 2;
+
+        fn parse(b: &[u8]) -> ParseResult<Self> {
+            match b {
+                [a, b, ..] => Ok(Pair { a: *a, b: *b }),
+                _ => Err(ParseError::Incomplete {
+                    needed: Some(Self::BYTE_LEN),
+                }),
+            }
+        }
+    }
+
+    /// Count-prefixed list of levels.
+    #[derive(Debug, PartialEq)]
+    struct Levels(Vec<u8>);
+
+    impl Parsable for Levels {
+        const BYTE_LEN: usize = 1;
+
+        fn parse(b: &[u8]) -> ParseResult<Self> {
+            Self::parse_with_len(b).map(|(levels, _)| levels)
+        }
+
+        fn parse_with_len(b: &[u8]) -> ParseResult<(Self, usize)> {
+            let (&count, rest) = b.split_first().ok_or(ParseError::Incomplete {
+                needed: Some(Self::BYTE_LEN),
+            })?;
+            let len = Self::BYTE_LEN + count as usize;
+            match rest.get(..count as usize) {
+                Some(levels) => Ok((Levels(levels.to_vec()), len)),
+                None => Err(ParseError::Incomplete { needed: Some(len) }),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_with_len() {
+        assert_eq!(
+            Pair::parse_with_len(&[1, 2, 3]).unwrap(),
+            (Pair { a: 1, b: 2 }, 2)
+        );
+
+        let buf = [2, 7, 8, 1, 9];
+        let (first, used) = Levels::parse_with_len(&buf).unwrap();
+        assert_eq!((first, used), (Levels(vec![7, 8]), 3));
+        let (second, used) = Levels::parse_with_len(&buf[3..]).unwrap();
+        assert_eq!((second, used), (Levels(vec![9]), 2));
+
+        assert!(matches!(
+            Levels::parse_with_len(&[3, 1]),
+            Err(ParseError::Incomplete { needed: Some(4) })
+        ));
+    }
+
+    fn context() -> PacketContext<'static> {
+        PacketContext {
+            feed_type: None,
+            last_timestamp: None,
+            sequence: None,
+        }
+    }
+
+    #[test]
+    fn test_parsable_parser_delegates() {
+        let parser: Box<dyn PacketParser<Pair> + Send + Sync> =
+            Box::new(ParsableParser::<Pair>::new());
+
+        assert_eq!(
+            parser.parse(&[1, 2], context()).unwrap(),
+            Pair { a: 1, b: 2 }
+        );
+
+        let err = parser.parse(&[1], context()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+        assert!(err.to_string().contains("incomplete data"));
+    }
+
+    struct Tagged(u8);
+
+    impl PacketParser<(u8, u8)> for Tagged {
+        fn parse(&self, bytes: &[u8], _context: PacketContext) -> io::Result<(u8, u8)> {
+            Ok((self.0, bytes[0]))
+        }
+    }
+
+    #[test]
+    fn test_parser_registry_dispatches_on_feed_type() {
+        let registry = ParserRegistry::new()
+            .register(DataFeedType::Itch, Box::new(Tagged(1)))
+            .register(DataFeedType::Mdf, Box::new(Tagged(2)));
+        let parse = |feed_type| {
+            registry.parse(
+                &[9],
+                PacketContext {
+                    feed_type,
+                    last_timestamp: None,
+                    sequence: None,
+                },
+            )
+        };
+
+        assert_eq!(parse(Some(&DataFeedType::Itch)).unwrap(), (1, 9));
+        assert_eq!(parse(Some(&DataFeedType::Mdf)).unwrap(), (2, 9));
+        assert_eq!(parse(None).unwrap_err().kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_parser_registry_rejects_unregistered_feed() {
+        let registry = ParserRegistry::new().register(DataFeedType::Itch, Box::new(Tagged(1)));
+        assert!(!registry.contains(DataFeedType::Mdf));
+
+        let err = registry
+            .parse(
+                &[9],
+                PacketContext {
+                    feed_type: Some(&DataFeedType::Mdf),
+                    last_timestamp: None,
+                    sequence: None,
+                },
+            )
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(err.to_string(), "no parser registered for MDF feed");
+    }
+}
@@ -0,0 +1,335 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::{ParseResult, utils::parser_int::parse_i64};
+
+pub const NO_PRICE: i64 = i64::MIN;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[repr(C)]
+pub struct Price {
+    raw: i64,
+    decimals: u8,
+}
+
+impl Price {
+    #[inline]
+    pub const fn new(raw: i64) -> Self {
+        Self { raw, decimals: 0 }
+    }
+
+    #[inline]
+    pub const fn new_with_decimals(raw: i64, decimals: u8) -> Self {
+        Self { raw, decimals }
+    }
+
+    /// Price carrying the [`NO_PRICE`] sentinel.
+    #[inline]
+    pub const fn none() -> Self {
+        Self::new(NO_PRICE)
+    }
+
+    /// Parse an 8-byte big-endian raw price with decimals left at 0.
+    #[inline]
+    pub fn parse_raw(bytes: &[u8]) -> ParseResult<Self> {
+        Self::parse_raw_with_decimals(bytes, 0)
+    }
+
+    /// Parse an 8-byte big-endian raw price with known decimals.
+    ///
+    /// The [`NO_PRICE`] sentinel yields [`Price::none`].
+    #[inline]
+    pub fn parse_raw_with_decimals(bytes: &[u8], decimals: u8) -> ParseResult<Self> {
+        let raw = parse_i64(bytes)?;
+        if raw == NO_PRICE {
+            return Ok(Self::none());
+        }
+        Ok(Self::new_with_decimals(raw, decimals))
+    }
+
+    #[inline]
+    pub fn raw(self) -> i64 {
+        self.raw
+    }
+
+    #[inline]
+    pub fn decimals(self) -> u8 {
+        self.decimals
+    }
+
+    #[inline]
+    pub fn is_none(self) -> bool {
+        self.raw == NO_PRICE
+    }
+
+    /// Set decimals after creation (e.g. once Order Book Directory is known)
+    #[inline]
+    pub fn set_decimals(mut self, decimals: u8) -> Self {
+        self.decimals = decimals;
+        self
+    }
+
+    /// Size of the [`Price::to_le_bytes`] encoding.
+    pub const ENCODED_LEN: usize = 9;
+
+    /// Fixed on-disk layout: `[0..8)` raw as i64 little-endian, `[8]` decimals.
+    ///
+    /// [`NO_PRICE`] is stored verbatim as `i64::MIN`, so it decodes back to a none price.
+    #[inline]
+    pub const fn to_le_bytes(self) -> [u8; Self::ENCODED_LEN] {
+        let raw = self.raw.to_le_bytes();
+        [
+            raw[0],
+            raw[1],
+            raw[2],
+            raw[3],
+            raw[4],
+            raw[5],
+            raw[6],
+            raw[7],
+            self.decimals,
+        ]
+    }
+
+    /// Decode the layout written by [`Price::to_le_bytes`].
+    #[inline]
+    pub const fn from_le_bytes(bytes: [u8; Self::ENCODED_LEN]) -> Self {
+        let raw = i64::from_le_bytes([
+            bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+        ]);
+        Self::new_with_decimals(raw, bytes[8])
+    }
+
+    /// Express the same value with `target_decimals` decimals.
+    ///
+    /// Returns `None` if downscaling would drop non-zero digits or upscaling overflows `i64`.
+    /// [`NO_PRICE`] becomes [`Price::none`], the same sentinel parsing yields.
+    pub fn rescale(self, target_decimals: u8) -> Option<Price> {
+        if self.is_none() {
+            return Some(Self::none());
+        }
+
+        let raw = if target_decimals >= self.decimals {
+            let factor = 10i64.checked_pow((target_decimals - self.decimals) as u32)?;
+            self.raw
+                .checked_mul(factor)
+                .filter(|&raw| raw != NO_PRICE)?
+        } else {
+            match 10i64.checked_pow((self.decimals - target_decimals) as u32) {
+                Some(factor) if self.raw % factor == 0 => self.raw / factor,
+                Some(_) => return None,
+                // any non-zero i64 is smaller than the divisor
+                None if self.raw == 0 => 0,
+                None => return None,
+            }
+        };
+
+        Some(Self::new_with_decimals(raw, target_decimals))
+    }
+
+    /// Notional `raw * qty` widened to `i128`, so it cannot overflow.
+    ///
+    /// The result keeps this price's scale: divide by `10^decimals` for the real value.
+    /// Returns `None` for [`NO_PRICE`].
+    #[inline]
+    pub fn notional_i128(self, qty: u64) -> Option<i128> {
+        if self.is_none() {
+            return None;
+        }
+
+        // |i64| * u64::MAX < 2^127
+        Some(self.raw as i128 * qty as i128)
+    }
+
+    /// Convert to Decimal if possible (both raw != NO_PRICE and decimals known)
+    #[inline]
+    pub fn as_decimal(self) -> Option<Decimal> {
+        if self.is_none() {
+            return None;
+        }
+
+        Some(Decimal::from_i128_with_scale(
+            self.raw as i128,
+            self.decimals as u32,
+        ))
+    }
+}
+
+impl From<i64> for Price {
+    fn from(v: i64) -> Self {
+        Self::new(v)
+    }
+}
+
+impl From<Price> for i64 {
+    fn from(p: Price) -> Self {
+        p.raw
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::Decimal;
+
+    #[test]
+    fn test_new() {
+        let p = Price::new(100);
+        assert_eq!(p.raw(), 100);
+        assert_eq!(p.decimals(), 0);
+        assert!(!p.is_none());
+    }
+
+    #[test]
+    fn test_new_with_decimals() {
+        let p = Price::new_with_decimals(12345, 2);
+        assert_eq!(p.raw(), 12345);
+        assert_eq!(p.decimals(), 2);
+        assert!(!p.is_none());
+    }
+
+    #[test]
+    fn test_is_none() {
+        let p = Price::new(NO_PRICE);
+        assert!(p.is_none());
+
+        let p2 = Price::new(0);
+        assert!(!p2.is_none());
+    }
+
+    #[test]
+    fn test_rescale() {
+        let up = Price::new_with_decimals(12, 1).rescale(3).unwrap();
+        assert_eq!((up.raw(), up.decimals()), (1200, 3));
+
+        let down = Price::new_with_decimals(1200, 3).rescale(1).unwrap();
+        assert_eq!((down.raw(), down.decimals()), (12, 1));
+
+        let same = Price::new_with_decimals(-125, 2).rescale(2).unwrap();
+        assert_eq!((same.raw(), same.decimals()), (-125, 2));
+    }
+
+    #[test]
+    fn test_rescale_rejects_lossy_and_overflow() {
+        assert_eq!(Price::new_with_decimals(125, 2).rescale(1), None);
+        assert_eq!(Price::new_with_decimals(i64::MAX / 10, 0).rescale(2), None);
+        assert_eq!(Price::new_with_decimals(1, 0).rescale(19), None);
+        assert_eq!(Price::new_with_decimals(1, 30).rescale(0), None);
+        assert_eq!(
+            Price::new_with_decimals(0, 30).rescale(0),
+            Some(Price::new_with_decimals(0, 0))
+        );
+    }
+
+    #[test]
+    fn test_rescale_preserves_no_price() {
+        let p = Price::none().rescale(4).unwrap();
+        assert!(p.is_none());
+        assert_eq!(p.decimals(), 0);
+
+        // a sentinel compares equal whether it was parsed or rescaled
+        let parsed = Price::parse_raw_with_decimals(&NO_PRICE.to_be_bytes(), 2).unwrap();
+        assert_eq!(
+            Price::new_with_decimals(NO_PRICE, 2).rescale(4),
+            Some(parsed)
+        );
+        assert_eq!(parsed, Price::none());
+    }
+
+    #[test]
+    fn test_le_bytes_round_trip() {
+        let p = Price::new_with_decimals(0x0102_0304_0506_0708, 4);
+        let bytes = p.to_le_bytes();
+        assert_eq!(bytes, [0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01, 4]);
+        assert_eq!(Price::from_le_bytes(bytes), p);
+
+        for p in [
+            Price::none(),
+            Price::new_with_decimals(NO_PRICE, 2),
+            Price::new_with_decimals(-1, 8),
+            Price::new(i64::MAX),
+        ] {
+            assert_eq!(Price::from_le_bytes(p.to_le_bytes()), p);
+        }
+        assert!(Price::from_le_bytes(Price::none().to_le_bytes()).is_none());
+    }
+
+    #[test]
+    fn test_set_decimals() {
+        let p = Price::new(100).set_decimals(3);
+        assert_eq!(p.decimals(), 3);
+        assert_eq!(p.raw(), 100);
+    }
+
+    #[test]
+    fn test_as_decimal() {
+        let p = Price::new_with_decimals(12345, 2);
+        let dec = p.as_decimal().unwrap();
+        assert_eq!(dec, Decimal::new(12345, 2));
+
+        let none_price = Price::new(NO_PRICE);
+        assert!(none_price.as_decimal().is_none());
+    }
+
+    #[test]
+    fn test_parse_raw() {
+        let bytes = 12345i64.to_be_bytes();
+        assert_eq!(Price::parse_raw(&bytes).unwrap(), Price::new(12345));
+        assert_eq!(
+            Price::parse_raw_with_decimals(&bytes, 2).unwrap(),
+            Price::new_with_decimals(12345, 2)
+        );
+    }
+
+    #[test]
+    fn test_parse_raw_sentinel() {
+        let bytes = NO_PRICE.to_be_bytes();
+        let p = Price::parse_raw_with_decimals(&bytes, 2).unwrap();
+        assert!(p.is_none());
+        assert_eq!(p, Price::none());
+    }
+
+    #[test]
+    fn test_parse_raw_short_slice() {
+        let err = Price::parse_raw(&[0u8; 5]).unwrap_err();
+        assert!(matches!(
+            err,
+            crate::ParseError::Incomplete { needed: Some(3) }
+        ));
+    }
+
+    #[test]
+    fn test_from_i64() {
+        let p: Price = 999i64.into();
+        assert_eq!(p.raw(), 999);
+        assert_eq!(p.decimals(), 0);
+    }
+
+    #[test]
+    fn test_into_i64() {
+        let p = Price::new_with_decimals(777, 2);
+        let raw: i64 = p.into();
+        assert_eq!(raw, 777);
+    }
+
+    #[test]
+    fn test_notional_i128_beyond_i64() {
+        let price = Price::new_with_decimals(1_000_000_000_000, 4);
+        let qty = 50_000_000_000u64;
+        assert!(price.raw().checked_mul(qty as i64).is_none());
+
+        let notional = price.notional_i128(qty).unwrap();
+        assert!(notional > i64::MAX as i128);
+        assert_eq!(notional, 50_000_000_000_000_000_000_000);
+
+        assert_eq!(
+            Price::new(i64::MIN + 1).notional_i128(u64::MAX),
+            Some((i64::MIN + 1) as i128 * u64::MAX as i128)
+        );
+        assert_eq!(
+            Price::new_with_decimals(-25, 2).notional_i128(4),
+            Some(-100)
+        );
+        assert_eq!(Price::none().notional_i128(10), None);
+    }
+}
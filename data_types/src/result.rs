@@ -0,0 +1,3 @@
+use crate::ParseError;
+
+pub type ParseResult<T> = Result<T, ParseError>;
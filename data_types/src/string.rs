@@ -0,0 +1,550 @@
+use crate::{ParseError, result::ParseResult, utils::check_len};
+use core::str::from_utf8_unchecked;
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{Error, Visitor},
+};
+use std::fmt;
+
+struct AlphaVisitor<const N: usize>;
+
+impl<'de, const N: usize> Visitor<'de> for AlphaVisitor<N> {
+    type Value = Alpha<N>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an ASCII string up to length {}", N)
+    }
+
+    fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+        let bytes = value.as_bytes();
+        if bytes.len() > N {
+            return Err(E::custom(format!(
+                "expected at most length {}, got {}",
+                N,
+                bytes.len()
+            )));
+        }
+
+        let mut arr = [b' '; N];
+        arr[..bytes.len()].copy_from_slice(bytes);
+        Ok(Alpha::new(arr))
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
+#[repr(C)]
+pub struct Alpha<const N: usize> {
+    bytes: [u8; N],
+    len: u8,
+}
+
+impl<const N: usize> Alpha<N> {
+    #[inline(always)]
+    pub const fn new(bytes: [u8; N]) -> Self {
+        let mut end = N;
+        while end > 0 && bytes[end - 1] == b' ' {
+            end -= 1;
+        }
+
+        Self {
+            bytes,
+            len: end as u8,
+        }
+    }
+
+    /// Copies the first `N` bytes without checking them, for trusted hot paths whose feed
+    /// guarantees ASCII. Use [`parse_ascii`](Self::parse_ascii) for untrusted input.
+    #[inline(always)]
+    pub fn parse(input: &[u8]) -> ParseResult<Self> {
+        check_len(input, N)?;
+
+        let mut buf = [0u8; N];
+        buf.copy_from_slice(&input[..N]);
+        Ok(Self::new(buf))
+    }
+
+    /// Like [`parse`](Self::parse), but fails with [`ParseError::InvalidChar`] on any
+    /// non-ASCII byte, keeping [`as_str`](Self::as_str) sound.
+    #[inline]
+    pub fn parse_ascii(input: &[u8]) -> ParseResult<Self> {
+        check_len(input, N)?;
+
+        if let Some(&value) = input[..N].iter().find(|b| !b.is_ascii()) {
+            return Err(ParseError::InvalidChar { value });
+        }
+        Self::parse(input)
+    }
+
+    /// Returns the full underlying ASCII string (including padding).
+    ///
+    /// # Safety
+    /// Safe because upstream ensures only ASCII bytes are sent.
+    #[inline(always)]
+    pub fn as_str(&self) -> &str {
+        unsafe {
+            debug_assert!(self.bytes.iter().all(|&b| b.is_ascii()));
+            from_utf8_unchecked(&self.bytes)
+        }
+    }
+
+    /// Returns the trimmed ASCII string (O(1) due to cached len).
+    ///
+    /// # Safety
+    /// Safe because upstream ensures only ASCII bytes are sent.
+    #[inline(always)]
+    pub fn as_trimmed_str(&self) -> &str {
+        unsafe {
+            debug_assert!(self.bytes.iter().all(|&b| b.is_ascii()));
+            from_utf8_unchecked(&self.bytes[..self.len as usize])
+        }
+    }
+
+    /// Returns the full byte slice (including padding).
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8; N] {
+        &self.bytes
+    }
+
+    /// Returns the trimmed length.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Checks if the Alpha value is empty.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Uppercases the ASCII letters in place. Padding is untouched, so the cached len holds.
+    #[inline(always)]
+    pub fn to_ascii_uppercase(mut self) -> Self {
+        self.bytes.make_ascii_uppercase();
+        self
+    }
+
+    /// Lowercases the ASCII letters in place. Padding is untouched, so the cached len holds.
+    #[inline(always)]
+    pub fn to_ascii_lowercase(mut self) -> Self {
+        self.bytes.make_ascii_lowercase();
+        self
+    }
+
+    /// Concatenates the trimmed values of `self` and `other`, optionally joined by `separator`,
+    /// into a space-padded `Alpha<O>`.
+    ///
+    /// Fails with [`ParseError::Custom`](crate::ParseError::Custom) if the result does not fit
+    /// in `O` bytes.
+    pub fn try_concat<const M: usize, const O: usize>(
+        self,
+        other: Alpha<M>,
+        separator: Option<u8>,
+    ) -> ParseResult<Alpha<O>> {
+        let first = &self.bytes[..self.len()];
+        let second = &other.as_bytes()[..other.len()];
+        let needed = first.len() + usize::from(separator.is_some()) + second.len();
+        if needed > O {
+            return Err(crate::ParseError::custom(format!(
+                "concatenation needs {} bytes, Alpha<{}> holds {}",
+                needed, O, O
+            )));
+        }
+
+        let mut bytes = [b' '; O];
+        bytes[..first.len()].copy_from_slice(first);
+        let mut pos = first.len();
+        if let Some(separator) = separator {
+            bytes[pos] = separator;
+            pos += 1;
+        }
+        bytes[pos..pos + second.len()].copy_from_slice(second);
+
+        Ok(Alpha::new(bytes))
+    }
+}
+
+impl<const N: usize> AsRef<str> for Alpha<N> {
+    fn as_ref(&self) -> &str {
+        self.as_trimmed_str()
+    }
+}
+
+impl<const N: usize> core::fmt::Debug for Alpha<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Alpha")
+            .field(&self.as_trimmed_str())
+            .finish()
+    }
+}
+
+impl<const N: usize> core::fmt::Display for Alpha<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_trimmed_str())
+    }
+}
+
+impl<const N: usize> Serialize for Alpha<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_trimmed_str())
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for Alpha<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(AlphaVisitor::<N>)
+    }
+}
+
+/// Serde helpers keeping an [`Alpha`]'s trailing padding, for fixed-width formats.
+///
+/// Serializes all `N` characters and deserializes only strings of exactly `N` characters.
+///
+/// ```
+/// use data_types::string::{Alpha4, alpha_padded};
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Row {
+///     #[serde(with = "alpha_padded")]
+///     board: Alpha4,
+/// }
+/// ```
+pub mod alpha_padded {
+    use super::Alpha;
+    use serde::{
+        Deserializer, Serializer,
+        de::{Error, Visitor},
+    };
+    use std::fmt;
+
+    pub fn serialize<S: Serializer, const N: usize>(
+        alpha: &Alpha<N>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(alpha.as_str())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<Alpha<N>, D::Error> {
+        deserializer.deserialize_str(PaddedVisitor::<N>)
+    }
+
+    struct PaddedVisitor<const N: usize>;
+
+    impl<'de, const N: usize> Visitor<'de> for PaddedVisitor<N> {
+        type Value = Alpha<N>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "an ASCII string of exactly length {}", N)
+        }
+
+        fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+            if !value.is_ascii() {
+                return Err(E::custom(format!("expected ASCII, got {value:?}")));
+            }
+            let bytes: [u8; N] = value
+                .as_bytes()
+                .try_into()
+                .map_err(|_| E::custom(format!("expected length {}, got {}", N, value.len())))?;
+            Ok(Alpha::new(bytes))
+        }
+    }
+}
+
+/// Build an [`Alpha`] from a string literal, inferring `N` from its length.
+///
+/// The value is built at compile time, so a non-ASCII literal fails the build.
+///
+/// ```
+/// use data_types::{alpha, string::Alpha4};
+///
+/// const SYMBOL: Alpha4 = alpha!("BBCA");
+/// assert_eq!(SYMBOL.as_trimmed_str(), "BBCA");
+/// ```
+#[macro_export]
+macro_rules! alpha {
+    ($literal:literal) => {{
+        const BYTES: &[u8] = $literal.as_bytes();
+        const ALPHA: $crate::string::Alpha<{ BYTES.len() }> =
+            $crate::string::Alpha::new($crate::string::ascii_array(BYTES));
+        ALPHA
+    }};
+}
+
+/// Copy `bytes` into an array for [`alpha!`], panicking (at compile time there) on a length
+/// mismatch or a non-ASCII byte.
+#[doc(hidden)]
+pub const fn ascii_array<const N: usize>(bytes: &[u8]) -> [u8; N] {
+    assert!(bytes.len() == N, "alpha! literal length mismatch");
+
+    let mut out = [0u8; N];
+    let mut i = 0;
+    while i < N {
+        assert!(bytes[i].is_ascii(), "alpha! literal must be ASCII");
+        out[i] = bytes[i];
+        i += 1;
+    }
+    out
+}
+
+pub type Alpha3 = Alpha<3>;
+pub type Alpha4 = Alpha<4>;
+pub type Alpha8 = Alpha<8>;
+pub type Alpha10 = Alpha<10>;
+pub type Alpha12 = Alpha<12>;
+pub type Alpha16 = Alpha<16>;
+pub type Alpha32 = Alpha<32>;
+pub type Alpha40 = Alpha<40>;
+pub type Alpha64 = Alpha<64>;
+pub type Alpha100 = Alpha<100>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn test_alpha_new() {
+        let bytes = *b"DATA";
+        let alpha = Alpha4::new(bytes);
+        assert_eq!(alpha.as_bytes(), b"DATA");
+        assert_eq!(alpha.as_str(), "DATA");
+        assert_eq!(alpha.as_trimmed_str(), "DATA");
+    }
+
+    #[test]
+    fn test_alpha_parse_valid() {
+        let bytes = b"ABCD";
+        let alpha = Alpha4::parse(bytes).unwrap();
+        assert_eq!(alpha.as_bytes(), b"ABCD");
+        assert_eq!(alpha.as_str(), "ABCD");
+        assert_eq!(alpha.as_trimmed_str(), "ABCD");
+    }
+
+    #[test]
+    fn test_alpha_parse_short_buffer() {
+        let bytes = b"ABC"; // only 3 bytes, need 4
+        let result = Alpha4::parse(bytes).unwrap_err();
+        assert!(matches!(result, ParseError::Incomplete { needed: Some(1) }));
+    }
+
+    #[test]
+    fn test_alpha_trim_trailing_spaces() {
+        let bytes = b"HI  ";
+        let alpha = Alpha4::parse(bytes).unwrap();
+        assert_eq!(alpha.as_str(), "HI  ");
+        assert_eq!(alpha.as_trimmed_str(), "HI");
+    }
+
+    #[test]
+    fn test_alpha_as_str_includes_spaces() {
+        let bytes = b"XY Z";
+        let alpha = Alpha4::parse(bytes).unwrap();
+        assert_eq!(alpha.as_str(), "XY Z");
+        assert_eq!(alpha.as_trimmed_str(), "XY Z"); // no trailing spaces
+    }
+
+    #[test]
+    fn test_alpha_display_and_debug() {
+        let bytes = b"TEST";
+        let alpha = Alpha4::parse(bytes).unwrap();
+        assert_eq!(format!("{}", alpha), "TEST");
+        assert_eq!(format!("{:?}", alpha), "Alpha(\"TEST\")");
+    }
+
+    #[test]
+    fn test_alpha_with_spaces_only() {
+        let bytes = b"    ";
+        let alpha = Alpha4::parse(bytes).unwrap();
+        assert_eq!(alpha.as_trimmed_str(), "");
+        assert_eq!(format!("{}", alpha), "");
+    }
+
+    #[test]
+    fn test_alpha_larger_sizes() {
+        let bytes = b"ABCDEFGH";
+        let alpha8 = Alpha8::parse(bytes).unwrap();
+        assert_eq!(alpha8.as_trimmed_str(), "ABCDEFGH");
+
+        let bytes10 = b"HELLOWORLD";
+        let alpha10 = Alpha10::parse(bytes10).unwrap();
+        assert_eq!(alpha10.as_trimmed_str(), "HELLOWORLD");
+    }
+
+    #[test]
+    fn test_alpha_empty() {
+        let bytes = b"    ";
+        let alpha = Alpha4::parse(bytes).unwrap();
+        assert!(alpha.is_empty());
+        assert_eq!(alpha.len(), 0);
+    }
+
+    #[test]
+    fn test_alpha_partial_spaces() {
+        let bytes = b"AB  ";
+        let alpha = Alpha4::parse(bytes).unwrap();
+        assert_eq!(alpha.as_trimmed_str(), "AB");
+        assert_eq!(alpha.len(), 2);
+    }
+
+    #[test]
+    fn test_alpha_full_length() {
+        let bytes = b"ABCD";
+        let alpha = Alpha4::parse(bytes).unwrap();
+        assert_eq!(alpha.len(), 4);
+    }
+
+    #[test]
+    fn test_alpha_serialize_deserialize() {
+        let alpha = Alpha4::parse(b"TEST").unwrap();
+        let json = serde_json::to_string(&alpha).unwrap();
+        assert_eq!(json, "\"TEST\"");
+
+        let de: Alpha4 = serde_json::from_str(&json).unwrap();
+        assert_eq!(de.as_trimmed_str(), "TEST");
+    }
+
+    #[test]
+    fn test_alpha_padded_round_trip() {
+        #[derive(Serialize, Deserialize)]
+        struct Row {
+            #[serde(with = "alpha_padded")]
+            board: Alpha4,
+        }
+
+        let row = Row {
+            board: Alpha4::parse(b"AB  ").unwrap(),
+        };
+        let json = serde_json::to_string(&row).unwrap();
+        assert_eq!(json, r#"{"board":"AB  "}"#);
+        // the default impl still trims
+        assert_eq!(serde_json::to_string(&row.board).unwrap(), "\"AB\"");
+
+        let de: Row = serde_json::from_str(&json).unwrap();
+        assert_eq!(de.board.as_bytes(), b"AB  ");
+        assert_eq!(de.board, row.board);
+
+        for json in [r#"{"board":"AB"}"#, r#"{"board":"ABCDE"}"#] {
+            assert!(serde_json::from_str::<Row>(json).is_err(), "{json}");
+        }
+
+        // four bytes, but not ASCII
+        let err = serde_json::from_str::<Row>(r#"{"board":"Aé "}"#)
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("expected ASCII"), "{err}");
+    }
+
+    #[test]
+    fn test_alpha_deserialize_too_long() {
+        let json = "\"TOOLONG\""; // 7 characters for Alpha4
+        let result: Result<Alpha4, _> = serde_json::from_str(json);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_alpha_visitor_expecting_covered() {
+        // This should trigger `visit_*` methods other than visit_str,
+        // causing Serde to fallback and call `expecting`
+        let json = "123"; // a number, not a string
+        let result: Result<Alpha4, _> = serde_json::from_str(json);
+
+        assert!(result.is_err());
+        let err_msg = result.unwrap_err().to_string();
+        assert!(err_msg.contains("an ASCII string up to length 4"));
+    }
+
+    #[test]
+    fn test_alpha_ascii_case_conversion() {
+        let alpha = Alpha4::parse(b"aapl").unwrap();
+        let upper = alpha.to_ascii_uppercase();
+        assert_eq!(upper.as_trimmed_str(), "AAPL");
+        assert_eq!(upper, Alpha4::parse(b"AAPL").unwrap());
+        assert_eq!(upper.to_ascii_lowercase(), alpha);
+
+        let padded = Alpha8::parse(b"Msft    ").unwrap().to_ascii_uppercase();
+        assert_eq!(padded.as_str(), "MSFT    ");
+        assert_eq!(padded.len(), 4);
+    }
+
+    #[test]
+    fn test_alpha_try_concat_fits() {
+        let exchange = Alpha4::parse(b"XNAS").unwrap();
+        let symbol = Alpha8::parse(b"AAPL    ").unwrap();
+
+        let key: Alpha12 = exchange.try_concat(symbol, None).unwrap();
+        assert_eq!(key.as_str(), "XNASAAPL    ");
+        assert_eq!(key.len(), 8);
+
+        let key: Alpha10 = exchange.try_concat(symbol, Some(b':')).unwrap();
+        assert_eq!(key.as_trimmed_str(), "XNAS:AAPL");
+
+        // exact fit
+        let key: Alpha8 = exchange
+            .try_concat(Alpha3::parse(b"ABC").unwrap(), Some(b'.'))
+            .unwrap();
+        assert_eq!(key.as_str(), "XNAS.ABC");
+    }
+
+    #[test]
+    fn test_alpha_try_concat_overflow() {
+        let exchange = Alpha4::parse(b"XNAS").unwrap();
+        let symbol = Alpha8::parse(b"GOOGL   ").unwrap();
+
+        let err = exchange.try_concat::<8, 8>(symbol, None).unwrap_err();
+        assert!(matches!(err, ParseError::Custom { .. }));
+        assert!(err.to_string().contains("needs 9 bytes"));
+
+        assert!(exchange.try_concat::<8, 9>(symbol, Some(b':')).is_err());
+    }
+
+    #[test]
+    fn test_alpha_as_ref() {
+        let bytes = *b"REF ";
+        let alpha = Alpha4::new(bytes);
+        let s: &str = alpha.as_ref();
+        assert_eq!(s, "REF");
+    }
+
+    #[test]
+    fn test_alpha_macro_infers_len() {
+        let ab = alpha!("AB");
+        let typed: Alpha<2> = ab;
+        assert_eq!(typed, Alpha::<2>::parse(b"AB").unwrap());
+
+        const PADDED: Alpha8 = alpha!("BBCA    ");
+        assert_eq!(PADDED, Alpha8::parse(b"BBCA    ").unwrap());
+        assert_eq!(PADDED.as_trimmed_str(), "BBCA");
+        assert_eq!(PADDED.len(), 4);
+
+        let empty = alpha!("");
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "alpha! literal must be ASCII")]
+    fn test_ascii_array_rejects_non_ascii() {
+        let _ = ascii_array::<2>("é".as_bytes());
+    }
+
+    #[test]
+    fn test_parse_ascii_accepts_ascii() {
+        let alpha = Alpha::<4>::parse_ascii(b"AB  tail").unwrap();
+        assert_eq!(alpha.as_trimmed_str(), "AB");
+    }
+
+    #[test]
+    fn test_parse_ascii_rejects_high_byte() {
+        let err = Alpha::<4>::parse_ascii(b"AB\xFFC").unwrap_err();
+        assert!(matches!(err, ParseError::InvalidChar { value: 0xFF }));
+
+        // bytes past N are not part of the value
+        assert!(Alpha::<2>::parse_ascii(b"AB\xFF").is_ok());
+        assert!(matches!(
+            Alpha::<4>::parse_ascii(b"AB"),
+            Err(ParseError::Incomplete { .. })
+        ));
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{ParseError, ParseResult, time::NANO_PER_SEC};
+use serde::{Deserialize, Serialize};
+
+/// Elapsed nanoseconds since the last [`super::UnixSeconds`] message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct ElapsedNanos(pub u32);
+
+impl ElapsedNanos {
+    /// Validated constructor; elapsed nanos within a second must be below [`NANO_PER_SEC`].
+    ///
+    /// Returns [`ParseError::InvalidValue`] otherwise, since adding such a value to a
+    /// seconds-derived timestamp would silently cross into the next second.
+    #[inline]
+    pub const fn new(nanos: u32) -> ParseResult<Self> {
+        if nanos as u64 >= NANO_PER_SEC {
+            return Err(ParseError::InvalidValue);
+        }
+        Ok(ElapsedNanos(nanos))
+    }
+}
+
+impl From<u32> for ElapsedNanos {
+    #[inline]
+    fn from(nanos: u32) -> Self {
+        ElapsedNanos(nanos)
+    }
+}
+
+impl From<ElapsedNanos> for u64 {
+    #[inline]
+    fn from(elapsed: ElapsedNanos) -> Self {
+        elapsed.0 as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_u32() {
+        let elapsed = ElapsedNanos::from(123456789u32);
+        assert_eq!(elapsed.0, 123456789);
+    }
+
+    #[test]
+    fn test_to_u64() {
+        let elapsed = ElapsedNanos(999999999);
+        let as_u64: u64 = elapsed.into();
+        assert_eq!(as_u64, 999999999u64);
+    }
+
+    #[test]
+    fn test_new_accepts_values_below_one_second() {
+        assert_eq!(ElapsedNanos::new(0).unwrap(), ElapsedNanos(0));
+        assert_eq!(
+            ElapsedNanos::new(999_999_999).unwrap(),
+            ElapsedNanos(999_999_999)
+        );
+    }
+
+    #[test]
+    fn test_new_rejects_values_at_or_over_one_second() {
+        assert!(matches!(
+            ElapsedNanos::new(1_000_000_000),
+            Err(ParseError::InvalidValue)
+        ));
+        assert!(matches!(
+            ElapsedNanos::new(u32::MAX),
+            Err(ParseError::InvalidValue)
+        ));
+    }
+
+    #[test]
+    fn test_ordering() {
+        let a = ElapsedNanos(100);
+        let b = ElapsedNanos(200);
+        assert!(a < b);
+        assert_eq!(a, ElapsedNanos(100));
+    }
+}
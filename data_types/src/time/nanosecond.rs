@@ -0,0 +1,330 @@
+use crate::{
+    result::ParseResult,
+    time::{DateTimeUtc, ElapsedNanos, JAKARTA_OFFSET, NANO_PER_SEC, second::UnixSeconds},
+    utils::parser_uint,
+};
+use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::ops::Add;
+
+/// Unix Timestamp in nanoseconds
+///
+/// The `+` operators use plain `+`: overflow panics in debug builds and wraps in release.
+/// Prefer [`checked_add`](Self::checked_add) or [`saturating_add`](Self::saturating_add) on hot
+/// paths where the operand is not known to be small.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct UnixNanoseconds(pub u64);
+
+impl TryFrom<UnixSeconds> for UnixNanoseconds {
+    type Error = &'static str;
+
+    /// Try to convert [`UnixSeconds`] to [`UnixNanoseconds`], checking for overflow
+    #[inline]
+    fn try_from(s: UnixSeconds) -> Result<Self, Self::Error> {
+        s.0.checked_mul(NANO_PER_SEC)
+            .map(UnixNanoseconds)
+            .ok_or("seconds * 1_000_000_000 overflowed u64")
+    }
+}
+
+impl From<u64> for UnixNanoseconds {
+    #[inline]
+    fn from(ns: u64) -> Self {
+        UnixNanoseconds(ns)
+    }
+}
+
+impl From<u32> for UnixNanoseconds {
+    #[inline]
+    fn from(ns: u32) -> Self {
+        UnixNanoseconds(ns as u64)
+    }
+}
+
+impl Add<ElapsedNanos> for UnixNanoseconds {
+    type Output = UnixNanoseconds;
+
+    #[inline]
+    fn add(self, nanos: ElapsedNanos) -> Self::Output {
+        UnixNanoseconds(self.0 + nanos.0 as u64)
+    }
+}
+
+impl Add<u32> for UnixNanoseconds {
+    type Output = UnixNanoseconds;
+
+    #[inline]
+    fn add(self, rhs: u32) -> Self::Output {
+        UnixNanoseconds(self.0 + rhs as u64)
+    }
+}
+
+/// Add u64 nanoseconds directly (for backward compatibility)
+impl Add<u64> for UnixNanoseconds {
+    type Output = UnixNanoseconds;
+
+    #[inline]
+    fn add(self, rhs: u64) -> Self::Output {
+        UnixNanoseconds(self.0 + rhs)
+    }
+}
+
+impl UnixNanoseconds {
+    /// Add `rhs` nanoseconds, returning `None` on overflow.
+    #[inline]
+    pub const fn checked_add(self, rhs: u64) -> Option<Self> {
+        match self.0.checked_add(rhs) {
+            Some(ns) => Some(UnixNanoseconds(ns)),
+            None => None,
+        }
+    }
+
+    /// Add `rhs` nanoseconds, clamping at `u64::MAX`.
+    #[inline]
+    pub const fn saturating_add(self, rhs: u64) -> Self {
+        UnixNanoseconds(self.0.saturating_add(rhs))
+    }
+
+    #[inline]
+    pub fn from_seconds_checked(seconds: u64) -> Result<Self, &'static str> {
+        UnixSeconds(seconds).try_into()
+    }
+
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> ParseResult<Self> {
+        parser_uint::parse_u64(bytes).map(UnixNanoseconds)
+    }
+
+    #[inline]
+    pub fn from_bytes_u32(bytes: &[u8]) -> ParseResult<Self> {
+        parser_uint::parse_u32(bytes).map(|val| UnixNanoseconds(val as u64))
+    }
+
+    /// Convert from `DateTime<Utc>`, keeping sub-second precision.
+    ///
+    /// Returns `None` before the Unix epoch or past `u64::MAX` nanoseconds.
+    #[inline]
+    pub fn from_datetime_utc(dt: &DateTimeUtc) -> Option<Self> {
+        let secs = u64::try_from(dt.timestamp()).ok()?;
+        secs.checked_mul(NANO_PER_SEC)?
+            .checked_add(dt.timestamp_subsec_nanos() as u64)
+            .map(UnixNanoseconds)
+    }
+
+    /// Convert into `DateTime<Utc>`
+    ///
+    /// # Panics
+    /// If chrono cannot represent the timestamp, see [`try_to_utc`](Self::try_to_utc).
+    #[inline]
+    pub fn to_utc(&self) -> DateTimeUtc {
+        self.try_to_utc().expect("valid timestamp")
+    }
+
+    /// Convert into `DateTime<Utc>`, or `None` if chrono cannot represent the timestamp
+    #[inline]
+    pub fn try_to_utc(&self) -> Option<DateTimeUtc> {
+        let secs = (self.0 / NANO_PER_SEC) as i64;
+        let nsec = (self.0 % NANO_PER_SEC) as u32;
+        Utc.timestamp_opt(secs, nsec).single()
+    }
+
+    /// Convert to Jakarta fixed offset time
+    #[inline]
+    pub fn to_local(&self) -> DateTime<FixedOffset> {
+        self.to_utc().with_timezone(&JAKARTA_OFFSET)
+    }
+
+    /// ISO8601 string in local timezone
+    #[inline]
+    pub fn to_iso8601(&self) -> String {
+        self.to_local().to_rfc3339()
+    }
+
+    /// ISO8601 string in local timezone, or `None` if the timestamp is out of range
+    #[inline]
+    pub fn try_to_iso8601(&self) -> Option<String> {
+        self.try_to_utc()
+            .map(|dt| dt.with_timezone(&JAKARTA_OFFSET).to_rfc3339())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn try_from_seconds_ok() {
+        let s = UnixSeconds(1);
+        let ns = UnixNanoseconds::try_from(s).unwrap();
+        assert_eq!(ns.0, 1_000_000_000);
+    }
+
+    #[test]
+    fn try_from_seconds_overflow() {
+        let seconds = 18_446_744_074u64; // exceeds u64::MAX / 1_000_000_000
+        assert!(UnixNanoseconds::from_seconds_checked(seconds).is_err());
+    }
+
+    #[test]
+    fn checked_add_near_max() {
+        let near_max = UnixNanoseconds(u64::MAX - 5);
+        assert_eq!(near_max.checked_add(5), Some(UnixNanoseconds(u64::MAX)));
+        assert_eq!(near_max.checked_add(6), None);
+        assert_eq!(
+            UnixNanoseconds(u64::MAX).checked_add(0),
+            Some(UnixNanoseconds(u64::MAX))
+        );
+        assert_eq!(UnixNanoseconds(1).checked_add(2), Some(UnixNanoseconds(3)));
+    }
+
+    #[test]
+    fn saturating_add_near_max() {
+        let near_max = UnixNanoseconds(u64::MAX - 5);
+        assert_eq!(near_max.saturating_add(5), UnixNanoseconds(u64::MAX));
+        assert_eq!(near_max.saturating_add(6), UnixNanoseconds(u64::MAX));
+        assert_eq!(near_max.saturating_add(u64::MAX), UnixNanoseconds(u64::MAX));
+        assert_eq!(UnixNanoseconds(1).saturating_add(2), UnixNanoseconds(3));
+    }
+
+    #[test]
+    fn from_datetime_utc_round_trip() {
+        let dt: DateTimeUtc = "2025-10-24T02:30:15.123456789Z".parse().unwrap();
+        let ns = UnixNanoseconds::from_datetime_utc(&dt).unwrap();
+        assert_eq!(ns.0, 1_761_273_015_123_456_789);
+        assert_eq!(ns.to_utc(), dt);
+
+        let epoch = Utc.timestamp_opt(0, 0).unwrap();
+        assert_eq!(
+            UnixNanoseconds::from_datetime_utc(&epoch),
+            Some(UnixNanoseconds(0))
+        );
+    }
+
+    #[test]
+    fn from_datetime_utc_out_of_range() {
+        let before_epoch = Utc.timestamp_opt(-1, 500).unwrap();
+        assert_eq!(UnixNanoseconds::from_datetime_utc(&before_epoch), None);
+
+        // year 2554 is past u64::MAX nanoseconds
+        let far_future: DateTimeUtc = "2600-01-01T00:00:00Z".parse().unwrap();
+        assert_eq!(UnixNanoseconds::from_datetime_utc(&far_future), None);
+    }
+
+    #[test]
+    fn to_utc_and_local() {
+        let ns = UnixNanoseconds::try_from(UnixSeconds(0)).unwrap();
+        let dt_utc = ns.to_utc();
+        assert_eq!(dt_utc, Utc.timestamp_opt(0, 0).unwrap());
+
+        let dt_local = ns.to_local();
+        let expected = Utc
+            .timestamp_opt(0, 0)
+            .unwrap()
+            .with_timezone(&JAKARTA_OFFSET);
+        assert_eq!(dt_local, expected);
+    }
+
+    #[test]
+    fn to_iso8601() {
+        let ns = UnixNanoseconds::try_from(UnixSeconds(0)).unwrap();
+        let iso_str = ns.to_iso8601();
+        assert_eq!(iso_str, "1970-01-01T07:00:00+07:00");
+    }
+
+    #[test]
+    fn try_to_utc_covers_full_range() {
+        // every u64 nanosecond count is a valid chrono timestamp
+        let max = UnixNanoseconds(u64::MAX);
+        assert_eq!(max.try_to_utc(), Some(max.to_utc()));
+        assert_eq!(
+            max.try_to_iso8601().as_deref(),
+            Some("2554-07-22T06:34:33.709551615+07:00")
+        );
+    }
+
+    #[test]
+    fn from_u32() {
+        let ns = UnixNanoseconds::from(1234567890u32);
+        assert_eq!(ns.0, 1234567890u64);
+    }
+
+    #[test]
+    fn from_u64() {
+        let ns = UnixNanoseconds::from(1234567890123456789u64);
+        assert_eq!(ns.0, 1234567890123456789u64);
+    }
+
+    #[test]
+    fn from_bytes_u64() {
+        let bytes = [0x00, 0x00, 0x00, 0x00, 0x3B, 0x9A, 0xCA, 0x00]; // 1_000_000_000
+        let ns = UnixNanoseconds::from_bytes(&bytes).unwrap();
+        assert_eq!(ns.0, 1_000_000_000);
+    }
+
+    #[test]
+    fn from_bytes_u32() {
+        let bytes = [0x3B, 0x9A, 0xCA, 0x00]; // 1_000_000_000
+        let ns = UnixNanoseconds::from_bytes_u32(&bytes).unwrap();
+        assert_eq!(ns.0, 1_000_000_000);
+    }
+
+    #[test]
+    fn from_bytes_insufficient_data() {
+        let bytes = [0x3B, 0x9A, 0xCA];
+        assert!(UnixNanoseconds::from_bytes(&bytes).is_err());
+        assert!(UnixNanoseconds::from_bytes_u32(&bytes).is_err());
+    }
+
+    #[test]
+    fn from_bytes_edge_cases() {
+        let zero_bytes = [0u8; 8];
+        let ns = UnixNanoseconds::from_bytes(&zero_bytes).unwrap();
+        assert_eq!(ns.0, 0);
+
+        let max_u32_bytes = [0xFF, 0xFF, 0xFF, 0xFF];
+        let ns = UnixNanoseconds::from_bytes_u32(&max_u32_bytes).unwrap();
+        assert_eq!(ns.0, u32::MAX as u64);
+
+        let max_u64_bytes = [0xFF; 8];
+        let ns = UnixNanoseconds::from_bytes(&max_u64_bytes).unwrap();
+        assert_eq!(ns.0, u64::MAX);
+    }
+
+    #[test]
+    fn test_add_elapsed_nanos() {
+        use crate::time::ElapsedNanos;
+
+        let base = UnixNanoseconds(1_000_000_000);
+        let elapsed = ElapsedNanos(123456789);
+        let result = base + elapsed;
+        assert_eq!(result.0, 1_123_456_789);
+    }
+
+    #[test]
+    fn test_add_u32() {
+        let base = UnixNanoseconds(1_000_000_000);
+        let result = base + 500_000_000u32;
+        assert_eq!(result.0, 1_500_000_000);
+    }
+
+    #[test]
+    fn test_add_u64() {
+        let base = UnixNanoseconds(1_000_000_000);
+        let result = base + 2_000_000_000u64;
+        assert_eq!(result.0, 3_000_000_000);
+    }
+
+    #[test]
+    fn test_seconds_to_nanos_with_elapsed() {
+        use crate::time::{ElapsedNanos, UnixSeconds};
+
+        // Simulating ITCH message timestamp calculation
+        let last_seconds = UnixSeconds(1700000000);
+        let base_nanos = UnixNanoseconds::try_from(last_seconds).unwrap();
+        let elapsed = ElapsedNanos(999999999);
+        let final_timestamp = base_nanos + elapsed;
+
+        assert_eq!(final_timestamp.0, 1700000000 * NANO_PER_SEC + 999999999);
+    }
+}
@@ -0,0 +1,222 @@
+use std::{sync::Mutex, time::Instant};
+
+use hdrhistogram::Histogram;
+use opentelemetry::Context;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+#[derive(Debug, Clone)]
+pub struct TraceData {
+    pub recv_at: Instant,
+    pub ctx: Context,
+}
+
+impl Default for TraceData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TraceData {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            recv_at: std::time::Instant::now(),
+            ctx: Context::new(),
+        }
+    }
+
+    #[inline]
+    pub fn with_current_context() -> Self {
+        Self {
+            recv_at: std::time::Instant::now(),
+            ctx: Context::current(),
+        }
+    }
+
+    #[inline]
+    pub fn elapsed_micros(&self) -> u64 {
+        self.recv_at.elapsed().as_micros() as u64
+    }
+
+    #[inline]
+    pub fn elapsed_millis(&self) -> u64 {
+        self.recv_at.elapsed().as_millis() as u64
+    }
+
+    #[inline]
+    pub fn elapsed_nanos(&self) -> u64 {
+        self.recv_at.elapsed().as_nanos() as u64
+    }
+
+    /// Start a processing-stage span parented to the stored `ctx`, with `recv_latency_us` set to
+    /// the time since the packet was received. `name` becomes the exported OpenTelemetry name.
+    pub fn start_span(&self, name: &'static str) -> ::tracing::Span {
+        let span = ::tracing::info_span!(
+            "trace_data",
+            otel.name = name,
+            recv_latency_us = self.elapsed_micros()
+        );
+        // without an OpenTelemetry layer there is no parent to attach, which is fine
+        let _ = span.set_parent(self.ctx.clone());
+        span
+    }
+}
+
+/// Percentiles of the latencies recorded by a [`LatencyRecorder`], in microseconds.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LatencySnapshot {
+    pub count: u64,
+    pub p50_us: u64,
+    pub p99_us: u64,
+    pub max_us: u64,
+}
+
+/// Histogram of receive-to-processing latency, fed from [`TraceData`].
+///
+/// Tracks 1µs to 60s at 3 significant digits; larger values are clamped to the upper bound.
+#[derive(Debug)]
+pub struct LatencyRecorder {
+    histogram: Mutex<Histogram<u64>>,
+}
+
+impl Default for LatencyRecorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LatencyRecorder {
+    /// Highest trackable latency in microseconds.
+    pub const MAX_TRACKABLE_US: u64 = 60_000_000;
+
+    pub fn new() -> Self {
+        Self {
+            histogram: Mutex::new(
+                Histogram::new_with_bounds(1, Self::MAX_TRACKABLE_US, 3)
+                    .expect("valid histogram bounds"),
+            ),
+        }
+    }
+
+    /// Record the time elapsed since `td` was received.
+    #[inline]
+    pub fn record(&self, td: &TraceData) {
+        self.record_micros(td.elapsed_micros());
+    }
+
+    /// Record a latency in microseconds.
+    #[inline]
+    pub fn record_micros(&self, micros: u64) {
+        self.histogram.lock().unwrap().saturating_record(micros);
+    }
+
+    pub fn snapshot(&self) -> LatencySnapshot {
+        let histogram = self.histogram.lock().unwrap();
+        LatencySnapshot {
+            count: histogram.len(),
+            p50_us: histogram.value_at_quantile(0.50),
+            p99_us: histogram.value_at_quantile(0.99),
+            max_us: histogram.max(),
+        }
+    }
+
+    /// Clear all recorded values, e.g. after reporting an interval.
+    pub fn reset(&self) {
+        self.histogram.lock().unwrap().reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+    use tracing_subscriber::{
+        Layer, Registry,
+        layer::{Context as LayerContext, SubscriberExt},
+        registry::LookupSpan,
+    };
+
+    #[derive(Default, Clone)]
+    struct FieldCapture(Arc<Mutex<Vec<(String, String)>>>);
+
+    impl ::tracing::field::Visit for FieldCapture {
+        fn record_debug(&mut self, field: &::tracing::field::Field, value: &dyn std::fmt::Debug) {
+            self.0
+                .lock()
+                .unwrap()
+                .push((field.name().to_string(), format!("{:?}", value)));
+        }
+    }
+
+    impl<S: ::tracing::Subscriber + for<'a> LookupSpan<'a>> Layer<S> for FieldCapture {
+        fn on_new_span(
+            &self,
+            attrs: &::tracing::span::Attributes<'_>,
+            _id: &::tracing::span::Id,
+            _ctx: LayerContext<'_, S>,
+        ) {
+            attrs.record(&mut self.clone());
+        }
+    }
+
+    #[test]
+    fn test_latency_recorder_percentiles() {
+        let recorder = LatencyRecorder::new();
+        assert_eq!(recorder.snapshot(), LatencySnapshot::default());
+
+        // 98 fast samples, 2 slow outliers
+        for _ in 0..98 {
+            recorder.record_micros(100);
+        }
+        recorder.record_micros(5_000);
+        recorder.record_micros(20_000);
+
+        let snapshot = recorder.snapshot();
+        assert_eq!(snapshot.count, 100);
+        assert!((100..=101).contains(&snapshot.p50_us));
+        assert!((4_995..=5_005).contains(&snapshot.p99_us));
+        assert!((19_980..=20_020).contains(&snapshot.max_us));
+
+        recorder.reset();
+        assert_eq!(recorder.snapshot().count, 0);
+    }
+
+    #[test]
+    fn test_latency_recorder_records_trace_data() {
+        let recorder = LatencyRecorder::new();
+        let td = TraceData {
+            recv_at: Instant::now() - Duration::from_millis(2),
+            ctx: Context::new(),
+        };
+
+        recorder.record(&td);
+        assert!(recorder.snapshot().max_us >= 2_000);
+    }
+
+    #[test]
+    fn test_start_span_records_latency() {
+        let capture = FieldCapture::default();
+        let subscriber = Registry::default().with(capture.clone());
+
+        let td = TraceData {
+            recv_at: Instant::now() - Duration::from_millis(5),
+            ctx: Context::new(),
+        };
+
+        ::tracing::subscriber::with_default(subscriber, || {
+            let _span = td.start_span("parse_itch");
+        });
+
+        let fields = capture.0.lock().unwrap();
+        assert!(fields.contains(&("otel.name".to_string(), "\"parse_itch\"".to_string())));
+        let latency = fields
+            .iter()
+            .find(|(name, _)| name == "recv_latency_us")
+            .map(|(_, value)| value.parse::<u64>().unwrap())
+            .expect("recv_latency_us recorded");
+        assert!(latency >= 5_000);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{ParseError, ParseResult};
+
+/// Parse an optionally signed ASCII decimal such as `-00123.45` into an integer scaled by
+/// `10^scale`, ready for [`Price::new_with_decimals`](crate::price::Price::new_with_decimals).
+///
+/// Fewer than `scale` fractional digits are zero-padded; more is an error, as are stray
+/// characters, a missing digit and values that do not fit in an `i64`.
+pub fn parse_ascii_decimal(b: &[u8], scale: u8) -> ParseResult<i64> {
+    let (negative, digits_start) = match b.first() {
+        Some(b'-') => (true, 1),
+        Some(b'+') => (false, 1),
+        _ => (false, 0),
+    };
+
+    let mut value: i64 = 0;
+    let mut seen_digit = false;
+    let mut fraction_digits: Option<u8> = None;
+
+    for (position, &byte) in b.iter().enumerate().skip(digits_start) {
+        match byte {
+            b'0'..=b'9' => {
+                if let Some(count) = fraction_digits.as_mut() {
+                    if *count == scale {
+                        return Err(ParseError::CustomAt {
+                            message: "too many fractional digits".into(),
+                            position,
+                        });
+                    }
+                    *count += 1;
+                }
+
+                let digit = i64::from(byte - b'0');
+                value = value
+                    .checked_mul(10)
+                    .and_then(|v| {
+                        if negative {
+                            v.checked_sub(digit)
+                        } else {
+                            v.checked_add(digit)
+                        }
+                    })
+                    .ok_or(ParseError::InvalidValueAt { position })?;
+                seen_digit = true;
+            }
+            b'.' if fraction_digits.is_none() => fraction_digits = Some(0),
+            value => return Err(ParseError::InvalidCharAt { value, position }),
+        }
+    }
+
+    if !seen_digit {
+        return Err(ParseError::InvalidValue);
+    }
+
+    let padding = scale - fraction_digits.unwrap_or(0);
+    10i64
+        .checked_pow(u32::from(padding))
+        .and_then(|factor| value.checked_mul(factor))
+        .ok_or(ParseError::InvalidValue)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::price::Price;
+
+    #[test]
+    fn test_parse_negative_and_zero_padded() {
+        assert_eq!(parse_ascii_decimal(b"-00123.45", 2).unwrap(), -12345);
+        assert_eq!(parse_ascii_decimal(b"000123.4", 4).unwrap(), 1_234_000);
+        assert_eq!(parse_ascii_decimal(b"+7", 3).unwrap(), 7000);
+        assert_eq!(parse_ascii_decimal(b"-0.00", 2).unwrap(), 0);
+        assert_eq!(parse_ascii_decimal(b"12.", 1).unwrap(), 120);
+        assert_eq!(parse_ascii_decimal(b".5", 1).unwrap(), 5);
+
+        let price = Price::new_with_decimals(parse_ascii_decimal(b"-00123.45", 2).unwrap(), 2);
+        assert_eq!(price.rescale(4).unwrap().raw(), -1_234_500);
+    }
+
+    #[test]
+    fn test_parse_rejects_over_precise_input() {
+        assert!(matches!(
+            parse_ascii_decimal(b"1.234", 2),
+            Err(ParseError::CustomAt { position: 4, .. })
+        ));
+        assert!(parse_ascii_decimal(b"1.5", 0).is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_input() {
+        assert!(matches!(
+            parse_ascii_decimal(b"12a", 2),
+            Err(ParseError::InvalidCharAt {
+                value: b'a',
+                position: 2
+            })
+        ));
+        assert!(matches!(
+            parse_ascii_decimal(b"1.2.3", 4),
+            Err(ParseError::InvalidCharAt {
+                value: b'.',
+                position: 3
+            })
+        ));
+        assert!(matches!(
+            parse_ascii_decimal(b"--1", 0),
+            Err(ParseError::InvalidCharAt { position: 1, .. })
+        ));
+        assert!(matches!(
+            parse_ascii_decimal(b"-", 2),
+            Err(ParseError::InvalidValue)
+        ));
+        assert!(parse_ascii_decimal(b"", 2).is_err());
+        assert!(parse_ascii_decimal(b" 1", 2).is_err());
+    }
+
+    #[test]
+    fn test_parse_detects_overflow() {
+        assert_eq!(
+            parse_ascii_decimal(b"-9223372036854775808", 0).unwrap(),
+            i64::MIN
+        );
+        assert!(parse_ascii_decimal(b"9223372036854775808", 0).is_err());
+        assert!(parse_ascii_decimal(b"92233720368547758", 4).is_err());
+        assert!(parse_ascii_decimal(b"1", 19).is_err());
+    }
+}
@@ -0,0 +1,201 @@
+use std::marker::PhantomData;
+
+use crate::{ParseError, ParseResult};
+
+/// Byte order used by [`ByteReader`] for multi-byte integers.
+pub trait ByteOrder {
+    fn u16_from(bytes: [u8; 2]) -> u16;
+    fn u32_from(bytes: [u8; 4]) -> u32;
+    fn u64_from(bytes: [u8; 8]) -> u64;
+}
+
+/// Network byte order, used by ITCH and SoupBinTCP.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BigEndian;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LittleEndian;
+
+impl ByteOrder for BigEndian {
+    #[inline(always)]
+    fn u16_from(bytes: [u8; 2]) -> u16 {
+        u16::from_be_bytes(bytes)
+    }
+
+    #[inline(always)]
+    fn u32_from(bytes: [u8; 4]) -> u32 {
+        u32::from_be_bytes(bytes)
+    }
+
+    #[inline(always)]
+    fn u64_from(bytes: [u8; 8]) -> u64 {
+        u64::from_be_bytes(bytes)
+    }
+}
+
+impl ByteOrder for LittleEndian {
+    #[inline(always)]
+    fn u16_from(bytes: [u8; 2]) -> u16 {
+        u16::from_le_bytes(bytes)
+    }
+
+    #[inline(always)]
+    fn u32_from(bytes: [u8; 4]) -> u32 {
+        u32::from_le_bytes(bytes)
+    }
+
+    #[inline(always)]
+    fn u64_from(bytes: [u8; 8]) -> u64 {
+        u64::from_le_bytes(bytes)
+    }
+}
+
+/// Zero-copy cursor over a byte slice that tracks its own offset.
+///
+/// Reads past the end return [`ParseError::Incomplete`] and leave the position unchanged.
+#[derive(Debug, Clone)]
+pub struct ByteReader<'a, E: ByteOrder = BigEndian> {
+    buf: &'a [u8],
+    pos: usize,
+    _order: PhantomData<E>,
+}
+
+impl<'a, E: ByteOrder> ByteReader<'a, E> {
+    #[inline(always)]
+    pub fn new(buf: &'a [u8]) -> Self {
+        Self {
+            buf,
+            pos: 0,
+            _order: PhantomData,
+        }
+    }
+
+    /// Current offset from the start of the slice.
+    #[inline(always)]
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Number of unread bytes.
+    #[inline(always)]
+    pub fn remaining(&self) -> usize {
+        self.buf.len() - self.pos
+    }
+
+    /// Borrow the next `n` bytes and advance past them.
+    #[inline(always)]
+    pub fn read_bytes(&mut self, n: usize) -> ParseResult<&'a [u8]> {
+        let remaining = self.remaining();
+        if remaining < n {
+            return Err(ParseError::Incomplete {
+                needed: Some(n - remaining),
+            });
+        }
+
+        let bytes = &self.buf[self.pos..self.pos + n];
+        self.pos += n;
+        Ok(bytes)
+    }
+
+    #[inline(always)]
+    fn read_array<const N: usize>(&mut self) -> ParseResult<[u8; N]> {
+        Ok(self
+            .read_bytes(N)?
+            .try_into()
+            .expect("read_bytes returned N bytes"))
+    }
+
+    /// Advance `n` bytes without reading them.
+    #[inline(always)]
+    pub fn skip(&mut self, n: usize) -> ParseResult<()> {
+        self.read_bytes(n).map(|_| ())
+    }
+
+    #[inline(always)]
+    pub fn read_u8(&mut self) -> ParseResult<u8> {
+        Ok(self.read_array::<1>()?[0])
+    }
+
+    #[inline(always)]
+    pub fn read_u16(&mut self) -> ParseResult<u16> {
+        self.read_array().map(E::u16_from)
+    }
+
+    #[inline(always)]
+    pub fn read_u32(&mut self) -> ParseResult<u32> {
+        self.read_array().map(E::u32_from)
+    }
+
+    #[inline(always)]
+    pub fn read_u64(&mut self) -> ParseResult<u64> {
+        self.read_array().map(E::u64_from)
+    }
+
+    #[inline(always)]
+    pub fn read_i8(&mut self) -> ParseResult<i8> {
+        self.read_u8().map(|v| v as i8)
+    }
+
+    #[inline(always)]
+    pub fn read_i16(&mut self) -> ParseResult<i16> {
+        self.read_u16().map(|v| v as i16)
+    }
+
+    #[inline(always)]
+    pub fn read_i32(&mut self) -> ParseResult<i32> {
+        self.read_u32().map(|v| v as i32)
+    }
+
+    #[inline(always)]
+    pub fn read_i64(&mut self) -> ParseResult<i64> {
+        self.read_u64().map(|v| v as i64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_mixed_record_big_endian() {
+        let mut record = vec![b'A'];
+        record.extend_from_slice(&0x0102u16.to_be_bytes());
+        record.extend_from_slice(&(-5i32).to_be_bytes());
+        record.extend_from_slice(&42u64.to_be_bytes());
+        record.extend_from_slice(b"PTT ");
+
+        let mut reader = ByteReader::<BigEndian>::new(&record);
+        assert_eq!(reader.read_u8().unwrap(), b'A');
+        assert_eq!(reader.read_u16().unwrap(), 0x0102);
+        assert_eq!(reader.read_i32().unwrap(), -5);
+        assert_eq!(reader.read_u64().unwrap(), 42);
+        assert_eq!(reader.read_bytes(4).unwrap(), b"PTT ");
+        assert_eq!(reader.remaining(), 0);
+        assert_eq!(reader.position(), record.len());
+    }
+
+    #[test]
+    fn test_read_little_endian() {
+        let mut record = Vec::new();
+        record.extend_from_slice(&0x0102u16.to_le_bytes());
+        record.extend_from_slice(&(-7i64).to_le_bytes());
+
+        let mut reader = ByteReader::<LittleEndian>::new(&record);
+        assert_eq!(reader.read_u16().unwrap(), 0x0102);
+        assert_eq!(reader.read_i64().unwrap(), -7);
+    }
+
+    #[test]
+    fn test_read_past_end() {
+        let data = [0u8, 1, 2];
+        let mut reader = ByteReader::<BigEndian>::new(&data);
+        reader.skip(1).unwrap();
+
+        let err = reader.read_u32().unwrap_err();
+        assert!(matches!(err, ParseError::Incomplete { needed: Some(2) }));
+        // failed reads do not advance
+        assert_eq!(reader.position(), 1);
+        assert_eq!(reader.read_u16().unwrap(), 0x0102);
+        assert!(reader.read_u8().is_err());
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{ParseError, ParseResult};
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Encode bytes as lowercase hex, two digits per byte with no separators.
+pub fn to_hex(b: &[u8]) -> String {
+    let mut out = String::with_capacity(b.len() * 2);
+    for &byte in b {
+        out.push(HEX_DIGITS[(byte >> 4) as usize] as char);
+        out.push(HEX_DIGITS[(byte & 0x0f) as usize] as char);
+    }
+    out
+}
+
+/// Decode a hex string (either case) produced by [`to_hex`].
+///
+/// Returns [`ParseError::InvalidChar`] for a non-hex digit, or for the dangling last digit of
+/// odd-length input.
+pub fn from_hex(s: &str) -> ParseResult<Vec<u8>> {
+    let digits = s.as_bytes();
+    if digits.len() % 2 != 0 {
+        return Err(ParseError::InvalidChar {
+            value: digits[digits.len() - 1],
+        });
+    }
+
+    let (pairs, _) = digits.as_chunks::<2>();
+    pairs
+        .iter()
+        .map(|&[high, low]| Ok((hex_value(high)? << 4) | hex_value(low)?))
+        .collect()
+}
+
+#[inline(always)]
+fn hex_value(digit: u8) -> ParseResult<u8> {
+    match digit {
+        b'0'..=b'9' => Ok(digit - b'0'),
+        b'a'..=b'f' => Ok(digit - b'a' + 10),
+        b'A'..=b'F' => Ok(digit - b'A' + 10),
+        _ => Err(ParseError::InvalidChar { value: digit }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let bytes: Vec<u8> = (0..=255).collect();
+        let hex = to_hex(&bytes);
+        assert_eq!(hex.len(), 512);
+        assert!(hex.starts_with("000102"));
+        assert!(hex.ends_with("fdfeff"));
+        assert_eq!(from_hex(&hex).unwrap(), bytes);
+
+        assert_eq!(to_hex(b""), "");
+        assert_eq!(from_hex("").unwrap(), Vec::<u8>::new());
+        assert_eq!(from_hex("DEADbeef").unwrap(), [0xde, 0xad, 0xbe, 0xef]);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(matches!(
+            from_hex("abc"),
+            Err(ParseError::InvalidChar { value: b'c' })
+        ));
+        assert!(matches!(
+            from_hex("0g"),
+            Err(ParseError::InvalidChar { value: b'g' })
+        ));
+        assert!(matches!(
+            from_hex("12 4"),
+            Err(ParseError::InvalidChar { value: b' ' })
+        ));
+    }
+}
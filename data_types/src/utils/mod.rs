@@ -0,0 +1,93 @@
+use crate::{ParseError, ParseResult};
+
+pub mod ascii_decimal;
+pub mod byte_reader;
+pub mod hex;
+pub mod parser_int;
+pub mod parser_uint;
+
+pub use ascii_decimal::parse_ascii_decimal;
+pub use byte_reader::{BigEndian, ByteOrder, ByteReader, LittleEndian};
+pub use hex::{from_hex, to_hex};
+
+#[inline(always)]
+pub fn check_len(b: &[u8], expected: usize) -> ParseResult<()> {
+    let byte_len = b.len();
+    if byte_len < expected {
+        Err(ParseError::Incomplete {
+            needed: Some(expected - byte_len),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+#[inline(always)]
+pub fn parse_boolean(b: &u8) -> ParseResult<bool> {
+    match b {
+        0 => Ok(false),
+        1 => Ok(true),
+        2 => Ok(false),
+        _ => Err(ParseError::InvalidValue),
+    }
+}
+
+/// Pass `value` through if it lies within `min..=max`, else [`ParseError::InvalidValue`].
+/// Unordered values such as `NaN` are rejected.
+///
+/// ```
+/// use data_types::utils::check_range;
+///
+/// let level = check_range(7u8, 0, 10).unwrap();
+/// assert_eq!(level, 7);
+/// assert!(check_range(11u8, 0, 10).is_err());
+/// ```
+#[inline(always)]
+pub fn check_range<T: PartialOrd>(value: T, min: T, max: T) -> ParseResult<T> {
+    if min <= value && value <= max {
+        Ok(value)
+    } else {
+        Err(ParseError::InvalidValue)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_len() {
+        let data = b"ABCD";
+        assert!(check_len(data, 4).is_ok());
+        assert!(check_len(data, 5).is_err());
+    }
+
+    #[test]
+    fn test_check_range() {
+        assert_eq!(check_range(5u8, 0, 10).unwrap(), 5);
+        assert_eq!(check_range(0u8, 0, 10).unwrap(), 0);
+        assert_eq!(check_range(10u8, 0, 10).unwrap(), 10);
+
+        assert!(matches!(
+            check_range(-1i32, 0, 10),
+            Err(ParseError::InvalidValue)
+        ));
+        assert!(matches!(
+            check_range(11i32, 0, 10),
+            Err(ParseError::InvalidValue)
+        ));
+        assert!(matches!(
+            check_range(f64::NAN, 0.0, 1.0),
+            Err(ParseError::InvalidValue)
+        ));
+    }
+
+    #[test]
+    fn test_parse_boolean_variants() {
+        assert!(matches!(parse_boolean(&0), Ok(false)));
+        assert!(matches!(parse_boolean(&1), Ok(true)));
+        assert!(matches!(parse_boolean(&2), Ok(false)));
+        assert!(parse_boolean(&3).is_err());
+        assert!(parse_boolean(&255).is_err());
+    }
+}
@@ -0,0 +1,449 @@
+use crate::{HttpError, TypedHttpClient, middleware};
+use reqwest::{
+    Certificate, Client, Proxy,
+    header::{ACCEPT, HeaderMap, HeaderName, HeaderValue, USER_AGENT},
+};
+use reqwest_middleware::{ClientBuilder, ClientWithMiddleware, Middleware};
+use reqwest_retry::{RetryTransientMiddleware, policies::ExponentialBackoff};
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct HttpClientBuilderConfig {
+    pub timeout: Option<std::time::Duration>,
+    pub connect_timeout: Option<std::time::Duration>,
+    pub max_idle_per_host: Option<usize>,
+    pub default_headers: Option<reqwest::header::HeaderMap>,
+    /// `User-Agent` for every request. Replaces one set in `default_headers`, and is itself
+    /// replaced by [`HttpClientBuilder::user_agent`].
+    pub user_agent: Option<String>,
+}
+
+impl Default for HttpClientBuilderConfig {
+    fn default() -> Self {
+        Self {
+            timeout: Some(std::time::Duration::from_secs(10)),
+            connect_timeout: Some(std::time::Duration::from_secs(5)),
+            max_idle_per_host: Some(8),
+            default_headers: Some({
+                let mut headers = HeaderMap::new();
+                headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
+                headers
+            }),
+            user_agent: None,
+        }
+    }
+}
+pub struct HttpClientBuilder {
+    base: reqwest::ClientBuilder,
+    /// Client built by `try_new` to validate the config, reused until a setter changes `base`
+    client: Option<Client>,
+    /// First invalid setter value, reported by `try_build`
+    error: Option<HttpError>,
+    middleware: Vec<Arc<dyn Middleware>>,
+}
+
+impl HttpClientBuilder {
+    /// Create a builder, see [`try_new`](Self::try_new)
+    ///
+    /// # Panics
+    /// If `config` cannot produce a client, e.g. an invalid user agent.
+    pub fn new(config: Option<HttpClientBuilderConfig>) -> Self {
+        Self::try_new(config).expect("Failed to create base reqwest client")
+    }
+
+    /// Create a builder, returning the error if `config` cannot produce a client
+    pub fn try_new(config: Option<HttpClientBuilderConfig>) -> Result<Self, reqwest::Error> {
+        let mut merged = HttpClientBuilderConfig::default();
+
+        if let Some(custom) = config {
+            merged.timeout = custom.timeout;
+            merged.connect_timeout = custom.connect_timeout;
+            merged.max_idle_per_host = custom.max_idle_per_host;
+            merged.default_headers = custom.default_headers;
+            merged.user_agent = custom.user_agent;
+        }
+
+        // reqwest only reports configuration errors from build(), so build up front and keep it
+        let client = Self::base_builder(&merged).build()?;
+
+        Ok(Self {
+            base: Self::base_builder(&merged),
+            client: Some(client),
+            error: None,
+            middleware: Vec::new(),
+        })
+    }
+
+    fn base_builder(config: &HttpClientBuilderConfig) -> reqwest::ClientBuilder {
+        let mut base = Client::builder();
+
+        if let Some(timeout) = config.timeout {
+            base = base.timeout(timeout);
+        }
+
+        if let Some(default_headers) = &config.default_headers {
+            base = base.default_headers(default_headers.clone());
+        }
+
+        if let Some(user_agent) = &config.user_agent {
+            base = base.user_agent(user_agent);
+        }
+
+        if let Some(max_idle) = config.max_idle_per_host {
+            base = base.pool_max_idle_per_host(max_idle);
+        }
+
+        if let Some(connect_timeout) = config.connect_timeout {
+            base = base.connect_timeout(connect_timeout);
+        }
+
+        base
+    }
+
+    /// Set the default `Accept` header, e.g. `text/csv` for bulk downloads
+    ///
+    /// Overrides any `Accept` from [`HttpClientBuilderConfig::default_headers`]. An invalid
+    /// `mime` is reported by [`try_build`](Self::try_build).
+    pub fn accept(self, mime: &str) -> Self {
+        self.default_header(ACCEPT, mime)
+    }
+
+    /// Set the default `User-Agent` header, an invalid `ua` is reported by
+    /// [`try_build`](Self::try_build)
+    pub fn user_agent(self, ua: &str) -> Self {
+        self.default_header(USER_AGENT, ua)
+    }
+
+    /// Send all requests through the proxy at `url`
+    pub fn with_proxy(mut self, url: &str) -> Result<Self, HttpError> {
+        let proxy = Proxy::all(url)
+            .map_err(|e| HttpError::Config(format!("invalid proxy URL {url:?}: {e}")))?;
+        self.base = self.base.proxy(proxy);
+        self.client = None;
+        Ok(self)
+    }
+
+    /// Trust the PEM encoded certificate(s) in `pem` in addition to the built-in roots
+    pub fn add_root_certificate(mut self, pem: &[u8]) -> Result<Self, HttpError> {
+        let certs = Certificate::from_pem_bundle(pem)
+            .map_err(|e| HttpError::Config(format!("invalid root certificate: {e}")))?;
+        if certs.is_empty() {
+            return Err(HttpError::Config(
+                "invalid root certificate: no PEM certificate found".to_string(),
+            ));
+        }
+
+        for cert in certs {
+            self.base = self.base.add_root_certificate(cert);
+        }
+        self.client = None;
+        Ok(self)
+    }
+
+    fn default_header(mut self, name: HeaderName, value: &str) -> Self {
+        let value = match HeaderValue::from_str(value) {
+            Ok(value) => value,
+            Err(e) => {
+                self.error.get_or_insert_with(|| {
+                    HttpError::Config(format!("invalid {name} header value {value:?}: {e}"))
+                });
+                return self;
+            }
+        };
+        // reqwest merges default headers, replacing only the same name
+        self.base = self
+            .base
+            .default_headers(HeaderMap::from_iter([(name, value)]));
+        self.client = None;
+        self
+    }
+
+    /// Build http client with tracing
+    pub fn with_tracing(self) -> Self {
+        self.with_middleware(middleware::tracing_middleware())
+    }
+
+    /// Build http client with retry middleware
+    pub fn with_retry(self, max_retries: Option<u32>) -> Self {
+        let retry_policy =
+            ExponentialBackoff::builder().build_with_max_retries(max_retries.unwrap_or(3));
+
+        self.with_middleware(RetryTransientMiddleware::new_with_policy(retry_policy))
+    }
+
+    /// Build http client with a shared requests-per-second limit
+    pub fn with_rate_limit(self, rps: u32) -> Self {
+        self.with_middleware(middleware::rate_limit(rps))
+    }
+
+    /// Build http client with a cached, auto-refreshing bearer token
+    pub fn with_bearer_auth<P: middleware::TokenProvider>(self, provider: P) -> Self {
+        self.with_middleware(middleware::bearer_auth(provider))
+    }
+
+    /// Honour a [`middleware::RequestTimeout`] extension set on individual requests
+    pub fn with_per_request_timeout(self) -> Self {
+        self.with_middleware(middleware::per_request_timeout())
+    }
+
+    /// Build http client that rejects response bodies larger than `limit` bytes
+    pub fn with_max_body_size(self, limit: usize) -> Self {
+        self.with_middleware(middleware::max_body_size(limit))
+    }
+
+    /// Build http client recording request counts and latency on `meter`
+    pub fn with_metrics(self, meter: &opentelemetry::metrics::Meter) -> Self {
+        self.with_middleware(middleware::metrics(meter))
+    }
+
+    /// Build http client that sends an `Idempotency-Key` on `POST`/`PATCH`, stable across retries
+    pub fn with_idempotency_key(self) -> Self {
+        self.with_middleware(middleware::idempotency())
+    }
+
+    /// Apply custom middleware
+    pub fn with_middleware<M>(mut self, middleware: M) -> Self
+    where
+        M: reqwest_middleware::Middleware + Send + Sync + 'static,
+    {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Return final reqwest client
+    ///
+    /// # Panics
+    /// If the underlying client cannot be built, see [`try_build`](Self::try_build).
+    pub fn build(self) -> ClientWithMiddleware {
+        self.try_build()
+            .expect("Failed to create base reqwest client")
+    }
+
+    /// Return final reqwest client, or [`HttpError::Config`] if it cannot be built
+    pub fn try_build(self) -> Result<ClientWithMiddleware, HttpError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+
+        let client = match self.client {
+            Some(client) => client,
+            None => self
+                .base
+                .build()
+                .map_err(|e| HttpError::Config(e.to_string()))?,
+        };
+
+        Ok(self
+            .middleware
+            .into_iter()
+            .fold(ClientBuilder::new(client), ClientBuilder::with_arc)
+            .build())
+    }
+
+    /// Return final client wrapped with typed JSON helpers
+    pub fn build_typed(self) -> TypedHttpClient {
+        TypedHttpClient::new(self.build())
+    }
+
+    /// Like [`build_typed`](Self::build_typed), but returns configuration errors
+    pub fn try_build_typed(self) -> Result<TypedHttpClient, HttpError> {
+        self.try_build().map(TypedHttpClient::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{ok_response, spawn_server};
+    use tokio::sync::mpsc;
+
+    /// Server that forwards each request head to the returned receiver.
+    async fn recording_server() -> (String, mpsc::UnboundedReceiver<String>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let url = spawn_server(move |head| {
+            let _ = tx.send(head.to_ascii_lowercase());
+            ok_response(head)
+        })
+        .await;
+        (url, rx)
+    }
+
+    #[tokio::test]
+    async fn test_accept_and_user_agent_are_sent() {
+        let (url, mut heads) = recording_server().await;
+
+        let client = HttpClientBuilder::new(None)
+            .accept("text/csv")
+            .user_agent("polaris-test/1.0")
+            .build();
+        client.get(&url).send().await.unwrap();
+
+        let head = heads.recv().await.unwrap();
+        assert!(head.contains("\r\naccept: text/csv\r\n"), "{head}");
+        assert!(!head.contains("application/json"), "{head}");
+        assert!(
+            head.contains("\r\nuser-agent: polaris-test/1.0\r\n"),
+            "{head}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_accept_composes_with_default_headers() {
+        let (url, mut heads) = recording_server().await;
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-api-key", HeaderValue::from_static("secret"));
+        headers.insert(ACCEPT, HeaderValue::from_static("application/xml"));
+        let config = HttpClientBuilderConfig {
+            default_headers: Some(headers),
+            ..Default::default()
+        };
+
+        let client = HttpClientBuilder::new(Some(config))
+            .accept("text/csv")
+            .with_tracing()
+            .build();
+        client.get(&url).send().await.unwrap();
+
+        let head = heads.recv().await.unwrap();
+        assert!(head.contains("\r\naccept: text/csv\r\n"), "{head}");
+        assert!(head.contains("\r\nx-api-key: secret\r\n"), "{head}");
+
+        // without the setter the config default is kept
+        let client = HttpClientBuilder::new(None).build();
+        client.get(&url).send().await.unwrap();
+        let head = heads.recv().await.unwrap();
+        assert!(head.contains("\r\naccept: application/json\r\n"), "{head}");
+    }
+
+    #[tokio::test]
+    async fn test_with_proxy_routes_requests_through_proxy() {
+        let (proxy_url, mut heads) = recording_server().await;
+
+        let client = HttpClientBuilder::new(None)
+            .with_proxy(&proxy_url)
+            .unwrap()
+            .try_build()
+            .unwrap();
+        client
+            .get("http://upstream.invalid/quotes")
+            .send()
+            .await
+            .unwrap();
+
+        let head = heads.recv().await.unwrap();
+        assert!(
+            head.starts_with("get http://upstream.invalid/quotes http/1.1"),
+            "{head}"
+        );
+    }
+
+    #[test]
+    fn test_bad_proxy_url_is_an_error() {
+        for url in ["not a url", "http://", "://missing-scheme"] {
+            let err = HttpClientBuilder::new(None).with_proxy(url).err();
+            assert!(
+                matches!(&err, Some(HttpError::Config(message)) if message.contains("proxy")),
+                "{url}: {err:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_bad_root_certificate_is_an_error() {
+        let err = HttpClientBuilder::new(None)
+            .add_root_certificate(b"not a certificate")
+            .err();
+        assert!(matches!(err, Some(HttpError::Config(_))), "{err:?}");
+
+        let truncated = b"-----BEGIN CERTIFICATE-----\nMIIB\n";
+        let err = HttpClientBuilder::new(None)
+            .add_root_certificate(truncated)
+            .err();
+        assert!(matches!(err, Some(HttpError::Config(_))), "{err:?}");
+    }
+
+    #[tokio::test]
+    async fn test_try_new_rejects_invalid_user_agent() {
+        let config = HttpClientBuilderConfig {
+            user_agent: Some("polaris\nagent".to_string()),
+            ..Default::default()
+        };
+        assert!(HttpClientBuilder::try_new(Some(config)).is_err());
+
+        let (url, mut heads) = recording_server().await;
+        let config = HttpClientBuilderConfig {
+            user_agent: Some("polaris/2.0".to_string()),
+            ..Default::default()
+        };
+        let client = HttpClientBuilder::try_new(Some(config)).unwrap().build();
+        client.get(&url).send().await.unwrap();
+        let head = heads.recv().await.unwrap();
+        assert!(head.contains("\r\nuser-agent: polaris/2.0\r\n"), "{head}");
+    }
+
+    #[tokio::test]
+    async fn test_user_agent_setter_overrides_config() {
+        let (url, mut heads) = recording_server().await;
+
+        let mut headers = HeaderMap::new();
+        headers.insert(USER_AGENT, HeaderValue::from_static("from-headers/0.1"));
+        let config = HttpClientBuilderConfig {
+            default_headers: Some(headers),
+            user_agent: Some("from-config/1.0".to_string()),
+            ..Default::default()
+        };
+
+        let client = HttpClientBuilder::try_new(Some(config.clone()))
+            .unwrap()
+            .build();
+        client.get(&url).send().await.unwrap();
+        let head = heads.recv().await.unwrap();
+        assert!(
+            head.contains("\r\nuser-agent: from-config/1.0\r\n"),
+            "{head}"
+        );
+
+        let client = HttpClientBuilder::try_new(Some(config))
+            .unwrap()
+            .user_agent("from-setter/2.0")
+            .build();
+        client.get(&url).send().await.unwrap();
+        let head = heads.recv().await.unwrap();
+        assert!(
+            head.contains("\r\nuser-agent: from-setter/2.0\r\n"),
+            "{head}"
+        );
+        assert!(!head.contains("from-config"), "{head}");
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed to create base reqwest client")]
+    fn test_new_panics_on_invalid_config() {
+        let _ = HttpClientBuilder::new(Some(HttpClientBuilderConfig {
+            user_agent: Some("bad\r\nagent".to_string()),
+            ..Default::default()
+        }));
+    }
+
+    #[test]
+    fn test_invalid_header_setter_is_a_build_error() {
+        let err = HttpClientBuilder::new(None)
+            .accept("text/csv\n")
+            .user_agent("polaris\r\nagent")
+            .try_build()
+            .err();
+        assert!(
+            matches!(&err, Some(HttpError::Config(message)) if message.contains("accept")),
+            "{err:?}"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid user-agent header value")]
+    fn test_invalid_user_agent_panics_on_build() {
+        let _ = HttpClientBuilder::new(None)
+            .user_agent("bad\nagent")
+            .build();
+    }
+}
@@ -0,0 +1,177 @@
+use crate::error::{BODY_SNIPPET_LEN, HttpError};
+use reqwest::Response;
+use reqwest_middleware::{ClientWithMiddleware, RequestBuilder};
+use serde::{Serialize, de::DeserializeOwned};
+
+/// JSON convenience wrapper around the client produced by
+/// [`HttpClientBuilder`](crate::HttpClientBuilder).
+#[derive(Debug, Clone)]
+pub struct TypedHttpClient {
+    inner: ClientWithMiddleware,
+}
+
+impl TypedHttpClient {
+    pub fn new(inner: ClientWithMiddleware) -> Self {
+        Self { inner }
+    }
+
+    /// Underlying client, for requests that need more than JSON in/out.
+    pub fn inner(&self) -> &ClientWithMiddleware {
+        &self.inner
+    }
+
+    /// `GET url` and deserialize a 2xx JSON response.
+    pub async fn get_json<T: DeserializeOwned>(&self, url: &str) -> Result<T, HttpError> {
+        Self::send_json(self.inner.get(url)).await
+    }
+
+    /// `POST url` with `body` as JSON and deserialize a 2xx JSON response.
+    pub async fn post_json<B, T>(&self, url: &str, body: &B) -> Result<T, HttpError>
+    where
+        B: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        Self::send_json(self.inner.post(url).json(body)).await
+    }
+
+    async fn send_json<T: DeserializeOwned>(request: RequestBuilder) -> Result<T, HttpError> {
+        let res = Self::error_for_status(request.send().await?).await?;
+        Ok(res.json::<T>().await?)
+    }
+
+    async fn error_for_status(mut res: Response) -> Result<Response, HttpError> {
+        let status = res.status();
+        if status.is_success() {
+            return Ok(res);
+        }
+
+        // read only the snippet; the rest of a large or endless body is dropped unread
+        let mut snippet = Vec::with_capacity(BODY_SNIPPET_LEN);
+        while snippet.len() < BODY_SNIPPET_LEN {
+            match res.chunk().await {
+                Ok(Some(chunk)) => {
+                    let take = chunk.len().min(BODY_SNIPPET_LEN - snippet.len());
+                    snippet.extend_from_slice(&chunk[..take]);
+                }
+                Ok(None) | Err(_) => break,
+            }
+        }
+        drop(res);
+
+        Err(HttpError::Status {
+            status,
+            body: utf8_snippet(snippet),
+        })
+    }
+}
+
+/// Decode `bytes` as text, dropping a character cut off at the end of the snippet.
+fn utf8_snippet(mut bytes: Vec<u8>) -> String {
+    if let Err(e) = std::str::from_utf8(&bytes)
+        && e.error_len().is_none()
+    {
+        bytes.truncate(e.valid_up_to());
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HttpClientBuilder, test_util::spawn_server};
+    use reqwest::StatusCode;
+    use serde::Deserialize;
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Quote {
+        symbol: String,
+        price: u64,
+    }
+
+    fn json_response(status: &str, body: &str) -> Vec<u8> {
+        format!(
+            "HTTP/1.1 {}\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        )
+        .into_bytes()
+    }
+
+    #[tokio::test]
+    async fn test_get_and_post_json() {
+        let url = spawn_server(|head| {
+            if head.starts_with("POST") {
+                json_response("201 Created", r#"{"symbol":"PTT","price":2}"#)
+            } else {
+                json_response("200 OK", r#"{"symbol":"PTT","price":1}"#)
+            }
+        })
+        .await;
+        let client = HttpClientBuilder::new(None).build_typed();
+
+        let quote: Quote = client.get_json(&url).await.unwrap();
+        assert_eq!(quote.price, 1);
+
+        let body = Quote {
+            symbol: "PTT".to_string(),
+            price: 0,
+        };
+        let quote: Quote = client.post_json(&url, &body).await.unwrap();
+        assert_eq!(quote.price, 2);
+    }
+
+    #[tokio::test]
+    async fn test_non_success_status_is_error() {
+        let url =
+            spawn_server(|_| json_response("500 Internal Server Error", r#"{"error":"boom"}"#))
+                .await;
+        let client = HttpClientBuilder::new(None).build_typed();
+
+        let err = client.get_json::<Quote>(&url).await.unwrap_err();
+        match err {
+            HttpError::Status { status, body } => {
+                assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+                assert_eq!(body, r#"{"error":"boom"}"#);
+            }
+            other => panic!("expected status error, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_error_body_reads_only_snippet() {
+        // announces 100 MiB but sends far less and keeps the connection open, so reading the
+        // whole body would never finish
+        let url = spawn_server(|_| {
+            let mut response =
+                b"HTTP/1.1 503 Service Unavailable\r\ncontent-length: 104857600\r\n\r\n".to_vec();
+            response.extend(std::iter::repeat_n(b'x', BODY_SNIPPET_LEN * 8));
+            response
+        })
+        .await;
+        let client = HttpClientBuilder::new(None).build_typed();
+
+        let err = tokio::time::timeout(
+            std::time::Duration::from_secs(5),
+            client.get_json::<Quote>(&url),
+        )
+        .await
+        .expect("error body must not be read to the end")
+        .unwrap_err();
+        match err {
+            HttpError::Status { status, body } => {
+                assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+                assert_eq!(body, "x".repeat(BODY_SNIPPET_LEN));
+            }
+            other => panic!("expected status error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_utf8_snippet_drops_cut_character() {
+        let mut bytes = "ab€".as_bytes().to_vec();
+        bytes.pop();
+        assert_eq!(utf8_snippet(bytes), "ab");
+        assert_eq!(utf8_snippet(b"a\xffb".to_vec()), "a\u{fffd}b");
+    }
+}
@@ -0,0 +1,37 @@
+use reqwest::StatusCode;
+
+/// Maximum number of body bytes kept in [`HttpError::Status`].
+pub const BODY_SNIPPET_LEN: usize = 512;
+
+/// Errors returned by [`TypedHttpClient`](crate::TypedHttpClient).
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum HttpError {
+    /// The request could not be sent or the middleware stack failed.
+    #[error("request failed: {0}")]
+    Request(#[from] reqwest_middleware::Error),
+
+    /// The server answered with a non-2xx status.
+    #[error("unexpected status {status}: {body}")]
+    Status { status: StatusCode, body: String },
+
+    /// The response body could not be read or deserialized.
+    #[error("failed to decode response: {0}")]
+    Decode(#[from] reqwest::Error),
+
+    /// The client could not be built from its configuration (proxy, certificates, ...).
+    #[error("invalid client configuration: {0}")]
+    Config(String),
+}
+
+impl HttpError {
+    /// Status code of the response, if one was received.
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            HttpError::Request(e) => e.status(),
+            HttpError::Status { status, .. } => Some(*status),
+            HttpError::Decode(e) => e.status(),
+            HttpError::Config(_) => None,
+        }
+    }
+}
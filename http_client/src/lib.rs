@@ -0,0 +1,10 @@
+pub mod builder;
+pub mod client;
+pub mod error;
+pub mod middleware;
+pub use builder::HttpClientBuilder;
+pub use client::TypedHttpClient;
+pub use error::HttpError;
+
+#[cfg(test)]
+mod test_util;
@@ -0,0 +1,200 @@
+use async_trait::async_trait;
+use http::{Extensions, HeaderValue, StatusCode, header::AUTHORIZATION};
+use reqwest::{Request, Response};
+use reqwest_middleware::{Error, Middleware, Next, Result};
+use std::{
+    future::Future,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tokio::sync::Mutex;
+
+/// Access token returned by a [`TokenProvider`].
+#[derive(Clone)]
+pub struct BearerToken {
+    pub token: String,
+    /// Lifetime of the token; `None` means it is cached until the server answers 401.
+    pub expires_in: Option<Duration>,
+}
+
+impl std::fmt::Debug for BearerToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BearerToken")
+            .field("token", &"***")
+            .field("expires_in", &self.expires_in)
+            .finish()
+    }
+}
+
+/// Source of fresh bearer tokens, e.g. an OAuth client-credentials call.
+///
+/// Implemented for any `Fn() -> impl Future<Output = Result<BearerToken>>` closure.
+#[async_trait]
+pub trait TokenProvider: Send + Sync + 'static {
+    async fn fetch_token(&self) -> Result<BearerToken>;
+}
+
+#[async_trait]
+impl<F, Fut> TokenProvider for F
+where
+    F: Fn() -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<BearerToken>> + Send,
+{
+    async fn fetch_token(&self) -> Result<BearerToken> {
+        self().await
+    }
+}
+
+struct CachedToken {
+    header: HeaderValue,
+    expires_at: Option<Instant>,
+}
+
+impl CachedToken {
+    fn is_fresh(&self) -> bool {
+        self.expires_at.is_none_or(|at| Instant::now() < at)
+    }
+}
+
+/// Injects `Authorization: Bearer <token>`, caching the token until it expires.
+///
+/// On a 401 response the cached token is dropped and the request is retried once with a new
+/// token, provided the request body can be cloned.
+#[derive(Clone)]
+pub struct BearerAuthMiddleware<P> {
+    provider: Arc<P>,
+    cached: Arc<Mutex<Option<CachedToken>>>,
+}
+
+impl<P: TokenProvider> BearerAuthMiddleware<P> {
+    pub fn new(provider: P) -> Self {
+        Self {
+            provider: Arc::new(provider),
+            cached: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    async fn header(&self) -> Result<HeaderValue> {
+        let mut cached = self.cached.lock().await;
+
+        if let Some(token) = cached.as_ref()
+            && token.is_fresh()
+        {
+            return Ok(token.header.clone());
+        }
+
+        let token = self.provider.fetch_token().await?;
+        let mut header =
+            HeaderValue::from_str(&format!("Bearer {}", token.token)).map_err(Error::middleware)?;
+        header.set_sensitive(true);
+
+        *cached = Some(CachedToken {
+            header: header.clone(),
+            expires_at: token.expires_in.map(|ttl| Instant::now() + ttl),
+        });
+
+        Ok(header)
+    }
+
+    async fn invalidate(&self) {
+        *self.cached.lock().await = None;
+    }
+}
+
+#[async_trait]
+impl<P: TokenProvider> Middleware for BearerAuthMiddleware<P> {
+    async fn handle(
+        &self,
+        mut req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> Result<Response> {
+        let retry = req.try_clone();
+
+        req.headers_mut()
+            .insert(AUTHORIZATION, self.header().await?);
+        let res = next.clone().run(req, extensions).await?;
+
+        match retry {
+            Some(mut retry) if res.status() == StatusCode::UNAUTHORIZED => {
+                self.invalidate().await;
+                retry
+                    .headers_mut()
+                    .insert(AUTHORIZATION, self.header().await?);
+                next.run(retry, extensions).await
+            }
+            _ => Ok(res),
+        }
+    }
+}
+
+/// Create the bearer auth middleware to be used in HttpClientBuilder
+pub fn bearer_auth<P: TokenProvider>(provider: P) -> BearerAuthMiddleware<P> {
+    BearerAuthMiddleware::new(provider)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HttpClientBuilder, test_util::spawn_server};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn counting_provider(calls: Arc<AtomicUsize>) -> impl TokenProvider {
+        move || {
+            let calls = Arc::clone(&calls);
+            async move {
+                let n = calls.fetch_add(1, Ordering::SeqCst) + 1;
+                Ok(BearerToken {
+                    token: format!("token-{}", n),
+                    expires_in: Some(Duration::from_secs(60)),
+                })
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bearer_auth_caches_token() {
+        let url = spawn_server(|head| {
+            let status = if head.contains("authorization: Bearer token-1\r\n") {
+                "200 OK"
+            } else {
+                "400 Bad Request"
+            };
+            format!("HTTP/1.1 {}\r\ncontent-length: 0\r\n\r\n", status).into_bytes()
+        })
+        .await;
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let client = HttpClientBuilder::new(None)
+            .with_bearer_auth(counting_provider(Arc::clone(&calls)))
+            .build();
+
+        for _ in 0..2 {
+            let res = client.get(&url).send().await.unwrap();
+            assert_eq!(res.status(), StatusCode::OK);
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_bearer_auth_refreshes_once_on_401() {
+        let url = spawn_server(|head| {
+            let status = if head.contains("authorization: Bearer token-2\r\n") {
+                "200 OK"
+            } else {
+                "401 Unauthorized"
+            };
+            format!("HTTP/1.1 {}\r\ncontent-length: 0\r\n\r\n", status).into_bytes()
+        })
+        .await;
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let client = HttpClientBuilder::new(None)
+            .with_bearer_auth(counting_provider(Arc::clone(&calls)))
+            .build();
+
+        let res = client.get(&url).send().await.unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+}
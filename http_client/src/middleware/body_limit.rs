@@ -0,0 +1,148 @@
+use async_trait::async_trait;
+use http::Extensions;
+use http_body_util::{BodyExt, LengthLimitError, Limited};
+use reqwest::{Body, Request, Response, ResponseBuilderExt};
+use reqwest_middleware::{Error, Middleware, Next, Result};
+
+/// Raised when a response body is larger than the configured limit.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum BodyLimitError {
+    /// The advertised `Content-Length` is over the limit; the body was not read.
+    #[error("response Content-Length {content_length} exceeds limit of {limit} bytes")]
+    ContentLengthTooLarge { content_length: u64, limit: usize },
+
+    /// A body without a usable `Content-Length` grew past the limit while being read.
+    #[error("response body exceeded limit of {limit} bytes")]
+    BodyTooLarge { limit: usize },
+}
+
+/// Rejects responses whose body is larger than `limit` bytes.
+///
+/// Oversized `Content-Length` values fail the request up front. Other bodies are capped while
+/// streaming, so `.text()`/`.bytes()` return an error instead of buffering past the limit.
+#[derive(Debug, Clone, Copy)]
+pub struct MaxBodySizeMiddleware {
+    limit: usize,
+}
+
+impl MaxBodySizeMiddleware {
+    pub fn new(limit: usize) -> Self {
+        Self { limit }
+    }
+}
+
+#[async_trait]
+impl Middleware for MaxBodySizeMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> Result<Response> {
+        let res = next.run(req, extensions).await?;
+        let limit = self.limit;
+
+        if let Some(content_length) = res.content_length()
+            && content_length > limit as u64
+        {
+            return Err(Error::middleware(BodyLimitError::ContentLengthTooLarge {
+                content_length,
+                limit,
+            }));
+        }
+
+        // the http::Response round trip drops the URL, so carry it over explicitly
+        let url = res.url().clone();
+        let (mut parts, body) = http::Response::<Body>::from(res).into_parts();
+        let (url_parts, _) = http::Response::builder()
+            .url(url)
+            .body(())
+            .map_err(Error::middleware)?
+            .into_parts();
+        parts.extensions.extend(url_parts.extensions);
+
+        let body = Limited::new(body, limit).map_err(move |e| {
+            if e.is::<LengthLimitError>() {
+                Box::new(BodyLimitError::BodyTooLarge { limit }) as _
+            } else {
+                e
+            }
+        });
+
+        Ok(http::Response::from_parts(parts, Body::wrap(body)).into())
+    }
+}
+
+/// Create the response body size limit middleware to be used in HttpClientBuilder
+pub fn max_body_size(limit: usize) -> MaxBodySizeMiddleware {
+    MaxBodySizeMiddleware::new(limit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HttpClientBuilder, test_util::spawn_server};
+    use std::error::Error as _;
+
+    #[tokio::test]
+    async fn test_rejects_large_content_length() {
+        let url =
+            spawn_server(|_| b"HTTP/1.1 200 OK\r\ncontent-length: 10000000\r\n\r\n".to_vec()).await;
+        let client = HttpClientBuilder::new(None)
+            .with_max_body_size(1024)
+            .build();
+
+        let err = client.get(&url).send().await.unwrap_err();
+        let Error::Middleware(err) = err else {
+            panic!("expected middleware error, got {err:?}");
+        };
+        assert!(matches!(
+            err.downcast_ref::<BodyLimitError>(),
+            Some(BodyLimitError::ContentLengthTooLarge {
+                content_length: 10_000_000,
+                limit: 1024
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_caps_chunked_body() {
+        let url = spawn_server(|_| {
+            let mut res = b"HTTP/1.1 200 OK\r\ntransfer-encoding: chunked\r\n\r\n".to_vec();
+            res.extend_from_slice(format!("{:x}\r\n", 2048).as_bytes());
+            res.extend_from_slice(&[b'a'; 2048]);
+            res.extend_from_slice(b"\r\n0\r\n\r\n");
+            res
+        })
+        .await;
+        let client = HttpClientBuilder::new(None)
+            .with_max_body_size(1024)
+            .build();
+
+        let res = client.get(&url).send().await.unwrap();
+        assert_eq!(res.url().as_str(), url);
+
+        let err = res.bytes().await.unwrap_err();
+        let mut source = err.source();
+        while let Some(inner) = source {
+            if inner.is::<BodyLimitError>() {
+                return;
+            }
+            source = inner.source();
+        }
+        panic!("expected BodyLimitError in source chain, got {err:?}");
+    }
+
+    #[tokio::test]
+    async fn test_allows_small_body() {
+        let url =
+            spawn_server(|_| b"HTTP/1.1 200 OK\r\ncontent-length: 5\r\n\r\nhello".to_vec()).await;
+        let client = HttpClientBuilder::new(None)
+            .with_max_body_size(1024)
+            .build();
+
+        let body = client.get(&url).send().await.unwrap().text().await.unwrap();
+        assert_eq!(body, "hello");
+    }
+}
@@ -0,0 +1,149 @@
+use async_trait::async_trait;
+use http::{Extensions, HeaderName, HeaderValue};
+use reqwest::{Request, Response};
+use reqwest_middleware::{Middleware, Next, Result};
+use uuid_generator::UUID;
+
+/// Header carrying the idempotency key.
+pub const IDEMPOTENCY_KEY: HeaderName = HeaderName::from_static("idempotency-key");
+
+/// Request extension with the idempotency key to send, instead of a generated one.
+///
+/// ```rust,ignore
+/// client
+///     .post(url)
+///     .with_extension(IdempotencyKey(order_id.to_string()))
+///     .send()
+///     .await?;
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdempotencyKey(pub String);
+
+/// Sets an `Idempotency-Key` header on non-idempotent requests (`POST`, `PATCH`).
+///
+/// The key comes from an [`IdempotencyKey`] extension, or a fresh UUID v4 that is stored back
+/// into the extensions, so retries by `RetryTransientMiddleware` send the same key whichever
+/// order the two are installed in. An `Idempotency-Key` header set on the request is kept.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IdempotencyMiddleware;
+
+#[async_trait]
+impl Middleware for IdempotencyMiddleware {
+    async fn handle(
+        &self,
+        mut req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> Result<Response> {
+        if !req.method().is_idempotent() && !req.headers().contains_key(IDEMPOTENCY_KEY) {
+            let key = extensions
+                .get_or_insert_with(|| IdempotencyKey(UUID::new_v4().hyphenated()))
+                .clone();
+            let value =
+                HeaderValue::try_from(key.0).map_err(reqwest_middleware::Error::middleware)?;
+            req.headers_mut().insert(IDEMPOTENCY_KEY, value);
+        }
+        next.run(req, extensions).await
+    }
+}
+
+/// Create the idempotency key middleware to be used in HttpClientBuilder
+pub fn idempotency() -> IdempotencyMiddleware {
+    IdempotencyMiddleware
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HttpClientBuilder, test_util::spawn_server};
+    use reqwest_retry::{RetryTransientMiddleware, policies::ExponentialBackoff};
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        time::Duration,
+    };
+    use tokio::sync::mpsc;
+
+    /// Server failing the first `failures` requests with a 503, forwarding each request's
+    /// idempotency key (if any) to the receiver.
+    async fn flaky_server(failures: usize) -> (String, mpsc::UnboundedReceiver<Option<String>>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let seen = Arc::new(AtomicUsize::new(0));
+        let url = spawn_server(move |head| {
+            let key = head.lines().find_map(|line| {
+                let (name, value) = line.split_once(':')?;
+                name.eq_ignore_ascii_case("idempotency-key")
+                    .then(|| value.trim().to_string())
+            });
+            let _ = tx.send(key);
+            if seen.fetch_add(1, Ordering::SeqCst) < failures {
+                b"HTTP/1.1 503 Service Unavailable\r\ncontent-length: 0\r\n\r\n".to_vec()
+            } else {
+                b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n".to_vec()
+            }
+        })
+        .await;
+        (url, rx)
+    }
+
+    fn fast_retry() -> RetryTransientMiddleware<ExponentialBackoff> {
+        let policy = ExponentialBackoff::builder()
+            .retry_bounds(Duration::from_millis(1), Duration::from_millis(10))
+            .build_with_max_retries(3);
+        RetryTransientMiddleware::new_with_policy(policy)
+    }
+
+    #[tokio::test]
+    async fn test_retried_request_reuses_key() {
+        let (url, mut keys) = flaky_server(2).await;
+        // idempotency inside the retry loop runs once per attempt
+        let client = HttpClientBuilder::new(None)
+            .with_middleware(fast_retry())
+            .with_idempotency_key()
+            .build();
+
+        let response = client.post(&url).send().await.unwrap();
+        assert_eq!(response.status(), 200);
+
+        let sent: Vec<_> = std::iter::from_fn(|| keys.try_recv().ok()).collect();
+        assert_eq!(sent.len(), 3);
+        let key = sent[0].clone().expect("key header set");
+        assert!(key.parse::<UUID>().is_ok(), "{key}");
+        assert!(sent.iter().all(|k| k.as_deref() == Some(key.as_str())));
+
+        // the next request gets a new key
+        client.post(&url).send().await.unwrap();
+        assert_ne!(keys.recv().await.unwrap(), Some(key));
+    }
+
+    #[tokio::test]
+    async fn test_uses_supplied_key_and_skips_idempotent_methods() {
+        let (url, mut keys) = flaky_server(1).await;
+        let client = HttpClientBuilder::new(None)
+            .with_idempotency_key()
+            .with_middleware(fast_retry())
+            .build();
+
+        client
+            .post(&url)
+            .with_extension(IdempotencyKey("order-42".to_string()))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(keys.recv().await.unwrap().as_deref(), Some("order-42"));
+        assert_eq!(keys.recv().await.unwrap().as_deref(), Some("order-42"));
+
+        client.get(&url).send().await.unwrap();
+        assert_eq!(keys.recv().await.unwrap(), None);
+
+        client
+            .patch(&url)
+            .header(IDEMPOTENCY_KEY, "explicit")
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(keys.recv().await.unwrap().as_deref(), Some("explicit"));
+    }
+}
@@ -0,0 +1,162 @@
+use async_trait::async_trait;
+use http::Extensions;
+use opentelemetry::{
+    KeyValue,
+    metrics::{Counter, Histogram, Meter},
+};
+use reqwest::{Request, Response};
+use reqwest_middleware::{Middleware, Next, Result};
+use std::time::Instant;
+
+/// Records a request counter and a latency histogram per method, host and status class
+/// (`2xx`, `4xx`, ..., or `error` when no response was received).
+///
+/// Timing uses a local clock rather than the `Instant` extension of the tracing middleware, so
+/// the two can be installed in any order.
+#[derive(Debug, Clone)]
+pub struct MetricsMiddleware {
+    requests: Counter<u64>,
+    duration: Histogram<f64>,
+}
+
+impl MetricsMiddleware {
+    /// Register the instruments on `meter`, e.g. `opentelemetry::global::meter("http_client")`.
+    pub fn new(meter: &Meter) -> Self {
+        Self {
+            requests: meter
+                .u64_counter("http.client.requests")
+                .with_description("Number of HTTP requests sent")
+                .build(),
+            duration: meter
+                .f64_histogram("http.client.request.duration")
+                .with_description("Duration of HTTP requests")
+                .with_unit("s")
+                .build(),
+        }
+    }
+}
+
+fn status_class(result: &Result<Response>) -> &'static str {
+    match result
+        .as_ref()
+        .map(|response| response.status().as_u16() / 100)
+    {
+        Ok(1) => "1xx",
+        Ok(2) => "2xx",
+        Ok(3) => "3xx",
+        Ok(4) => "4xx",
+        Ok(5) => "5xx",
+        Ok(_) | Err(_) => "error",
+    }
+}
+
+#[async_trait]
+impl Middleware for MetricsMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> Result<Response> {
+        let method = req.method().to_string();
+        let host = req.url().host_str().unwrap_or_default().to_string();
+
+        let start = Instant::now();
+        let result = next.run(req, extensions).await;
+        let elapsed = start.elapsed().as_secs_f64();
+
+        let attributes = [
+            KeyValue::new("http.request.method", method),
+            KeyValue::new("server.address", host),
+            KeyValue::new("http.response.status_class", status_class(&result)),
+        ];
+        self.requests.add(1, &attributes);
+        self.duration.record(elapsed, &attributes);
+
+        result
+    }
+}
+
+/// Create the metrics middleware to be used in HttpClientBuilder
+pub fn metrics(meter: &Meter) -> MetricsMiddleware {
+    MetricsMiddleware::new(meter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        HttpClientBuilder,
+        test_util::{ok_response, spawn_server},
+    };
+    use opentelemetry::metrics::MeterProvider;
+    use opentelemetry_sdk::metrics::{
+        InMemoryMetricExporter, PeriodicReader, SdkMeterProvider,
+        data::{AggregatedMetrics, MetricData},
+    };
+
+    fn attribute<'a>(attributes: impl Iterator<Item = &'a KeyValue>, key: &str) -> String {
+        attributes
+            .filter(|kv| kv.key.as_str() == key)
+            .map(|kv| kv.value.to_string())
+            .next()
+            .unwrap_or_default()
+    }
+
+    #[tokio::test]
+    async fn test_successful_request_increments_2xx_counter() {
+        let exporter = InMemoryMetricExporter::default();
+        let provider = SdkMeterProvider::builder()
+            .with_reader(PeriodicReader::builder(exporter.clone()).build())
+            .build();
+        let meter = provider.meter("http_client_test");
+
+        let url = spawn_server(ok_response).await;
+        let client = HttpClientBuilder::new(None)
+            .with_tracing()
+            .with_metrics(&meter)
+            .build();
+        client.get(&url).send().await.unwrap();
+        client.get(&url).send().await.unwrap();
+
+        provider.force_flush().unwrap();
+        let exported = exporter.get_finished_metrics().unwrap();
+        let metrics: Vec<_> = exported
+            .iter()
+            .flat_map(|resource| resource.scope_metrics())
+            .flat_map(|scope| scope.metrics())
+            .collect();
+
+        let requests = metrics
+            .iter()
+            .find(|metric| metric.name() == "http.client.requests")
+            .expect("request counter exported");
+        let AggregatedMetrics::U64(MetricData::Sum(sum)) = requests.data() else {
+            panic!("unexpected counter data: {:?}", requests.data());
+        };
+        let points: Vec<_> = sum.data_points().collect();
+        assert_eq!(points.len(), 1);
+        assert_eq!(points[0].value(), 2);
+        assert_eq!(
+            attribute(points[0].attributes(), "http.response.status_class"),
+            "2xx"
+        );
+        assert_eq!(
+            attribute(points[0].attributes(), "http.request.method"),
+            "GET"
+        );
+        assert_eq!(
+            attribute(points[0].attributes(), "server.address"),
+            "127.0.0.1"
+        );
+
+        let duration = metrics
+            .iter()
+            .find(|metric| metric.name() == "http.client.request.duration")
+            .expect("duration histogram exported");
+        let AggregatedMetrics::F64(MetricData::Histogram(histogram)) = duration.data() else {
+            panic!("unexpected histogram data: {:?}", duration.data());
+        };
+        assert_eq!(histogram.data_points().map(|p| p.count()).sum::<u64>(), 2);
+    }
+}
@@ -0,0 +1,14 @@
+pub mod bearer_auth;
+pub mod body_limit;
+pub mod idempotency;
+pub mod metrics;
+pub mod rate_limit;
+pub mod timeout;
+pub mod tracing;
+pub use bearer_auth::{BearerAuthMiddleware, BearerToken, TokenProvider, bearer_auth};
+pub use body_limit::{BodyLimitError, MaxBodySizeMiddleware, max_body_size};
+pub use idempotency::{IDEMPOTENCY_KEY, IdempotencyKey, IdempotencyMiddleware, idempotency};
+pub use metrics::{MetricsMiddleware, metrics};
+pub use rate_limit::{RateLimitMiddleware, rate_limit};
+pub use timeout::{PerRequestTimeoutMiddleware, RequestTimeout, per_request_timeout};
+pub use tracing::tracing_middleware;
@@ -0,0 +1,67 @@
+use async_trait::async_trait;
+use governor::{DefaultDirectRateLimiter, Quota, RateLimiter};
+use http::Extensions;
+use reqwest::{Request, Response};
+use reqwest_middleware::{Middleware, Next, Result};
+use std::{num::NonZeroU32, sync::Arc};
+
+/// Token-bucket rate limiter shared by every clone, so all requests from one client draw from
+/// the same budget.
+#[derive(Debug, Clone)]
+pub struct RateLimitMiddleware {
+    limiter: Arc<DefaultDirectRateLimiter>,
+}
+
+impl RateLimitMiddleware {
+    /// Allow up to `rps` requests per second, with bursts of at most `rps`.
+    ///
+    /// A `rps` of zero is treated as one.
+    pub fn new(rps: u32) -> Self {
+        let rps = NonZeroU32::new(rps).unwrap_or(NonZeroU32::MIN);
+        Self {
+            limiter: Arc::new(RateLimiter::direct(Quota::per_second(rps))),
+        }
+    }
+}
+
+#[async_trait]
+impl Middleware for RateLimitMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> Result<Response> {
+        self.limiter.until_ready().await;
+        next.run(req, extensions).await
+    }
+}
+
+/// Create the rate limiting middleware to be used in HttpClientBuilder
+pub fn rate_limit(rps: u32) -> RateLimitMiddleware {
+    RateLimitMiddleware::new(rps)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        HttpClientBuilder,
+        test_util::{ok_response, spawn_server},
+    };
+    use std::time::{Duration, Instant};
+
+    #[tokio::test]
+    async fn test_rate_limit_spaces_requests() {
+        let url = spawn_server(ok_response).await;
+        let client = HttpClientBuilder::new(None).with_rate_limit(5).build();
+
+        let start = Instant::now();
+        for _ in 0..10 {
+            let res = client.get(&url).send().await.unwrap();
+            assert!(res.status().is_success());
+        }
+
+        // burst of 5 is immediate, the remaining 5 are spaced 200ms apart
+        assert!(start.elapsed() >= Duration::from_millis(900));
+    }
+}
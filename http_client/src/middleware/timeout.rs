@@ -0,0 +1,72 @@
+use async_trait::async_trait;
+use http::Extensions;
+use reqwest::{Request, Response};
+use reqwest_middleware::{Middleware, Next, Result};
+use std::time::Duration;
+
+/// Request extension overriding the client-wide timeout for a single call.
+///
+/// ```rust,ignore
+/// client
+///     .get(url)
+///     .with_extension(RequestTimeout(Duration::from_secs(60)))
+///     .send()
+///     .await?;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestTimeout(pub Duration);
+
+/// Applies a [`RequestTimeout`] extension as the deadline of the outgoing request.
+///
+/// Requests without the extension keep the client-wide timeout.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PerRequestTimeoutMiddleware;
+
+#[async_trait]
+impl Middleware for PerRequestTimeoutMiddleware {
+    async fn handle(
+        &self,
+        mut req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> Result<Response> {
+        if let Some(RequestTimeout(timeout)) = extensions.get::<RequestTimeout>() {
+            *req.timeout_mut() = Some(*timeout);
+        }
+        next.run(req, extensions).await
+    }
+}
+
+/// Create the per-request timeout middleware to be used in HttpClientBuilder
+pub fn per_request_timeout() -> PerRequestTimeoutMiddleware {
+    PerRequestTimeoutMiddleware
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HttpClientBuilder, builder::HttpClientBuilderConfig, test_util::spawn_server};
+
+    #[tokio::test]
+    async fn test_request_timeout_overrides_global() {
+        // never answers
+        let url = spawn_server(|_| Vec::new()).await;
+        let client = HttpClientBuilder::new(Some(HttpClientBuilderConfig {
+            timeout: Some(Duration::from_secs(30)),
+            ..Default::default()
+        }))
+        .with_per_request_timeout()
+        .build();
+
+        let start = std::time::Instant::now();
+        let err = client
+            .get(&url)
+            .with_extension(RequestTimeout(Duration::from_millis(100)))
+            .send()
+            .await
+            .unwrap_err();
+
+        assert!(err.is_timeout());
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+}
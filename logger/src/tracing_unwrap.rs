@@ -0,0 +1 @@
+pub use tracing_unwrap::ResultExt;
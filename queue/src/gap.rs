@@ -0,0 +1,145 @@
+/// First sequence number of a SoupBinTCP session.
+pub const FIRST_SEQUENCE: u64 = 1;
+
+/// Result of feeding a sequence number to a [`GapDetector`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SequenceCheck {
+    /// The next expected sequence, or the first one seen.
+    InOrder,
+    /// Sequences `from..=to` were skipped before this one.
+    Gap { from: u64, to: u64 },
+    /// Already seen; the message should be dropped.
+    Duplicate,
+    /// The stream restarted at [`FIRST_SEQUENCE`], e.g. a new session.
+    Reset,
+}
+
+/// Tracks the last sequence number of a `PacketData` stream and classifies each new one.
+#[derive(Debug, Default, Clone)]
+pub struct GapDetector {
+    last: Option<u64>,
+}
+
+impl GapDetector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Detector that has already seen `last`, e.g. to resume a stream whose position is known.
+    pub fn with_last_seen(last: u64) -> Self {
+        Self { last: Some(last) }
+    }
+
+    /// Last in-order sequence number seen, if any.
+    #[inline]
+    pub fn last_seen(&self) -> Option<u64> {
+        self.last
+    }
+
+    /// Forget all history; the next sequence is treated as the first one.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.last = None;
+    }
+
+    /// Classify `seq` and advance the tracked position unless it is a duplicate.
+    #[inline]
+    pub fn observe(&mut self, seq: u64) -> SequenceCheck {
+        let Some(last) = self.last else {
+            self.last = Some(seq);
+            return SequenceCheck::InOrder;
+        };
+
+        match last.checked_add(1) {
+            Some(expected) if seq == expected => {
+                self.last = Some(seq);
+                SequenceCheck::InOrder
+            }
+            Some(expected) if seq > expected => {
+                self.last = Some(seq);
+                SequenceCheck::Gap {
+                    from: expected,
+                    to: seq - 1,
+                }
+            }
+            // a repeated FIRST_SEQUENCE is a duplicate, not a restart
+            _ if seq == FIRST_SEQUENCE && last > FIRST_SEQUENCE => {
+                self.last = Some(seq);
+                SequenceCheck::Reset
+            }
+            _ => SequenceCheck::Duplicate,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clean_run() {
+        let mut detector = GapDetector::new();
+        for seq in 1..=5 {
+            assert_eq!(detector.observe(seq), SequenceCheck::InOrder);
+        }
+        assert_eq!(detector.last_seen(), Some(5));
+    }
+
+    #[test]
+    fn test_skipped_sequence() {
+        let mut detector = GapDetector::new();
+        detector.observe(1);
+        assert_eq!(detector.observe(5), SequenceCheck::Gap { from: 2, to: 4 });
+        assert_eq!(detector.observe(6), SequenceCheck::InOrder);
+    }
+
+    #[test]
+    fn test_repeated_sequence() {
+        let mut detector = GapDetector::new();
+        detector.observe(1);
+        detector.observe(2);
+        detector.observe(3);
+        assert_eq!(detector.observe(3), SequenceCheck::Duplicate);
+        assert_eq!(detector.observe(2), SequenceCheck::Duplicate);
+        assert_eq!(detector.last_seen(), Some(3));
+        assert_eq!(detector.observe(4), SequenceCheck::InOrder);
+    }
+
+    #[test]
+    fn test_backwards_reset() {
+        let mut detector = GapDetector::new();
+        detector.observe(41);
+        detector.observe(42);
+        assert_eq!(detector.observe(1), SequenceCheck::Reset);
+        assert_eq!(detector.observe(2), SequenceCheck::InOrder);
+    }
+
+    #[test]
+    fn test_with_last_seen_resumes() {
+        let mut detector = GapDetector::with_last_seen(9);
+        assert_eq!(detector.observe(9), SequenceCheck::Duplicate);
+        assert_eq!(detector.observe(10), SequenceCheck::InOrder);
+        assert_eq!(
+            detector.observe(12),
+            SequenceCheck::Gap { from: 11, to: 11 }
+        );
+    }
+
+    #[test]
+    fn test_repeated_first_sequence_is_duplicate() {
+        let mut detector = GapDetector::new();
+        detector.observe(1);
+        assert_eq!(detector.observe(1), SequenceCheck::Duplicate);
+        assert_eq!(detector.last_seen(), Some(1));
+        assert_eq!(detector.observe(2), SequenceCheck::InOrder);
+    }
+
+    #[test]
+    fn test_max_sequence_does_not_overflow() {
+        let mut detector = GapDetector::with_last_seen(u64::MAX);
+        assert_eq!(detector.observe(u64::MAX), SequenceCheck::Duplicate);
+        assert_eq!(detector.observe(7), SequenceCheck::Duplicate);
+        assert_eq!(detector.observe(1), SequenceCheck::Reset);
+        assert_eq!(detector.last_seen(), Some(1));
+    }
+}
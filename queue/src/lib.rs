@@ -0,0 +1,13 @@
+use bytes::Bytes;
+use data_types::tracing::TraceData;
+
+pub mod gap;
+pub mod merge;
+pub mod spsc;
+pub use gap::{GapDetector, SequenceCheck};
+pub use merge::{MergeIterator, MergeOrder};
+pub use spsc::{Consumer, Producer, SpscQueue};
+
+/// Packet data: sequence number, raw bytes (for backup), parsed message, optional trace data
+pub type PacketDataWithTrace<T> = (u64, Bytes, T, TraceData);
+pub type PacketData<T> = (u64, Bytes, T, Option<TraceData>);
@@ -0,0 +1,205 @@
+use crate::PacketData;
+
+use std::{fmt, time::Instant};
+
+/// Key a [`MergeIterator`] orders packets by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeOrder {
+    /// The embedded sequence number.
+    Sequence,
+    /// [`TraceData::recv_at`](data_types::tracing::TraceData), packets without trace data first.
+    RecvAt,
+}
+
+type Source<T> = Box<dyn Iterator<Item = PacketData<T>> + Send>;
+
+struct Feed<T> {
+    source: Source<T>,
+    head: Option<PacketData<T>>,
+    ended: bool,
+}
+
+/// Merges several `PacketData` sources, each already in order, into one ordered stream.
+///
+/// One packet per source is buffered. A source that ends is dropped from the merge; the
+/// iterator ends once every source has. Channel receivers block while waiting for their next
+/// packet, so a quiet live feed holds back the merged view. Ties go to the source added first.
+pub struct MergeIterator<T> {
+    feeds: Vec<Feed<T>>,
+    order: MergeOrder,
+}
+
+impl<T> MergeIterator<T> {
+    pub fn new(order: MergeOrder) -> Self {
+        Self {
+            feeds: Vec::new(),
+            order,
+        }
+    }
+
+    /// Add a source, e.g. a `Vec`, an iterator or a channel receiver.
+    pub fn with_source<S>(mut self, source: S) -> Self
+    where
+        S: IntoIterator<Item = PacketData<T>>,
+        S::IntoIter: Send + 'static,
+    {
+        self.feeds.push(Feed {
+            source: Box::new(source.into_iter()),
+            head: None,
+            ended: false,
+        });
+        self
+    }
+
+    /// Number of sources that have not ended yet.
+    pub fn active_sources(&self) -> usize {
+        self.feeds.iter().filter(|feed| !feed.ended).count()
+    }
+
+    fn key(&self, packet: &PacketData<T>) -> (Option<Instant>, u64) {
+        match self.order {
+            MergeOrder::Sequence => (None, packet.0),
+            MergeOrder::RecvAt => (packet.3.as_ref().map(|trace| trace.recv_at), packet.0),
+        }
+    }
+}
+
+impl<T> Iterator for MergeIterator<T> {
+    type Item = PacketData<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for feed in self.feeds.iter_mut().filter(|feed| !feed.ended) {
+            if feed.head.is_none() {
+                feed.head = feed.source.next();
+                feed.ended = feed.head.is_none();
+            }
+        }
+
+        let next = self
+            .feeds
+            .iter()
+            .enumerate()
+            .filter_map(|(index, feed)| Some((index, self.key(feed.head.as_ref()?))))
+            .min_by_key(|&(_, key)| key)
+            .map(|(index, _)| index)?;
+
+        self.feeds[next].head.take()
+    }
+}
+
+impl<T> fmt::Debug for MergeIterator<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MergeIterator")
+            .field("sources", &self.feeds.len())
+            .field("active_sources", &self.active_sources())
+            .field("order", &self.order)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use data_types::tracing::TraceData;
+    use std::time::Duration;
+
+    fn packet(seq: u64, feed: &'static str) -> PacketData<&'static str> {
+        (seq, Bytes::new(), feed, None)
+    }
+
+    fn traced(seq: u64, feed: &'static str, recv_at: Instant) -> PacketData<&'static str> {
+        let mut trace = TraceData::new();
+        trace.recv_at = recv_at;
+        (seq, Bytes::new(), feed, Some(trace))
+    }
+
+    #[test]
+    fn test_merges_two_streams_by_sequence() {
+        let itch = vec![packet(1, "itch"), packet(4, "itch"), packet(5, "itch")];
+        let mdf = vec![packet(2, "mdf"), packet(3, "mdf"), packet(6, "mdf")];
+
+        let merged: Vec<_> = MergeIterator::new(MergeOrder::Sequence)
+            .with_source(itch)
+            .with_source(mdf)
+            .map(|(seq, _, feed, _)| (seq, feed))
+            .collect();
+
+        assert_eq!(
+            merged,
+            [
+                (1, "itch"),
+                (2, "mdf"),
+                (3, "mdf"),
+                (4, "itch"),
+                (5, "itch"),
+                (6, "mdf")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_source_ending_early_and_ties() {
+        let short = vec![packet(1, "short"), packet(2, "short")];
+        let long = (2..=5).map(|seq| packet(seq, "long")).collect::<Vec<_>>();
+
+        let mut merge = MergeIterator::new(MergeOrder::Sequence)
+            .with_source(short)
+            .with_source(long)
+            .with_source(Vec::new());
+        assert_eq!(merge.active_sources(), 3);
+
+        let merged: Vec<_> = merge
+            .by_ref()
+            .map(|(seq, _, feed, _)| (seq, feed))
+            .collect();
+        assert_eq!(
+            merged,
+            [
+                (1, "short"),
+                (2, "short"),
+                (2, "long"),
+                (3, "long"),
+                (4, "long"),
+                (5, "long")
+            ]
+        );
+        assert_eq!(merge.active_sources(), 0);
+        assert!(merge.next().is_none());
+    }
+
+    #[test]
+    fn test_merges_channels_by_recv_at() {
+        let base = Instant::now();
+        let at = |ms| base + Duration::from_millis(ms);
+
+        let (itch_tx, itch_rx) = std::sync::mpsc::channel();
+        let (mdf_tx, mdf_rx) = std::sync::mpsc::channel();
+        // sequences are per feed, so only recv_at gives a global order
+        for (seq, ms) in [(10, 1), (11, 5), (12, 6)] {
+            itch_tx.send(traced(seq, "itch", at(ms))).unwrap();
+        }
+        for (seq, ms) in [(1, 2), (2, 3), (3, 7)] {
+            mdf_tx.send(traced(seq, "mdf", at(ms))).unwrap();
+        }
+        drop((itch_tx, mdf_tx));
+
+        let merged: Vec<_> = MergeIterator::new(MergeOrder::RecvAt)
+            .with_source(itch_rx)
+            .with_source(mdf_rx)
+            .map(|(seq, _, feed, _)| (seq, feed))
+            .collect();
+
+        assert_eq!(
+            merged,
+            [
+                (10, "itch"),
+                (1, "mdf"),
+                (2, "mdf"),
+                (11, "itch"),
+                (12, "itch"),
+                (3, "mdf")
+            ]
+        );
+    }
+}
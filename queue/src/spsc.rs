@@ -0,0 +1,240 @@
+use std::{
+    cell::UnsafeCell,
+    mem::MaybeUninit,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+/// Keeps producer and consumer indices on separate cache lines.
+#[repr(align(64))]
+struct CachePadded<T>(T);
+
+/// Bounded lock-free single-producer/single-consumer ring buffer.
+///
+/// Slots are allocated once up front, so pushing and popping never allocate. Use
+/// [`SpscQueue::with_capacity`] to get the [`Producer`] and [`Consumer`] halves; each half can
+/// be moved to its own thread but not cloned, which is what makes the queue SPSC.
+pub struct SpscQueue<T> {
+    buffer: Box<[UnsafeCell<MaybeUninit<T>>]>,
+    mask: usize,
+    /// Next slot to pop, written only by the consumer.
+    head: CachePadded<AtomicUsize>,
+    /// Next slot to push, written only by the producer.
+    tail: CachePadded<AtomicUsize>,
+}
+
+// SAFETY: slots are only accessed by the single producer (before publishing `tail`) or the
+// single consumer (before publishing `head`), so values of `T` move between exactly two threads.
+unsafe impl<T: Send> Send for SpscQueue<T> {}
+unsafe impl<T: Send> Sync for SpscQueue<T> {}
+
+impl<T> SpscQueue<T> {
+    /// Create a queue holding at least `capacity` items, rounded up to a power of two.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn with_capacity(capacity: usize) -> (Producer<T>, Consumer<T>) {
+        assert!(capacity > 0, "SpscQueue capacity must be non-zero");
+        let capacity = capacity.next_power_of_two();
+
+        let buffer = (0..capacity)
+            .map(|_| UnsafeCell::new(MaybeUninit::uninit()))
+            .collect();
+
+        let queue = Arc::new(SpscQueue {
+            buffer,
+            mask: capacity - 1,
+            head: CachePadded(AtomicUsize::new(0)),
+            tail: CachePadded(AtomicUsize::new(0)),
+        });
+
+        (
+            Producer {
+                queue: Arc::clone(&queue),
+            },
+            Consumer { queue },
+        )
+    }
+
+    /// Number of slots in the ring.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Number of items currently queued. Only a snapshot when the other half is active.
+    #[inline]
+    pub fn len(&self) -> usize {
+        let tail = self.tail.0.load(Ordering::Acquire);
+        let head = self.head.0.load(Ordering::Acquire);
+        tail.wrapping_sub(head)
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Drop for SpscQueue<T> {
+    fn drop(&mut self) {
+        let tail = *self.tail.0.get_mut();
+        let mut head = *self.head.0.get_mut();
+        while head != tail {
+            // SAFETY: slots in head..tail were written by push and never popped.
+            unsafe { self.buffer[head & self.mask].get_mut().assume_init_drop() };
+            head = head.wrapping_add(1);
+        }
+    }
+}
+
+/// Pushing half of an [`SpscQueue`].
+pub struct Producer<T> {
+    queue: Arc<SpscQueue<T>>,
+}
+
+impl<T> Producer<T> {
+    /// Push `item`, handing it back if the queue is full.
+    #[inline]
+    pub fn push(&mut self, item: T) -> Result<(), T> {
+        let queue = &*self.queue;
+        let tail = queue.tail.0.load(Ordering::Relaxed);
+        let head = queue.head.0.load(Ordering::Acquire);
+
+        if tail.wrapping_sub(head) == queue.capacity() {
+            return Err(item);
+        }
+
+        // SAFETY: the slot is outside head..tail, so the consumer is not reading it.
+        unsafe { (*queue.buffer[tail & queue.mask].get()).write(item) };
+        queue.tail.0.store(tail.wrapping_add(1), Ordering::Release);
+
+        Ok(())
+    }
+
+    /// Shared queue state, e.g. for [`SpscQueue::len`].
+    #[inline]
+    pub fn queue(&self) -> &SpscQueue<T> {
+        &self.queue
+    }
+}
+
+/// Popping half of an [`SpscQueue`].
+pub struct Consumer<T> {
+    queue: Arc<SpscQueue<T>>,
+}
+
+impl<T> Consumer<T> {
+    /// Pop the oldest item, or `None` if the queue is empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        let queue = &*self.queue;
+        let head = queue.head.0.load(Ordering::Relaxed);
+        let tail = queue.tail.0.load(Ordering::Acquire);
+
+        if head == tail {
+            return None;
+        }
+
+        // SAFETY: the slot is inside head..tail, so the producer has initialised it and will
+        // not touch it until `head` moves past it.
+        let item = unsafe { (*queue.buffer[head & queue.mask].get()).assume_init_read() };
+        queue.head.0.store(head.wrapping_add(1), Ordering::Release);
+
+        Some(item)
+    }
+
+    /// Shared queue state, e.g. for [`SpscQueue::len`].
+    #[inline]
+    pub fn queue(&self) -> &SpscQueue<T> {
+        &self.queue
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capacity_rounds_up_to_power_of_two() {
+        let (producer, _consumer) = SpscQueue::<u64>::with_capacity(5);
+        assert_eq!(producer.queue().capacity(), 8);
+    }
+
+    #[test]
+    fn test_empty_and_full() {
+        let (mut producer, mut consumer) = SpscQueue::with_capacity(2);
+        assert_eq!(consumer.pop(), None);
+
+        assert_eq!(producer.push(1), Ok(()));
+        assert_eq!(producer.push(2), Ok(()));
+        assert_eq!(producer.push(3), Err(3));
+        assert_eq!(producer.queue().len(), 2);
+
+        assert_eq!(consumer.pop(), Some(1));
+        assert_eq!(consumer.pop(), Some(2));
+        assert_eq!(consumer.pop(), None);
+        assert!(consumer.queue().is_empty());
+    }
+
+    #[test]
+    fn test_wrap_around() {
+        let (mut producer, mut consumer) = SpscQueue::with_capacity(4);
+
+        for round in 0..10u64 {
+            for i in 0..3 {
+                producer.push(round * 3 + i).unwrap();
+            }
+            for i in 0..3 {
+                assert_eq!(consumer.pop(), Some(round * 3 + i));
+            }
+        }
+        assert_eq!(consumer.pop(), None);
+    }
+
+    #[test]
+    fn test_drop_releases_queued_items() {
+        let item = Arc::new(());
+        let (mut producer, consumer) = SpscQueue::with_capacity(4);
+        producer.push(Arc::clone(&item)).unwrap();
+        producer.push(Arc::clone(&item)).unwrap();
+        assert_eq!(Arc::strong_count(&item), 3);
+
+        drop(producer);
+        drop(consumer);
+        assert_eq!(Arc::strong_count(&item), 1);
+    }
+
+    #[test]
+    fn test_concurrent_producer_consumer() {
+        const COUNT: u64 = 100_000;
+        let (mut producer, mut consumer) = SpscQueue::with_capacity(64);
+
+        let handle = std::thread::spawn(move || {
+            for i in 0..COUNT {
+                let mut item = i;
+                while let Err(back) = producer.push(item) {
+                    item = back;
+                    std::hint::spin_loop();
+                }
+            }
+        });
+
+        let mut expected = 0;
+        while expected < COUNT {
+            match consumer.pop() {
+                Some(value) => {
+                    assert_eq!(value, expected);
+                    expected += 1;
+                }
+                None => std::hint::spin_loop(),
+            }
+        }
+
+        handle.join().unwrap();
+        assert_eq!(consumer.pop(), None);
+    }
+}
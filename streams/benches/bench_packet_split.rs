@@ -0,0 +1,60 @@
+use bytes::{Bytes, BytesMut};
+use criterion::{BatchSize, Criterion, Throughput, criterion_group, criterion_main};
+use std::hint::black_box;
+
+const PACKET_COUNT: usize = 1024;
+const PAYLOAD_LEN: usize = 64;
+
+/// A read buffer holding `PACKET_COUNT` back-to-back SoupBinTCP sequenced data packets.
+fn filled_buffer() -> BytesMut {
+    let mut buf = BytesMut::with_capacity(PACKET_COUNT * (PAYLOAD_LEN + 3));
+    for i in 0..PACKET_COUNT {
+        buf.extend_from_slice(&((PAYLOAD_LEN + 1) as u16).to_be_bytes());
+        buf.extend_from_slice(b"S");
+        buf.extend_from_slice(&[i as u8; PAYLOAD_LEN]);
+    }
+    buf
+}
+
+fn next_len(buf: &BytesMut) -> usize {
+    2 + u16::from_be_bytes([buf[0], buf[1]]) as usize
+}
+
+fn bench_packet_split(c: &mut Criterion) {
+    let template = filled_buffer();
+    let mut group = c.benchmark_group("soupbintcp packet extraction");
+    group.throughput(Throughput::Bytes(template.len() as u64));
+
+    group.bench_function("copy_from_slice + split_to", |b| {
+        b.iter_batched_ref(
+            || template.clone(),
+            |buf| {
+                while !buf.is_empty() {
+                    let total_len = next_len(buf);
+                    let packet = Bytes::copy_from_slice(&buf[..total_len]);
+                    let _ = buf.split_to(total_len);
+                    black_box(packet);
+                }
+            },
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("split_to + freeze", |b| {
+        b.iter_batched_ref(
+            || template.clone(),
+            |buf| {
+                while !buf.is_empty() {
+                    let total_len = next_len(buf);
+                    black_box(buf.split_to(total_len).freeze());
+                }
+            },
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_packet_split);
+criterion_main!(benches);
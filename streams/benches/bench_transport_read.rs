@@ -0,0 +1,131 @@
+use criterion::{Criterion, criterion_group, criterion_main};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    future::poll_fn,
+};
+use streams::net::{
+    tokio_transport::TokioTransport,
+    transport::{PollTransport, ReadBuffer, Transport},
+};
+use tokio::{
+    io::AsyncWriteExt,
+    net::{TcpListener, TcpStream},
+};
+
+const PAYLOAD: [u8; 64] = [b'S'; 64];
+
+/// Reads per path when counting allocations.
+const COUNTED_READS: usize = 100;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// System allocator that counts allocations made on the current thread.
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|n| n.set(n.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(Cell::get)
+}
+
+async fn connected_pair() -> (TokioTransport, TcpStream) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap().to_string();
+    let (transport, accepted) = tokio::join!(TokioTransport::connect(&addr), listener.accept());
+    (transport.unwrap(), accepted.unwrap().0)
+}
+
+async fn boxed_read(transport: &mut TokioTransport, buf: &mut ReadBuffer) {
+    let (n, _) = transport.read_bytes(buf).await.unwrap();
+    assert_eq!(n, PAYLOAD.len());
+}
+
+async fn poll_read(transport: &mut TokioTransport, buf: &mut ReadBuffer) {
+    let (n, _) = poll_fn(|cx| transport.poll_read_bytes(cx, buf))
+        .await
+        .unwrap();
+    assert_eq!(n, PAYLOAD.len());
+}
+
+/// Allocations made by `COUNTED_READS` reads of data already sitting in the socket, counting
+/// only the read itself.
+fn allocations_per_path(runtime: &tokio::runtime::Runtime, poll: bool) -> usize {
+    runtime.block_on(async {
+        let (mut transport, mut peer) = connected_pair().await;
+        let mut buf = ReadBuffer::with_capacity(4096);
+        let mut total = 0;
+
+        for _ in 0..COUNTED_READS {
+            buf.clear();
+            peer.write_all(&PAYLOAD).await.unwrap();
+            // let the driver see the socket readable before counting
+            tokio::task::yield_now().await;
+
+            let before = allocations();
+            if poll {
+                poll_read(&mut transport, &mut buf).await;
+            } else {
+                boxed_read(&mut transport, &mut buf).await;
+            }
+            total += allocations() - before;
+        }
+        total
+    })
+}
+
+fn bench_transport_read(c: &mut Criterion) {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+
+    // the point of poll_read_bytes: no boxed future per read
+    let boxed = allocations_per_path(&runtime, false);
+    let polled = allocations_per_path(&runtime, true);
+    assert!(
+        boxed >= COUNTED_READS,
+        "read_bytes made {boxed} allocations in {COUNTED_READS} reads"
+    );
+    assert_eq!(polled, 0, "poll_read_bytes allocated");
+
+    let mut group = c.benchmark_group("tokio transport read");
+
+    for (name, poll) in [("read_bytes (boxed)", false), ("poll_read_bytes", true)] {
+        let (mut transport, mut peer) = runtime.block_on(connected_pair());
+        let mut buf = ReadBuffer::with_capacity(4096);
+
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                runtime.block_on(async {
+                    buf.clear();
+                    peer.write_all(&PAYLOAD).await.unwrap();
+                    if poll {
+                        poll_read(&mut transport, &mut buf).await;
+                    } else {
+                        boxed_read(&mut transport, &mut buf).await;
+                    }
+                })
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_transport_read);
+criterion_main!(benches);
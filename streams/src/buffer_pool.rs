@@ -0,0 +1,205 @@
+use crate::constants::{BUFFER_POOL_CAPACITY, BUFFER_POOL_MAX_SIZE};
+
+use bytes::BytesMut;
+use std::{
+    fmt,
+    ops::{Deref, DerefMut},
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+/// Pool of reusable [`BytesMut`] buffers shared by every clone.
+///
+/// Buffers go back to the bounded free list when their [`PooledBuffer`] is dropped; once the
+/// list holds `max_size` buffers further returns are simply freed. The list is a stack, so
+/// [`acquire`](Self::acquire) hands out the most recently returned, cache-warm buffer.
+#[derive(Debug, Clone)]
+pub struct BufferPool {
+    inner: Arc<PoolInner>,
+}
+
+#[derive(Debug)]
+struct PoolInner {
+    free: Mutex<Vec<BytesMut>>,
+    max_size: usize,
+    buffer_capacity: usize,
+}
+
+impl PoolInner {
+    /// The free list; a panic while it was held cannot leave it inconsistent.
+    #[inline]
+    fn free(&self) -> MutexGuard<'_, Vec<BytesMut>> {
+        self.free.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Default for BufferPool {
+    /// Pool of [`BUFFER_POOL_CAPACITY`] byte buffers, keeping at most [`BUFFER_POOL_MAX_SIZE`].
+    fn default() -> Self {
+        Self::new(BUFFER_POOL_CAPACITY, BUFFER_POOL_MAX_SIZE)
+    }
+}
+
+impl BufferPool {
+    /// Pool handing out buffers of at least `buffer_capacity` bytes, keeping at most
+    /// `max_size` idle buffers. A `max_size` of zero is treated as one.
+    pub fn new(buffer_capacity: usize, max_size: usize) -> Self {
+        let max_size = max_size.max(1);
+        Self {
+            inner: Arc::new(PoolInner {
+                free: Mutex::new(Vec::with_capacity(max_size)),
+                max_size,
+                buffer_capacity,
+            }),
+        }
+    }
+
+    /// Take an empty buffer from the pool, allocating one if none is idle.
+    #[inline]
+    pub fn acquire(&self) -> PooledBuffer {
+        let idle = self.inner.free().pop();
+        let buf = idle.unwrap_or_else(|| BytesMut::with_capacity(self.inner.buffer_capacity));
+
+        PooledBuffer {
+            buf,
+            pool: Arc::clone(&self.inner),
+        }
+    }
+
+    /// Number of idle buffers ready to be acquired.
+    pub fn available(&self) -> usize {
+        self.inner.free().len()
+    }
+
+    /// Maximum number of idle buffers kept.
+    pub fn max_size(&self) -> usize {
+        self.inner.max_size
+    }
+}
+
+/// Buffer borrowed from a [`BufferPool`], returned to it on drop.
+pub struct PooledBuffer {
+    buf: BytesMut,
+    pool: Arc<PoolInner>,
+}
+
+impl PooledBuffer {
+    /// Keep the buffer instead of returning it to the pool.
+    pub fn detach(mut self) -> BytesMut {
+        std::mem::take(&mut self.buf)
+    }
+}
+
+impl Deref for PooledBuffer {
+    type Target = BytesMut;
+
+    #[inline]
+    fn deref(&self) -> &BytesMut {
+        &self.buf
+    }
+}
+
+impl DerefMut for PooledBuffer {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut BytesMut {
+        &mut self.buf
+    }
+}
+
+impl fmt::Debug for PooledBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PooledBuffer")
+            .field("len", &self.buf.len())
+            .field("capacity", &self.buf.capacity())
+            .finish()
+    }
+}
+
+impl Drop for PooledBuffer {
+    fn drop(&mut self) {
+        // detached, or split down to less than a pooled buffer: not worth keeping
+        if self.buf.capacity() < self.pool.buffer_capacity {
+            return;
+        }
+
+        let mut buf = std::mem::take(&mut self.buf);
+        buf.clear();
+        let mut free = self.pool.free();
+        // pool full: the buffer is freed
+        if free.len() < self.pool.max_size {
+            free.push(buf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_acquire_reuses_returned_buffer() {
+        let pool = BufferPool::new(64, 4);
+
+        let mut buf = pool.acquire();
+        assert!(buf.capacity() >= 64);
+        buf.extend_from_slice(b"packet");
+        let ptr = buf.as_ptr();
+        drop(buf);
+        assert_eq!(pool.available(), 1);
+
+        let buf = pool.acquire();
+        assert_eq!(buf.as_ptr(), ptr);
+        assert!(buf.is_empty());
+        assert_eq!(pool.available(), 0);
+    }
+
+    #[test]
+    fn test_acquire_prefers_most_recently_returned() {
+        let pool = BufferPool::new(64, 4);
+
+        let first = pool.acquire();
+        let second = pool.acquire();
+        let second_ptr = second.as_ptr();
+        drop(first);
+        drop(second);
+
+        assert_eq!(pool.acquire().as_ptr(), second_ptr);
+    }
+
+    #[test]
+    fn test_pool_caps_at_max_size() {
+        let pool = BufferPool::default();
+        assert_eq!(pool.max_size(), BUFFER_POOL_MAX_SIZE);
+
+        let buffers: Vec<_> = (0..BUFFER_POOL_MAX_SIZE + 10)
+            .map(|_| pool.acquire())
+            .collect();
+        assert!(buffers.iter().all(|b| b.capacity() >= BUFFER_POOL_CAPACITY));
+        drop(buffers);
+
+        assert_eq!(pool.available(), BUFFER_POOL_MAX_SIZE);
+    }
+
+    #[test]
+    fn test_detached_and_shrunk_buffers_are_not_returned() {
+        let pool = BufferPool::new(64, 4);
+
+        let detached = pool.acquire().detach();
+        assert!(detached.capacity() >= 64);
+        assert_eq!(pool.available(), 0);
+
+        let mut buf = pool.acquire();
+        buf.extend_from_slice(&[0u8; 64]);
+        let _frame = buf.split_to(40);
+        drop(buf);
+        assert_eq!(pool.available(), 0);
+    }
+
+    #[test]
+    fn test_clones_share_the_free_list() {
+        let pool = BufferPool::new(16, 2);
+        let other = pool.clone();
+
+        drop(other.acquire());
+        assert_eq!(pool.available(), 1);
+    }
+}
@@ -0,0 +1,13 @@
+pub mod buffer_pool;
+pub mod constants;
+pub mod framing;
+// pub mod message_stream;
+pub mod soupbintcp;
+// Re-export commonly used types
+// pub use message_stream::MessageStream;
+pub use soupbintcp::{
+    soupbintcp_client::SoupBinTcpClient,
+    soupbintcp_packet::{ClientPacket, ServerPacket},
+};
+
+pub mod net;
@@ -0,0 +1,207 @@
+use super::{
+    gzip::GzipStream,
+    transport::{ReadBuffer, Transport, TransportStats},
+};
+
+use data_types::tracing::TraceData;
+use tokio::io;
+
+/// Decompresses the gzip byte stream of any inner transport on the fly.
+///
+/// Concatenated gzip members decode as one stream. Writes go to the inner transport
+/// unchanged. [`FileReplayTransport`] decodes files named `*.gz` with the same decoder; wrap
+/// it only when the capture has another name.
+///
+/// [`FileReplayTransport`]: super::file_transport::FileReplayTransport
+#[derive(Debug)]
+pub struct GzipTransport<T> {
+    inner: T,
+    gzip: GzipStream,
+}
+
+impl<T: Transport> GzipTransport<T> {
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            gzip: GzipStream::new(),
+        }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: Transport> Transport for GzipTransport<T> {
+    /// Returns between 1 and 64 KiB of decompressed bytes, or `Ok((0, _))` once the inner
+    /// transport hit EOF after a complete gzip member.
+    ///
+    /// Output decoded before a truncated stream is detected is returned first; the error
+    /// (kind `InvalidData`) follows on the next read.
+    async fn read_bytes(&mut self, buf: &mut ReadBuffer) -> io::Result<(usize, TraceData)> {
+        let mut trace_data = None;
+
+        let n = loop {
+            if let Some(result) = self.gzip.try_read(buf) {
+                break result?;
+            }
+            let (n, trace) = self.inner.read_bytes(self.gzip.input()).await?;
+            trace_data = Some(trace);
+            if n == 0 {
+                self.gzip.end_input();
+            }
+        };
+
+        Ok((
+            n,
+            trace_data.unwrap_or_else(TraceData::with_current_context),
+        ))
+    }
+
+    #[inline]
+    async fn write(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.inner.write(buf).await
+    }
+
+    #[inline]
+    async fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush().await
+    }
+
+    #[inline]
+    fn try_write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.inner.try_write(buf)
+    }
+
+    #[inline]
+    async fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.inner.write_all(buf).await
+    }
+
+    fn stats(&self) -> TransportStats {
+        self.inner.stats()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::net::{gzip::MAX_DECODED_PER_READ, mock_transport::MockTransport};
+    use async_compression::tokio::write::GzipEncoder;
+    use tokio::io::AsyncWriteExt;
+
+    async fn gzip(data: &[u8]) -> Vec<u8> {
+        let mut encoder = GzipEncoder::new(Vec::new());
+        encoder.write_all(data).await.unwrap();
+        encoder.shutdown().await.unwrap();
+        encoder.into_inner()
+    }
+
+    async fn read_all(transport: &mut impl Transport) -> io::Result<Vec<u8>> {
+        let mut buf = ReadBuffer::new();
+        loop {
+            let (n, _) = transport.read_bytes(&mut buf).await?;
+            if n == 0 {
+                return Ok(buf.to_vec());
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_decodes_stream_split_across_reads() {
+        let data: Vec<u8> = (0..=255u8).cycle().take(5000).collect();
+        let compressed = gzip(&data).await;
+
+        let mut mock = MockTransport::new();
+        for chunk in compressed.chunks(7) {
+            mock = mock.with_read(chunk.to_vec());
+        }
+        let mut transport = GzipTransport::new(mock);
+
+        assert_eq!(read_all(&mut transport).await.unwrap(), data);
+        let mut buf = ReadBuffer::new();
+        assert_eq!(transport.read_bytes(&mut buf).await.unwrap().0, 0);
+    }
+
+    #[tokio::test]
+    async fn test_truncated_stream_returns_data_then_error() {
+        let data = vec![b'x'; 100_000];
+        let compressed = gzip(&data).await;
+        let truncated = compressed[..compressed.len() - 4].to_vec();
+
+        let mut transport = GzipTransport::new(MockTransport::new().with_read(truncated));
+        let err = read_all(&mut transport).await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[tokio::test]
+    async fn test_decodes_multi_member_stream() {
+        let first = b"first member ".repeat(50);
+        let second = b"second member".repeat(50);
+        let mut compressed = gzip(&first).await;
+        compressed.extend(gzip(&second).await);
+
+        let mut mock = MockTransport::new();
+        for chunk in compressed.chunks(11) {
+            mock = mock.with_read(chunk.to_vec());
+        }
+        let mut transport = GzipTransport::new(mock);
+
+        assert_eq!(
+            read_all(&mut transport).await.unwrap(),
+            [first, second].concat()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_caps_decoded_bytes_per_read() {
+        let data = vec![0u8; 1 << 20];
+        let compressed = gzip(&data).await;
+
+        let mut transport = GzipTransport::new(MockTransport::new().with_read(compressed));
+        let mut buf = ReadBuffer::new();
+        loop {
+            let (n, _) = transport.read_bytes(&mut buf).await.unwrap();
+            assert!(n <= MAX_DECODED_PER_READ);
+            if n == 0 {
+                break;
+            }
+        }
+        assert_eq!(buf.len(), data.len());
+    }
+
+    #[tokio::test]
+    async fn test_writes_pass_through_uncompressed() {
+        let mock = MockTransport::new();
+        let written = mock.written();
+        let mut transport = GzipTransport::new(mock);
+
+        transport.write_all(b"login").await.unwrap();
+        assert_eq!(&written.lock().unwrap()[..], b"login");
+    }
+
+    #[cfg(feature = "file_transport")]
+    #[tokio::test]
+    async fn test_replays_gzipped_capture_like_plain_one() {
+        use crate::net::file_transport::FileReplayTransport;
+
+        let dir = tempfile::tempdir().unwrap();
+        let capture: Vec<u8> = (0..2000u32).flat_map(|i| i.to_be_bytes()).collect();
+
+        let plain = dir.path().join("capture.bin");
+        tokio::fs::write(&plain, &capture).await.unwrap();
+        // not named *.gz, so the file transport hands over the compressed bytes as they are
+        let compressed = dir.path().join("capture.gzip");
+        tokio::fs::write(&compressed, gzip(&capture).await)
+            .await
+            .unwrap();
+
+        let mut plain = FileReplayTransport::open(&plain).await.unwrap();
+        let mut gzipped = GzipTransport::new(FileReplayTransport::open(&compressed).await.unwrap());
+
+        let expected = read_all(&mut plain).await.unwrap();
+        assert_eq!(expected, capture);
+        assert_eq!(read_all(&mut gzipped).await.unwrap(), expected);
+    }
+}
@@ -0,0 +1,111 @@
+use super::transport::ReadBuffer;
+
+use async_compression::tokio::bufread::GzipDecoder;
+use bytes::{Buf, BufMut};
+use std::{
+    future::Future,
+    pin::{Pin, pin},
+    task::{Context, Poll, Waker},
+};
+use tokio::io::{self, AsyncBufRead, AsyncRead, AsyncReadExt, ReadBuf};
+
+/// Compressed bytes requested from the source per refill.
+const READ_CHUNK: usize = 8 * 1024;
+
+/// Upper bound on the decompressed bytes handed out by a single [`GzipStream::try_read`].
+pub(crate) const MAX_DECODED_PER_READ: usize = 64 * 1024;
+
+/// Compressed bytes waiting to be decoded.
+///
+/// An empty buffer before EOF reports `Pending` without registering a waker: the owning
+/// [`GzipStream`] polls the decoder once, refills on `Pending` and polls again.
+#[derive(Debug, Default)]
+struct Input {
+    bytes: ReadBuffer,
+    eof: bool,
+}
+
+impl AsyncRead for Input {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        if this.bytes.is_empty() && !this.eof {
+            return Poll::Pending;
+        }
+        let n = this.bytes.len().min(buf.remaining());
+        buf.put_slice(&this.bytes[..n]);
+        this.bytes.advance(n);
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl AsyncBufRead for Input {
+    fn poll_fill_buf(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        let this = self.get_mut();
+        if this.bytes.is_empty() && !this.eof {
+            return Poll::Pending;
+        }
+        Poll::Ready(Ok(&this.bytes[..]))
+    }
+
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        self.get_mut().bytes.advance(amt);
+    }
+}
+
+/// Multi-member gzip decoder fed from any async byte source.
+///
+/// Shared by [`FileReplayTransport`] for `*.gz` captures and by [`GzipTransport`].
+///
+/// [`FileReplayTransport`]: super::file_transport::FileReplayTransport
+/// [`GzipTransport`]: super::compressed_transport::GzipTransport
+#[derive(Debug)]
+pub(crate) struct GzipStream {
+    decoder: GzipDecoder<Input>,
+}
+
+impl GzipStream {
+    pub(crate) fn new() -> Self {
+        let mut decoder = GzipDecoder::new(Input::default());
+        // concatenated members (e.g. rotated captures joined with cat) form one stream
+        decoder.multiple_members(true);
+        Self { decoder }
+    }
+
+    /// Appends between 1 and [`MAX_DECODED_PER_READ`] decompressed bytes to `buf`, or returns
+    /// `Ok(0)` once the input ended on a member boundary.
+    ///
+    /// Returns `None` when more compressed bytes are needed: append them to [`Self::input`],
+    /// or call [`Self::end_input`] at EOF, and try again. A truncated or corrupt stream is an
+    /// `InvalidData` error, reported after any output decoded before it.
+    pub(crate) fn try_read(&mut self, buf: &mut ReadBuffer) -> Option<io::Result<usize>> {
+        buf.reserve(MAX_DECODED_PER_READ);
+        let mut limited = (&mut *buf).limit(MAX_DECODED_PER_READ);
+        let read = pin!(self.decoder.read_buf(&mut limited));
+        // the decoder only pends on an empty input buffer, which needs no wakeup
+        match read.poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(result) => Some(result.map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("truncated or corrupt gzip stream: {e}"),
+                )
+            })),
+            Poll::Pending => None,
+        }
+    }
+
+    /// Buffer to append compressed bytes to, with room reserved for one chunk.
+    pub(crate) fn input(&mut self) -> &mut ReadBuffer {
+        let bytes = &mut self.decoder.get_mut().bytes;
+        bytes.reserve(READ_CHUNK);
+        bytes
+    }
+
+    /// Marks the end of the compressed input.
+    pub(crate) fn end_input(&mut self) {
+        self.decoder.get_mut().eof = true;
+    }
+}
@@ -0,0 +1,486 @@
+use crate::{
+    constants::{
+        BATCH_READ_MAX_BYTES, DEFAULT_BUFFER_CAPACITY, MAX_BUFFER_CAPACITY, MIO_BATCH_SIZE,
+        MIO_POLL_TIMEOUT_MS,
+    },
+    net::transport::{PollTransport, ReadBuffer, Transport, TransportStats},
+};
+use tracing::{error, warn};
+
+const MIO_TEMP_BUFFER_SIZE: usize = BATCH_READ_MAX_BYTES;
+use async_trait::async_trait;
+use bytes::BytesMut;
+use mio::{Events, Interest, Poll, Token};
+use std::{
+    io::{self, Read, Write},
+    net::ToSocketAddrs,
+    sync::{
+        Arc, Mutex as StdMutex,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+    },
+    task::{Context, ready},
+    thread,
+    time::Duration,
+};
+use tokio::sync::{Notify, mpsc};
+
+const READ_TOKEN: Token = Token(0);
+const WRITE_TOKEN: Token = Token(1);
+
+/// Upper bound on a single wait for writability, so a dead MIO thread cannot stall writes forever.
+const WRITABLE_WAIT_TIMEOUT: Duration = Duration::from_millis(MIO_POLL_TIMEOUT_MS);
+
+/// Buffer reallocation counters updated by the MIO thread.
+#[derive(Debug, Default)]
+struct BufferCounters {
+    grow: AtomicU64,
+    shrink: AtomicU64,
+}
+
+/// TCP transport using MIO event loop
+#[derive(Debug)]
+pub struct MioTransport {
+    /// Channel to receive batched messages
+    msg_rx: mpsc::UnboundedReceiver<Vec<ReadBuffer>>,
+    /// Shutdown flag
+    shutdown: Arc<AtomicBool>,
+    /// MIO stream for writes (wrapped for Send)
+    write_stream: Arc<StdMutex<mio::net::TcpStream>>,
+    /// Read buffer reallocation counters shared with the MIO thread
+    counters: Arc<BufferCounters>,
+    /// Signalled by the MIO thread when the write stream becomes writable
+    writable: Arc<Notify>,
+    /// Number of times `write_all` waited on `writable`
+    write_waits: u64,
+}
+
+impl MioTransport {
+    pub async fn connect<A: ToSocketAddrs>(addr: A) -> io::Result<Self> {
+        let std_stream = std::net::TcpStream::connect(addr)?;
+        std_stream.set_nodelay(true)?;
+        std_stream.set_nonblocking(true)?;
+
+        let std_stream_write = std_stream.try_clone()?;
+
+        let read_stream = Arc::new(StdMutex::new(mio::net::TcpStream::from_std(std_stream)));
+        let write_stream = Arc::new(StdMutex::new(mio::net::TcpStream::from_std(
+            std_stream_write,
+        )));
+        let shutdown = Arc::new(AtomicBool::new(false));
+
+        let (msg_tx, msg_rx) = mpsc::unbounded_channel();
+
+        let shutdown_clone = Arc::clone(&shutdown);
+        let counters = Arc::new(BufferCounters::default());
+        let counters_clone = Arc::clone(&counters);
+        let writable = Arc::new(Notify::new());
+        let writable_clone = Arc::clone(&writable);
+        let write_stream_clone = Arc::clone(&write_stream);
+
+        thread::Builder::new()
+            .name("mio-transport-loop".to_string())
+            .spawn(move || {
+                if let Err(e) = Self::mio_tight_loop(
+                    read_stream,
+                    write_stream_clone,
+                    msg_tx,
+                    shutdown_clone,
+                    counters_clone,
+                    writable_clone,
+                ) {
+                    error!("MIO tight loop error: {}", e);
+                }
+            })?;
+
+        Ok(Self {
+            msg_rx,
+            shutdown,
+            write_stream,
+            counters,
+            writable,
+            write_waits: 0,
+        })
+    }
+
+    /// MIO tight read loop running in dedicated thread. Also watches the write stream and wakes
+    /// `writable` whenever a full socket drains.
+    fn mio_tight_loop(
+        stream: Arc<StdMutex<mio::net::TcpStream>>,
+        write_stream: Arc<StdMutex<mio::net::TcpStream>>,
+        msg_tx: mpsc::UnboundedSender<Vec<ReadBuffer>>,
+        shutdown: Arc<AtomicBool>,
+        counters: Arc<BufferCounters>,
+        writable: Arc<Notify>,
+    ) -> io::Result<()> {
+        let mut poll = Poll::new()?;
+        let mut events = Events::with_capacity(128);
+        let mut temp_buf = vec![0u8; MIO_TEMP_BUFFER_SIZE];
+
+        // Accumulation buffer local to MIO thread
+        let mut read_buf = BytesMut::with_capacity(DEFAULT_BUFFER_CAPACITY);
+
+        // Register stream with MIO
+        {
+            let mut stream_lock = stream.lock().unwrap();
+            poll.registry()
+                .register(&mut *stream_lock, READ_TOKEN, Interest::READABLE)?;
+        }
+        {
+            let mut stream_lock = write_stream.lock().unwrap();
+            poll.registry()
+                .register(&mut *stream_lock, WRITE_TOKEN, Interest::WRITABLE)?;
+        }
+
+        loop {
+            if shutdown.load(Ordering::Relaxed) {
+                break;
+            }
+
+            // Poll for events with short timeout to allow shutdown checks
+            poll.poll(
+                &mut events,
+                Some(Duration::from_millis(MIO_POLL_TIMEOUT_MS)),
+            )?;
+
+            for event in events.iter() {
+                if event.token() == WRITE_TOKEN && event.is_writable() {
+                    // stores a permit if no writer is waiting yet, so the edge is not lost
+                    writable.notify_one();
+                }
+
+                if event.token() == READ_TOKEN && event.is_readable() {
+                    loop {
+                        let mut stream_lock = stream.lock().unwrap();
+
+                        match stream_lock.read(&mut temp_buf) {
+                            Ok(0) => {
+                                // EOF — clean exit; dropping msg_tx makes read_bytes report UnexpectedEof
+                                drop(stream_lock);
+                                return Ok(());
+                            }
+                            Ok(n) => {
+                                drop(stream_lock);
+
+                                let capacity = read_buf.capacity();
+                                read_buf.extend_from_slice(&temp_buf[..n]);
+                                if read_buf.capacity() > capacity {
+                                    counters.grow.fetch_add(1, Ordering::Relaxed);
+                                }
+
+                                // Extract and send raw byte chunks
+                                match Self::extract_chunks(
+                                    &mut read_buf,
+                                    MIO_BATCH_SIZE,
+                                    BATCH_READ_MAX_BYTES,
+                                    &counters,
+                                ) {
+                                    Ok(chunks) => {
+                                        if !chunks.is_empty() {
+                                            // if receiver closed, stop trying to send and exit gracefully
+                                            if msg_tx.send(chunks).is_err() {
+                                                return Ok(());
+                                            }
+                                        }
+                                    }
+                                    Err(e) => {
+                                        warn!("MIO extract error (recovering): {}", e);
+                                        // clear buffer to regain sync and continue reading.
+                                        read_buf.clear();
+                                        // continue reading instead of returning Err
+                                    }
+                                }
+                            }
+                            Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                                // socket drained for now
+                                break;
+                            }
+                            Err(e) => {
+                                // Log non-recoverable read error and exit cleanly
+                                error!("MIO transport read error: {}", e);
+                                return Err(e);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Extract up to `max_chunks` raw byte chunks from the buffer.
+    /// No framing logic - returns raw data chunks for application protocols to parse.
+    #[inline]
+    fn extract_chunks(
+        buf: &mut ReadBuffer,
+        max_chunks: usize,
+        max_bytes: usize,
+        counters: &BufferCounters,
+    ) -> io::Result<Vec<ReadBuffer>> {
+        if buf.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut chunks = Vec::with_capacity(std::cmp::min(32, max_chunks));
+        let mut total_bytes = 0;
+
+        // Split buffer into chunks (raw bytes, no framing)
+        #[cfg(any(
+            feature = "transport_bytes",
+            all(not(feature = "transport_bytes"), not(feature = "transport_slice"))
+        ))]
+        {
+            while !buf.is_empty() && chunks.len() < max_chunks {
+                // Calculate chunk size - take up to DEFAULT_BUFFER_CAPACITY or remaining bytes
+                let chunk_size = buf.len().min(DEFAULT_BUFFER_CAPACITY);
+
+                // Check if adding this chunk would exceed max_bytes
+                if total_bytes + chunk_size > max_bytes {
+                    // Take only what fits
+                    let allowed = max_bytes - total_bytes;
+                    if allowed > 0 {
+                        let chunk = buf.split_to(allowed);
+                        chunks.push(chunk);
+                    }
+                    break;
+                }
+
+                let chunk = buf.split_to(chunk_size);
+                total_bytes += chunk_size;
+                chunks.push(chunk);
+            }
+
+            // Recycle / shrink policy
+            if buf.is_empty() {
+                buf.clear();
+            } else if buf.capacity() > MAX_BUFFER_CAPACITY {
+                *buf = BytesMut::with_capacity(DEFAULT_BUFFER_CAPACITY);
+                counters.shrink.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        Ok(chunks)
+    }
+
+    #[inline]
+    pub fn recycle_buffer(&mut self) {
+        // Buffer is managed by MIO thread
+    }
+}
+
+impl Drop for MioTransport {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::Relaxed);
+    }
+}
+
+#[async_trait]
+impl Transport for MioTransport {
+    /// Read raw bytes into the provided buffer.
+    /// Returns (bytes_read, trace_data) tuple.
+    ///
+    /// The MIO thread continuously reads from the socket and batches data.
+    /// This method receives those batches and appends them to the caller's buffer.
+    /// Once the MIO thread has exited (peer closed or read error) this returns
+    /// `UnexpectedEof` so callers can tell a closed connection from an empty batch.
+    #[inline]
+    async fn read_bytes(
+        &mut self,
+        buf: &mut ReadBuffer,
+    ) -> io::Result<(usize, data_types::tracing::TraceData)> {
+        std::future::poll_fn(|cx| self.poll_read_bytes(cx, buf)).await
+    }
+
+    #[inline]
+    async fn write(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.write_all(buf).await
+    }
+
+    #[inline]
+    async fn flush(&mut self) -> io::Result<()> {
+        let mut stream = self.write_stream.lock().unwrap();
+        stream.flush()
+    }
+
+    #[inline]
+    fn try_write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut stream = self.write_stream.lock().unwrap();
+        stream.write(buf)
+    }
+
+    #[inline]
+    async fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        let mut remaining = buf;
+
+        while !remaining.is_empty() {
+            // Scope the lock to avoid Send issues
+            let result = {
+                let mut stream = self.write_stream.lock().unwrap();
+                stream.write(remaining)
+            }; // Lock is dropped here
+
+            match result {
+                Ok(0) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::WriteZero,
+                        "failed to write whole buffer",
+                    ));
+                }
+                Ok(n) => {
+                    remaining = &remaining[n..];
+                }
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                    // socket send buffer is full; park until the MIO thread sees it drain
+                    self.write_waits += 1;
+                    let _ =
+                        tokio::time::timeout(WRITABLE_WAIT_TIMEOUT, self.writable.notified()).await;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn stats(&self) -> TransportStats {
+        TransportStats {
+            buffer_grow_count: self.counters.grow.load(Ordering::Relaxed),
+            buffer_shrink_count: self.counters.shrink.load(Ordering::Relaxed),
+            write_wait_count: self.write_waits,
+        }
+    }
+
+    #[inline]
+    fn as_poll_transport(&mut self) -> Option<&mut dyn PollTransport> {
+        Some(self)
+    }
+}
+
+impl PollTransport for MioTransport {
+    #[inline]
+    fn poll_read_bytes(
+        &mut self,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuffer,
+    ) -> std::task::Poll<io::Result<(usize, data_types::tracing::TraceData)>> {
+        // Wait for data from MIO thread
+        std::task::Poll::Ready(match ready!(self.msg_rx.poll_recv(cx)) {
+            Some(chunks) if !chunks.is_empty() => {
+                // Append all batched data to caller's buffer
+                let mut total = 0;
+                for chunk in chunks {
+                    #[cfg(any(
+                        feature = "transport_bytes",
+                        all(not(feature = "transport_bytes"), not(feature = "transport_slice"))
+                    ))]
+                    {
+                        buf.extend_from_slice(&chunk);
+                        total += chunk.len();
+                    }
+
+                    #[cfg(feature = "transport_slice")]
+                    {
+                        buf.extend_from_slice(&chunk);
+                        total += chunk.len();
+                    }
+                }
+                Ok((total, data_types::tracing::TraceData::default()))
+            }
+            Some(_) => Ok((0, data_types::tracing::TraceData::default())),
+            None => Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "connection closed by peer",
+            )),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{io::Read, net::TcpListener, time::Instant};
+
+    #[tokio::test]
+    async fn test_read_bytes_reports_eof_after_peer_close() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let mut transport = MioTransport::connect(addr).await.unwrap();
+        let (mut peer, _) = listener.accept().unwrap();
+        peer.write_all(b"bye").unwrap();
+        drop(peer);
+
+        let mut buf = ReadBuffer::new();
+        let (n, _) = transport.read_bytes(&mut buf).await.unwrap();
+        assert_eq!(n, 3);
+
+        let err = transport.read_bytes(&mut buf).await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[tokio::test]
+    async fn test_poll_read_bytes_waits_for_batch() {
+        use crate::net::transport::read_bytes_fast;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let mut transport = MioTransport::connect(addr).await.unwrap();
+        let (mut peer, _) = listener.accept().unwrap();
+
+        let mut buf = ReadBuffer::new();
+        std::future::poll_fn(|cx| {
+            // nothing sent yet, so the read must park rather than report EOF
+            assert!(transport.poll_read_bytes(cx, &mut buf).is_pending());
+            std::task::Poll::Ready(())
+        })
+        .await;
+
+        peer.write_all(b"hello").unwrap();
+        let (n, _) = read_bytes_fast(&mut transport, &mut buf).await.unwrap();
+        assert_eq!(n, 5);
+        assert_eq!(&buf[..], b"hello");
+    }
+
+    #[tokio::test]
+    async fn test_write_all_waits_for_full_socket_to_drain() {
+        const PAYLOAD_LEN: usize = 32 * 1024 * 1024;
+        const STALL: Duration = Duration::from_millis(300);
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        // peer accepts but does not read until the client's send buffer has filled up
+        let reader = thread::spawn(move || {
+            let (mut socket, _) = listener.accept().unwrap();
+            thread::sleep(STALL);
+            let mut received = 0;
+            let mut buf = vec![0u8; 64 * 1024];
+            while received < PAYLOAD_LEN {
+                match socket.read(&mut buf).unwrap() {
+                    0 => break,
+                    n => received += n,
+                }
+            }
+            received
+        });
+
+        let mut transport = MioTransport::connect(addr).await.unwrap();
+        let payload = vec![0xA5u8; PAYLOAD_LEN];
+
+        let start = Instant::now();
+        transport.write_all(&payload).await.unwrap();
+        assert!(start.elapsed() >= STALL / 2);
+
+        assert_eq!(reader.join().unwrap(), PAYLOAD_LEN);
+
+        // a spinning writer would retry on every WouldBlock for the whole stall; a parked
+        // writer waits at most once per drain edge or wait timeout
+        let waits = transport.stats().write_wait_count;
+        assert!(waits > 0);
+        assert!(
+            waits < STALL.as_millis() as u64 * 10,
+            "write_all retried {waits} times, looks like a busy spin"
+        );
+    }
+}
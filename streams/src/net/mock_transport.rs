@@ -0,0 +1,141 @@
+use super::transport::{ReadBuffer, Transport};
+
+use data_types::tracing::TraceData;
+use std::{
+    collections::VecDeque,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+use tokio::io;
+
+#[derive(Debug)]
+enum ScriptedRead {
+    Data(Vec<u8>),
+    Error(io::ErrorKind),
+}
+
+/// In-memory transport for tests: reads replay a script of byte chunks and errors, writes
+/// are appended to a shared log.
+///
+/// Each read returns exactly one scripted step. Once the script is exhausted reads report
+/// EOF (`Ok((0, _))`).
+#[derive(Debug, Default)]
+pub struct MockTransport {
+    reads: VecDeque<ScriptedRead>,
+    written: Arc<Mutex<Vec<u8>>>,
+    write_calls: Arc<AtomicUsize>,
+}
+
+impl MockTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue a chunk of bytes to be returned by the next unscripted read.
+    pub fn with_read(mut self, chunk: impl Into<Vec<u8>>) -> Self {
+        self.reads.push_back(ScriptedRead::Data(chunk.into()));
+        self
+    }
+
+    /// Queue a read that fails with `kind`, e.g. `ConnectionReset` to trigger a reconnect.
+    pub fn with_error(mut self, kind: io::ErrorKind) -> Self {
+        self.reads.push_back(ScriptedRead::Error(kind));
+        self
+    }
+
+    /// Handle to everything written so far; stays valid after the transport is moved.
+    pub fn written(&self) -> Arc<Mutex<Vec<u8>>> {
+        Arc::clone(&self.written)
+    }
+
+    /// Handle to the number of write calls so far, to check how writes were batched.
+    pub fn write_calls(&self) -> Arc<AtomicUsize> {
+        Arc::clone(&self.write_calls)
+    }
+
+    /// Number of scripted reads not yet consumed.
+    pub fn remaining_reads(&self) -> usize {
+        self.reads.len()
+    }
+
+    fn record(&self, buf: &[u8]) {
+        self.write_calls.fetch_add(1, Ordering::Relaxed);
+        self.written
+            .lock()
+            .expect("mock write log poisoned")
+            .extend_from_slice(buf);
+    }
+}
+
+#[async_trait::async_trait]
+impl Transport for MockTransport {
+    async fn read_bytes(&mut self, buf: &mut ReadBuffer) -> io::Result<(usize, TraceData)> {
+        let trace_data = TraceData::with_current_context();
+
+        match self.reads.pop_front() {
+            Some(ScriptedRead::Data(chunk)) => {
+                buf.extend_from_slice(&chunk);
+                Ok((chunk.len(), trace_data))
+            }
+            Some(ScriptedRead::Error(kind)) => Err(io::Error::new(kind, "scripted mock error")),
+            None => Ok((0, trace_data)),
+        }
+    }
+
+    async fn write(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.record(buf);
+        Ok(())
+    }
+
+    async fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn try_write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.record(buf);
+        Ok(buf.len())
+    }
+
+    async fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.record(buf);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_reads_follow_script_then_eof() {
+        let mut transport = MockTransport::new()
+            .with_read(b"abc".to_vec())
+            .with_error(io::ErrorKind::ConnectionReset)
+            .with_read(b"de".to_vec());
+        let mut buf = ReadBuffer::new();
+
+        assert_eq!(transport.read_bytes(&mut buf).await.unwrap().0, 3);
+        let err = transport.read_bytes(&mut buf).await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::ConnectionReset);
+        assert_eq!(transport.read_bytes(&mut buf).await.unwrap().0, 2);
+        assert_eq!(transport.read_bytes(&mut buf).await.unwrap().0, 0);
+        assert_eq!(&buf[..], b"abcde");
+        assert_eq!(transport.remaining_reads(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_writes_are_recorded() {
+        let mut transport = MockTransport::new();
+        let written = transport.written();
+        let write_calls = transport.write_calls();
+
+        transport.write_all(b"one").await.unwrap();
+        assert_eq!(transport.try_write(b"two").unwrap(), 3);
+        transport.write(b"three").await.unwrap();
+
+        assert_eq!(&written.lock().unwrap()[..], b"onetwothree");
+        assert_eq!(write_calls.load(Ordering::Relaxed), 3);
+    }
+}
@@ -0,0 +1,63 @@
+#[cfg(any(test, feature = "test-util"))]
+pub mod mock_transport;
+pub mod transport;
+
+#[cfg(feature = "mio_transport")]
+pub mod mio_transport;
+
+#[cfg(feature = "tokio_transport")]
+pub mod tokio_transport;
+
+#[cfg(feature = "file_transport")]
+pub mod file_transport;
+
+#[cfg(feature = "compressed_transport")]
+pub mod compressed_transport;
+
+#[cfg(any(feature = "file_transport", feature = "compressed_transport"))]
+mod gzip;
+
+/// Transport [`SoupBinTcpClient`](crate::SoupBinTcpClient) dials with: `TokioTransport`,
+/// or `MioTransport` when only `mio_transport` is enabled.
+///
+/// With both features enabled tokio wins; check [`active_transport`] to confirm.
+#[cfg(feature = "tokio_transport")]
+pub type NetworkTransport = tokio_transport::TokioTransport;
+
+/// Transport [`SoupBinTcpClient`](crate::SoupBinTcpClient) dials with: `TokioTransport`,
+/// or `MioTransport` when only `mio_transport` is enabled.
+///
+/// With both features enabled tokio wins; check [`active_transport`] to confirm.
+#[cfg(all(feature = "mio_transport", not(feature = "tokio_transport")))]
+pub type NetworkTransport = mio_transport::MioTransport;
+
+#[cfg(not(any(feature = "tokio_transport", feature = "mio_transport")))]
+compile_error!("Either tokio_transport or mio_transport feature must be enabled");
+
+/// Name of the transport compiled in as [`NetworkTransport`]: `"tokio"` or `"mio"`.
+pub const fn active_transport() -> &'static str {
+    if cfg!(feature = "tokio_transport") {
+        "tokio"
+    } else {
+        "mio"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_active_transport_matches_features() {
+        #[cfg(feature = "tokio_transport")]
+        assert_eq!(active_transport(), "tokio");
+        #[cfg(not(feature = "tokio_transport"))]
+        assert_eq!(active_transport(), "mio");
+
+        assert!(
+            std::any::type_name::<NetworkTransport>()
+                .to_lowercase()
+                .contains(active_transport())
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use super::transport::{PollTransport, ReadBuffer, Transport};
+
+use data_types::tracing::TraceData;
+use std::task::{Context, Poll, ready};
+use tokio::{
+    io::{self, AsyncReadExt, AsyncWriteExt, Result as IoResult},
+    net::TcpStream,
+};
+
+/// Standard TCP transport using tokio
+#[derive(Debug)]
+pub struct TokioTransport {
+    pub stream: TcpStream,
+}
+
+impl TokioTransport {
+    pub async fn connect(addr: &str) -> IoResult<Self> {
+        let stream = TcpStream::connect(addr).await?;
+        stream.set_nodelay(true)?;
+
+        Ok(Self { stream })
+    }
+}
+
+#[async_trait::async_trait]
+impl Transport for TokioTransport {
+    #[inline]
+    async fn read_bytes(&mut self, buf: &mut ReadBuffer) -> IoResult<(usize, TraceData)> {
+        let trace_data = TraceData::with_current_context();
+
+        let n = self.stream.read_buf(buf).await?;
+        Ok((n, trace_data))
+    }
+
+    #[inline]
+    async fn write(&mut self, buf: &[u8]) -> IoResult<()> {
+        self.stream.write_all(buf).await
+    }
+
+    #[inline]
+    async fn flush(&mut self) -> IoResult<()> {
+        self.stream.flush().await
+    }
+
+    #[inline]
+    fn try_write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        self.stream.try_write(buf)
+    }
+
+    #[inline]
+    async fn write_all(&mut self, buf: &[u8]) -> IoResult<()> {
+        self.stream.write_all(buf).await
+    }
+
+    #[inline]
+    fn as_poll_transport(&mut self) -> Option<&mut dyn PollTransport> {
+        Some(self)
+    }
+}
+
+impl PollTransport for TokioTransport {
+    #[inline]
+    fn poll_read_bytes(
+        &mut self,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuffer,
+    ) -> Poll<IoResult<(usize, TraceData)>> {
+        loop {
+            ready!(self.stream.poll_read_ready(cx))?;
+
+            match self.stream.try_read_buf(buf) {
+                Ok(n) => return Poll::Ready(Ok((n, TraceData::with_current_context()))),
+                // readiness was stale, poll again to register the waker
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => continue,
+                Err(e) => return Poll::Ready(Err(e)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::net::transport::read_bytes_fast;
+    use tokio::net::TcpListener;
+
+    #[tokio::test]
+    async fn test_poll_read_bytes_reads_and_reports_eof() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+        let (transport, accepted) = tokio::join!(TokioTransport::connect(&addr), listener.accept());
+        let mut transport = transport.unwrap();
+        let mut server = accepted.unwrap().0;
+
+        let mut buf = ReadBuffer::with_capacity(64);
+        let pending = std::future::poll_fn(|cx| {
+            // nothing sent yet, so the read must park rather than report EOF
+            assert!(transport.poll_read_bytes(cx, &mut buf).is_pending());
+            Poll::Ready(())
+        });
+        pending.await;
+
+        server.write_all(b"hello").await.unwrap();
+        let (n, _) = std::future::poll_fn(|cx| transport.poll_read_bytes(cx, &mut buf))
+            .await
+            .unwrap();
+        assert_eq!(n, 5);
+
+        server.write_all(b" world").await.unwrap();
+        drop(server);
+        let (n, _) = read_bytes_fast(&mut transport, &mut buf).await.unwrap();
+        assert_eq!(n, 6);
+        assert_eq!(&buf[..], b"hello world");
+
+        let (n, _) = read_bytes_fast(&mut transport, &mut buf).await.unwrap();
+        assert_eq!(n, 0);
+    }
+}
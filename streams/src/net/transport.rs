@@ -0,0 +1,83 @@
+use bytes::BytesMut;
+use data_types::tracing::TraceData;
+use std::task::{Context, Poll};
+use tokio::io;
+
+/// Read buffer type for network I/O accumulation.
+pub type ReadBuffer = BytesMut;
+
+/// Snapshot of buffer reallocation and write back-pressure counters kept by a transport.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TransportStats {
+    /// Number of times the transport grew an internal buffer.
+    pub buffer_grow_count: u64,
+    /// Number of times the transport replaced an oversized internal buffer.
+    pub buffer_shrink_count: u64,
+    /// Number of times a write waited for a full socket to become writable.
+    pub write_wait_count: u64,
+}
+
+/// Transport abstraction for different I/O implementations (MIO, Tokio, io_uring, DPDK, etc.)
+///
+/// Uses BytesMut for efficient network accumulation. The transport does NOT perform any
+/// framing - it provides raw byte I/O. Application protocols (like SoupBinTCP) must handle
+/// their own message boundaries and use buffer pools for packet data.
+#[async_trait::async_trait]
+pub trait Transport: Send + Sync {
+    /// Read raw bytes from the socket into the provided buffer.
+    /// Returns number of bytes read (0 = EOF).
+    async fn read_bytes(&mut self, buf: &mut ReadBuffer) -> io::Result<(usize, TraceData)>;
+
+    /// Write data to the transport.
+    async fn write(&mut self, buf: &[u8]) -> io::Result<()>;
+
+    /// Flush any buffered data.
+    async fn flush(&mut self) -> io::Result<()>;
+
+    /// Try to write without blocking (for non-async contexts).
+    fn try_write(&mut self, buf: &[u8]) -> io::Result<usize>;
+
+    /// Write all data (blocking until complete).
+    async fn write_all(&mut self, buf: &[u8]) -> io::Result<()>;
+
+    /// Buffer reallocation counters. Transports without internal buffers report zeros.
+    fn stats(&self) -> TransportStats {
+        TransportStats::default()
+    }
+
+    /// The non-boxed read path, if this transport has one. Readers prefer it over
+    /// [`read_bytes`](Self::read_bytes), which allocates a future per call.
+    fn as_poll_transport(&mut self) -> Option<&mut dyn PollTransport> {
+        None
+    }
+}
+
+/// Poll-based read for the hot path, avoiding the boxed future behind
+/// [`Transport::read_bytes`].
+///
+/// Same contract as `read_bytes`: appends to `buf`, `Ok((0, _))` means EOF, and a read
+/// dropped while pending loses no data.
+///
+/// Implemented by the network transports, `TokioTransport` and `MioTransport`. The file
+/// replay, compressed and mock transports are not on the latency-sensitive path and keep only
+/// `read_bytes`: their reads await a pacing sleep or an async decoder, which would each need a
+/// hand-written state machine to poll without boxing.
+pub trait PollTransport: Transport {
+    fn poll_read_bytes(
+        &mut self,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuffer,
+    ) -> Poll<io::Result<(usize, TraceData)>>;
+}
+
+/// Read through [`PollTransport::poll_read_bytes`] when available, otherwise through
+/// [`Transport::read_bytes`].
+pub async fn read_bytes_fast(
+    transport: &mut (impl Transport + ?Sized),
+    buf: &mut ReadBuffer,
+) -> io::Result<(usize, TraceData)> {
+    match transport.as_poll_transport() {
+        Some(transport) => std::future::poll_fn(|cx| transport.poll_read_bytes(cx, buf)).await,
+        None => transport.read_bytes(buf).await,
+    }
+}
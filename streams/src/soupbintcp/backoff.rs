@@ -0,0 +1,78 @@
+use std::time::Duration;
+
+pub use data_types::backoff::ExponentialBackoff;
+
+/// Decides how long the client waits before each reconnection attempt.
+pub trait BackoffStrategy: Send {
+    /// Delay before reconnection attempt `attempt`, counted from 1.
+    fn next_delay(&mut self, attempt: u32) -> Duration;
+}
+
+/// Grows the delay by the backoff's multiplier per attempt, capped at its max and shortened by
+/// its jitter. The client's attempt count is used; the backoff's own is left untouched.
+impl BackoffStrategy for ExponentialBackoff {
+    fn next_delay(&mut self, attempt: u32) -> Duration {
+        self.jittered_delay(attempt)
+    }
+}
+
+/// Waits the same delay before every attempt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConstantBackoff(pub Duration);
+
+impl BackoffStrategy for ConstantBackoff {
+    fn next_delay(&mut self, _attempt: u32) -> Duration {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strategy_delays(backoff: &mut dyn BackoffStrategy, attempts: u32) -> Vec<Duration> {
+        (1..=attempts).map(|n| backoff.next_delay(n)).collect()
+    }
+
+    #[test]
+    fn test_exponential_doubles_up_to_max() {
+        let mut backoff = ExponentialBackoff::new(
+            Duration::from_millis(100),
+            Duration::from_millis(500),
+            2.0,
+            0.0,
+        );
+        let delays: Vec<_> = strategy_delays(&mut backoff, 5)
+            .iter()
+            .map(Duration::as_millis)
+            .collect();
+        assert_eq!(delays, [100, 200, 400, 500, 500]);
+    }
+
+    #[test]
+    fn test_exponential_does_not_overflow() {
+        let max = Duration::from_secs(30);
+        let mut backoff = ExponentialBackoff::new(Duration::from_secs(1), max, 2.0, 0.0);
+        assert_eq!(BackoffStrategy::next_delay(&mut backoff, u32::MAX), max);
+    }
+
+    #[test]
+    fn test_exponential_jitter_only_shortens() {
+        let mut backoff =
+            ExponentialBackoff::new(Duration::from_secs(1), Duration::from_secs(1), 2.0, 0.5);
+        let delays = strategy_delays(&mut backoff, 50);
+
+        assert!(
+            delays
+                .iter()
+                .all(|d| (Duration::from_millis(500)..=Duration::from_secs(1)).contains(d))
+        );
+        assert!(delays.iter().any(|d| *d != delays[0]));
+    }
+
+    #[test]
+    fn test_constant_ignores_attempt() {
+        let mut backoff = ConstantBackoff(Duration::from_millis(250));
+        assert_eq!(backoff.next_delay(1), backoff.next_delay(10));
+    }
+}
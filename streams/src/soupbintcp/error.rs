@@ -0,0 +1,50 @@
+use std::io;
+
+use thiserror::Error;
+
+use super::soupbintcp_packet::LoginRejectReason;
+
+/// Errors raised while building SoupBinTCP packets and client configs, or logging in.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum ProtocolError {
+    /// A fixed-width field value does not fit its width on the wire.
+    #[error("{field} is {len} bytes, exceeds field width of {width}")]
+    FieldTooLong {
+        field: &'static str,
+        len: usize,
+        width: usize,
+    },
+
+    /// A required config field was never set.
+    #[error("{field} is required")]
+    MissingField { field: &'static str },
+
+    /// The server answered the login request with a Login Rejected packet.
+    #[error("login rejected: {reason}")]
+    LoginRejected { reason: LoginRejectReason },
+
+    /// A Sequenced Data packet carried no payload, under [`EmptyPayloadPolicy::Error`].
+    ///
+    /// [`EmptyPayloadPolicy::Error`]: super::soupbintcp_client::EmptyPayloadPolicy::Error
+    #[error("sequenced data packet {sequence} has an empty payload")]
+    EmptySequencedData { sequence: u64 },
+
+    /// A packet's length prefix declares more than the configured `max_packet_len`, most
+    /// likely a corrupt stream.
+    #[error("declared packet length {len} exceeds maximum of {max}")]
+    PacketTooLarge { len: usize, max: usize },
+}
+
+impl From<ProtocolError> for io::Error {
+    fn from(err: ProtocolError) -> Self {
+        let kind = match err {
+            ProtocolError::LoginRejected { .. } => io::ErrorKind::PermissionDenied,
+            ProtocolError::EmptySequencedData { .. } | ProtocolError::PacketTooLarge { .. } => {
+                io::ErrorKind::InvalidData
+            }
+            _ => io::ErrorKind::InvalidInput,
+        };
+        io::Error::new(kind, err)
+    }
+}
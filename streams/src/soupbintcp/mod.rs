@@ -0,0 +1,17 @@
+pub mod backoff;
+pub mod error;
+pub mod recorder;
+pub mod sequence;
+pub mod soupbintcp_client;
+pub mod soupbintcp_packet;
+
+// Re-export common types
+pub use backoff::{BackoffStrategy, ConstantBackoff, ExponentialBackoff};
+pub use error::ProtocolError;
+pub use recorder::RawRecorder;
+pub use sequence::Sequence;
+pub use soupbintcp_client::{
+    BufferPolicy, ClientStats, ConnectionEvent, DeliveryPolicy, EmptyPayloadPolicy, FeedStatus,
+    LengthPrefix, RawTap, SequenceExtractor, TransportConnector,
+};
+pub use soupbintcp_packet::LoginRejectReason;
@@ -0,0 +1,87 @@
+use super::soupbintcp_client::RawTap;
+
+use bytes::Bytes;
+use crossbeam_channel::{Sender, unbounded};
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+    thread::JoinHandle,
+};
+
+/// Records the raw bytes read by a [`SoupBinTcpClient`](crate::SoupBinTcpClient) to a file
+/// that `FileReplayTransport` can replay later.
+///
+/// Taps only copy the bytes onto a channel; a background thread does the buffered file
+/// writes, so the read path never waits on disk.
+#[derive(Debug)]
+pub struct RawRecorder {
+    sender: Sender<Bytes>,
+    writer: JoinHandle<io::Result<u64>>,
+}
+
+impl RawRecorder {
+    /// Create (or truncate) `path` and start the writer thread.
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = File::create(path)?;
+        let (sender, receiver) = unbounded::<Bytes>();
+
+        let writer = std::thread::Builder::new()
+            .name("raw-recorder".to_string())
+            .spawn(move || {
+                let mut out = BufWriter::new(file);
+                let mut written = 0u64;
+                for chunk in receiver {
+                    out.write_all(&chunk)?;
+                    written += chunk.len() as u64;
+                }
+                out.flush()?;
+                Ok(written)
+            })?;
+
+        Ok(Self { sender, writer })
+    }
+
+    /// A tap for [`SoupBinTcpClient::set_raw_tap`](crate::SoupBinTcpClient::set_raw_tap).
+    pub fn tap(&self) -> RawTap {
+        let sender = self.sender.clone();
+        Box::new(move |bytes: &[u8]| {
+            // the writer only goes away after a write error, nothing left to record then
+            let _ = sender.send(Bytes::copy_from_slice(bytes));
+        })
+    }
+
+    /// Flush the recording and return the number of bytes written.
+    ///
+    /// Blocks until every tap handed out by [`tap`](Self::tap) has been dropped.
+    pub fn finish(self) -> io::Result<u64> {
+        drop(self.sender);
+        self.writer
+            .join()
+            .map_err(|_| io::Error::other("raw recorder thread panicked"))?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recorder_writes_tapped_bytes_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("session.bin");
+
+        let recorder = RawRecorder::create(&path).unwrap();
+        let mut tap = recorder.tap();
+        tap(&[0x00, 0x02, b'S']);
+        tap(b"x");
+        tap(&[0x00, 0x01, b'H']);
+        drop(tap);
+
+        assert_eq!(recorder.finish().unwrap(), 7);
+        assert_eq!(
+            std::fs::read(&path).unwrap(),
+            [0x00, 0x02, b'S', b'x', 0x00, 0x01, b'H']
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use serde::{Deserialize, Serialize};
+use std::{fmt, io, str::FromStr};
+
+/// SoupBinTCP sequence number.
+///
+/// Holds the number of the last sequenced message seen; `Sequence(0)` means nothing has been
+/// received yet. Use [`Sequence::next`] for the number to request from the server instead of
+/// doing `+ 1` arithmetic by hand.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct Sequence(pub u64);
+
+impl Sequence {
+    /// Lowest sequence number allowed on the wire.
+    pub const MIN: Sequence = Sequence(1);
+
+    /// The sequence number following this one.
+    #[inline]
+    pub fn next(self) -> Self {
+        Sequence(self.0 + 1)
+    }
+
+    /// The sequence number preceding this one, saturating at zero.
+    #[inline]
+    pub fn prev(self) -> Self {
+        Sequence(self.0.saturating_sub(1))
+    }
+
+    /// Decimal representation used in the login request sequence number field.
+    #[inline]
+    pub fn as_wire_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl From<u64> for Sequence {
+    #[inline]
+    fn from(seq: u64) -> Self {
+        Sequence(seq)
+    }
+}
+
+impl From<Sequence> for u64 {
+    #[inline]
+    fn from(seq: Sequence) -> Self {
+        seq.0
+    }
+}
+
+/// Parse a space-padded wire or config value, enforcing the protocol minimum of 1.
+impl FromStr for Sequence {
+    type Err = io::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let seq = value.trim().parse::<u64>().map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid sequence number '{}': {}", value, e),
+            )
+        })?;
+
+        if seq < Self::MIN.0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "sequence number must be at least {}, got {}",
+                    Self::MIN.0,
+                    seq
+                ),
+            ));
+        }
+
+        Ok(Sequence(seq))
+    }
+}
+
+impl fmt::Display for Sequence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str_enforces_minimum() {
+        assert_eq!("1".parse::<Sequence>().unwrap(), Sequence(1));
+        assert_eq!("  42  ".parse::<Sequence>().unwrap(), Sequence(42));
+
+        let err = "0".parse::<Sequence>().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert!("".parse::<Sequence>().is_err());
+        assert!("abc".parse::<Sequence>().is_err());
+    }
+
+    #[test]
+    fn test_next_and_wire_string() {
+        let seq = Sequence::default();
+        assert_eq!(seq.next(), Sequence::MIN);
+        assert_eq!(seq.next().as_wire_string(), "1");
+        assert_eq!(Sequence(41).next().as_wire_string(), "42");
+    }
+
+    #[test]
+    fn test_prev_saturates() {
+        assert_eq!(Sequence(5).prev(), Sequence(4));
+        assert_eq!(Sequence(0).prev(), Sequence(0));
+    }
+}
@@ -0,0 +1,2804 @@
+use crate::{
+    constants::{
+        DEFAULT_BUFFER_CAPACITY, DEFAULT_HEARTBEAT_INTERVAL_SECS, DEFAULT_MAX_PACKET_LEN,
+        DEFAULT_MAX_RECONNECT_ATTEMPTS, DEFAULT_RECONNECT_DELAY_MS, MAX_BUFFER_CAPACITY,
+        MAX_RECONNECT_DELAY_MS, MIN_SPARE_CAPACITY, RECONNECT_JITTER, SOUPBINTCP_LENGTH_SIZE,
+    },
+    net::{
+        NetworkTransport,
+        transport::{ReadBuffer, Transport, TransportStats, read_bytes_fast},
+    },
+    soupbintcp::{
+        backoff::{BackoffStrategy, ExponentialBackoff},
+        error::ProtocolError,
+        sequence::Sequence,
+        soupbintcp_packet::{ClientPacket, ServerPacket},
+    },
+};
+use bytes::Bytes;
+use crossbeam_channel::{Receiver, Sender, TrySendError};
+use data_types::{
+    PacketContext, PacketParser, data_feed_type::DataFeedType, time::UnixNanoseconds,
+};
+use logger::{debug, error, info, warn};
+use queue::{GapDetector, PacketData, SequenceCheck};
+use serde::{Deserialize, Serialize};
+use std::{fmt, future::Future, io, pin::Pin};
+
+/// Connection lifecycle notification; serializes as JSON with a `"type"` tag, e.g.
+/// `{"type":"Reconnecting","attempt":1,"delay_ms":1000}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ConnectionEvent {
+    /// Socket is up and the login request has been sent; not yet authenticated.
+    Connected,
+    /// The server accepted the login for `session` and will stream from `start_sequence`.
+    LoggedIn {
+        session: String,
+        start_sequence: Sequence,
+    },
+    /// About to wait `delay_ms` and then make reconnect attempt number `attempt` (1-based).
+    /// Sent for every reconnect, including the one refused for exceeding the attempt limit,
+    /// which is followed by [`Disconnected`](Self::Disconnected).
+    Reconnecting {
+        attempt: u32,
+        delay_ms: u64,
+    },
+    Reconnected,
+    /// Gave up on the connection, with a human-readable reason when known.
+    Disconnected {
+        reason: Option<String>,
+    },
+    /// A payload carried sequence `found`, past the `expected` next one; the client resyncs to
+    /// `found`. Only reported once a [`SequenceExtractor`] is installed.
+    SequenceGap {
+        expected: Sequence,
+        found: Sequence,
+    },
+    /// A payload carried [`FIRST_SEQUENCE`](queue::FIRST_SEQUENCE) while `expected` was further
+    /// along, e.g. after a venue restart; the client resyncs to `found` and keeps delivering.
+    /// Only reported once a [`SequenceExtractor`] is installed.
+    SequenceReset {
+        expected: Sequence,
+        found: Sequence,
+    },
+}
+
+/// A [`ConnectionEvent`] stamped with its feed and time, ready to push to a dashboard.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeedStatus {
+    pub feed: DataFeedType,
+    pub event: ConnectionEvent,
+    pub at: UnixNanoseconds,
+}
+
+impl FeedStatus {
+    /// Stamp `event` with the current wall-clock time.
+    pub fn now(feed: DataFeedType, event: ConnectionEvent) -> Self {
+        let since_epoch = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("Time went backwards");
+        Self {
+            feed,
+            event,
+            at: UnixNanoseconds(since_epoch.as_nanos() as u64),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SoupBinTcpConfig {
+    pub host: String,
+    pub port: u16,
+    pub username: String,
+    pub password: String,
+    pub feed_type: DataFeedType,
+    pub start_sequence: Sequence,
+    pub start_session: String,
+    pub buffer_policy: BufferPolicy,
+    pub length_prefix: LengthPrefix,
+    /// Largest packet length (after the prefix) accepted; anything larger fails the read
+    /// with [`ProtocolError::PacketTooLarge`] instead of being buffered.
+    pub max_packet_len: usize,
+}
+
+/// Width of the big-endian length prefix on packets read from the server.
+///
+/// Standard SoupBinTCP uses [`LengthPrefix::U16`]; some venues send a 4-byte prefix.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LengthPrefix {
+    #[default]
+    U16,
+    U32,
+}
+
+impl LengthPrefix {
+    /// Bytes taken by the length prefix.
+    #[inline]
+    pub const fn size(self) -> usize {
+        match self {
+            LengthPrefix::U16 => SOUPBINTCP_LENGTH_SIZE,
+            LengthPrefix::U32 => 4,
+        }
+    }
+
+    /// Bytes preceding the payload: length prefix plus packet type.
+    #[inline]
+    pub const fn header_len(self) -> usize {
+        self.size() + 1
+    }
+
+    /// Length of the complete frame at the start of `buf`, prefix included, or `None` if it is
+    /// not fully buffered yet.
+    #[inline]
+    fn complete_frame_len(self, buf: &[u8]) -> Option<usize> {
+        let total_len = self.size().checked_add(self.declared_len(buf)?)?;
+        (buf.len() >= total_len).then_some(total_len)
+    }
+
+    /// Packet length from the prefix at the start of `buf`, if the prefix is complete.
+    #[inline]
+    fn declared_len(self, buf: &[u8]) -> Option<usize> {
+        Some(match self {
+            LengthPrefix::U16 => u16::from_be_bytes(*buf.first_chunk()?) as usize,
+            LengthPrefix::U32 => u32::from_be_bytes(*buf.first_chunk()?) as usize,
+        })
+    }
+
+    /// Fail if the frame starting `buf` declares more than `max` bytes.
+    #[inline]
+    fn check_declared_len(self, buf: &[u8], max: usize) -> Result<(), ProtocolError> {
+        match self.declared_len(buf) {
+            Some(len) if len > max => Err(ProtocolError::PacketTooLarge { len, max }),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl SoupBinTcpConfig {
+    /// Start building a config; see [`SoupBinTcpConfigBuilder::build`] for validation.
+    pub fn builder() -> SoupBinTcpConfigBuilder {
+        SoupBinTcpConfigBuilder::default()
+    }
+}
+
+/// Builder for [`SoupBinTcpConfig`].
+///
+/// `host`, `port`, `username`, `password` and `feed_type` are required. The start sequence
+/// defaults to [`Sequence::MIN`], the session to blank (current session), the buffer policy
+/// to [`BufferPolicy::default`], the length prefix to [`LengthPrefix::U16`] and the maximum
+/// packet length to [`DEFAULT_MAX_PACKET_LEN`].
+#[derive(Debug, Clone, Default)]
+pub struct SoupBinTcpConfigBuilder {
+    host: Option<String>,
+    port: Option<u16>,
+    username: Option<String>,
+    password: Option<String>,
+    feed_type: Option<DataFeedType>,
+    start_sequence: Option<Sequence>,
+    start_session: Option<String>,
+    buffer_policy: Option<BufferPolicy>,
+    length_prefix: Option<LengthPrefix>,
+    max_packet_len: Option<usize>,
+}
+
+impl SoupBinTcpConfigBuilder {
+    pub fn host(mut self, host: impl Into<String>) -> Self {
+        self.host = Some(host.into());
+        self
+    }
+
+    pub fn port(mut self, port: u16) -> Self {
+        self.port = Some(port);
+        self
+    }
+
+    pub fn username(mut self, username: impl Into<String>) -> Self {
+        self.username = Some(username.into());
+        self
+    }
+
+    pub fn password(mut self, password: impl Into<String>) -> Self {
+        self.password = Some(password.into());
+        self
+    }
+
+    pub fn feed_type(mut self, feed_type: DataFeedType) -> Self {
+        self.feed_type = Some(feed_type);
+        self
+    }
+
+    pub fn start_sequence(mut self, start_sequence: impl Into<Sequence>) -> Self {
+        self.start_sequence = Some(start_sequence.into());
+        self
+    }
+
+    pub fn start_session(mut self, start_session: impl Into<String>) -> Self {
+        self.start_session = Some(start_session.into());
+        self
+    }
+
+    pub fn buffer_policy(mut self, buffer_policy: BufferPolicy) -> Self {
+        self.buffer_policy = Some(buffer_policy);
+        self
+    }
+
+    pub fn length_prefix(mut self, length_prefix: LengthPrefix) -> Self {
+        self.length_prefix = Some(length_prefix);
+        self
+    }
+
+    pub fn max_packet_len(mut self, max_packet_len: usize) -> Self {
+        self.max_packet_len = Some(max_packet_len);
+        self
+    }
+
+    /// Check required fields and that the login fields fit their SoupBinTCP widths.
+    pub fn build(self) -> Result<SoupBinTcpConfig, ProtocolError> {
+        let config = SoupBinTcpConfig {
+            host: self
+                .host
+                .ok_or(ProtocolError::MissingField { field: "host" })?,
+            port: self
+                .port
+                .ok_or(ProtocolError::MissingField { field: "port" })?,
+            username: self
+                .username
+                .ok_or(ProtocolError::MissingField { field: "username" })?,
+            password: self
+                .password
+                .ok_or(ProtocolError::MissingField { field: "password" })?,
+            feed_type: self
+                .feed_type
+                .ok_or(ProtocolError::MissingField { field: "feed_type" })?,
+            start_sequence: self.start_sequence.unwrap_or(Sequence::MIN),
+            start_session: self.start_session.unwrap_or_default(),
+            buffer_policy: self.buffer_policy.unwrap_or_default(),
+            length_prefix: self.length_prefix.unwrap_or_default(),
+            max_packet_len: self.max_packet_len.unwrap_or(DEFAULT_MAX_PACKET_LEN),
+        };
+
+        for (field, value, width) in [
+            ("username", &config.username, ClientPacket::USERNAME_WIDTH),
+            ("password", &config.password, ClientPacket::PASSWORD_WIDTH),
+            (
+                "session_id",
+                &config.start_session,
+                ClientPacket::SESSION_ID_WIDTH,
+            ),
+        ] {
+            if value.len() > width {
+                return Err(ProtocolError::FieldTooLong {
+                    field,
+                    len: value.len(),
+                    width,
+                });
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+/// Read buffer sizing used by [`SoupBinTcpClient::pump_packets`].
+///
+/// The defaults match the crate constants; high-throughput feeds can raise `max` to avoid
+/// repeated shrink/grow cycles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufferPolicy {
+    /// Capacity of the read buffer at connect time and the floor when shrinking.
+    pub initial: usize,
+    /// Capacity above which a mostly empty buffer is shrunk.
+    pub max: usize,
+    /// Spare capacity kept free before each read.
+    pub min_spare: usize,
+    /// Buffered length below which an oversized buffer counts as mostly empty.
+    pub shrink: usize,
+}
+
+impl Default for BufferPolicy {
+    fn default() -> Self {
+        Self {
+            initial: DEFAULT_BUFFER_CAPACITY,
+            max: MAX_BUFFER_CAPACITY,
+            min_spare: MIN_SPARE_CAPACITY,
+            shrink: MIN_SPARE_CAPACITY,
+        }
+    }
+}
+
+/// Counters describing how often the client reallocated its read buffer or dropped packets.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ClientStats {
+    /// Number of times the read buffer was reallocated to a larger capacity.
+    pub buffer_grow_count: u64,
+    /// Number of times an oversized, mostly empty read buffer was replaced by a smaller one.
+    pub buffer_shrink_count: u64,
+    /// Number of packets discarded on a full channel under a dropping [`DeliveryPolicy`].
+    pub dropped_packets: u64,
+    /// Number of [`ConnectionEvent::SequenceGap`]s detected.
+    pub sequence_gaps: u64,
+    /// Number of packets dropped because their payload repeated an earlier sequence.
+    pub duplicate_packets: u64,
+    /// Number of [`ConnectionEvent::SequenceReset`]s detected.
+    pub sequence_resets: u64,
+}
+
+/// What the client does with a Sequenced Data packet whose payload is empty.
+///
+/// Such a packet still takes a sequence number. Venues send it as a filler, e.g. for a
+/// suppressed or gap-filled message, so it carries nothing to parse.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EmptyPayloadPolicy {
+    /// Advance the sequence and deliver nothing.
+    #[default]
+    Skip,
+    /// Fail with [`ProtocolError::EmptySequencedData`] (kind `InvalidData`).
+    Error,
+}
+
+/// What the client does with a parsed packet when the packet channel is full.
+///
+/// [`Block`](Self::Block) never loses data, but a slow consumer stalls reading and lets the
+/// socket back up, so every later packet arrives late. The drop policies keep reading at wire
+/// speed and deliver fresh data, at the cost of gaps the consumer has to tolerate.
+#[derive(Default)]
+pub enum DeliveryPolicy<T> {
+    /// Wait for the consumer to make room.
+    #[default]
+    Block,
+    /// Discard the packet that did not fit.
+    DropNewest,
+    /// Discard the oldest queued packet through this receiver (a clone of the consumer's)
+    /// to make room. When nothing is queued to discard, as on a zero-capacity channel, the
+    /// new packet is dropped instead.
+    DropOldest(Receiver<PacketData<T>>),
+}
+
+impl<T> fmt::Debug for DeliveryPolicy<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            DeliveryPolicy::Block => "Block",
+            DeliveryPolicy::DropNewest => "DropNewest",
+            DeliveryPolicy::DropOldest(_) => "DropOldest",
+        })
+    }
+}
+
+type ParserFn<T> = Box<dyn PacketParser<T> + Send + Sync>;
+
+/// Callback receiving the raw bytes of every successful transport read, before parsing.
+pub type RawTap = Box<dyn FnMut(&[u8]) + Send>;
+
+/// Callback reading the sequence number a venue repeats inside a Sequenced Data payload,
+/// or `None` when this payload does not carry one.
+pub type SequenceExtractor = Box<dyn Fn(&[u8]) -> Option<u64> + Send + Sync>;
+
+/// Future returned by a [`TransportConnector`].
+pub type ConnectFuture = Pin<Box<dyn Future<Output = io::Result<Box<dyn Transport>>> + Send>>;
+
+/// Opens a new transport to a `host:port` address for reconnects and new sessions.
+pub type TransportConnector = Box<dyn FnMut(String) -> ConnectFuture + Send>;
+
+/// Connector dialing [`NetworkTransport`], used unless one is installed.
+fn network_connector() -> TransportConnector {
+    Box::new(|addr| {
+        Box::pin(async move {
+            let transport = NetworkTransport::connect(&addr).await?;
+            Ok(Box::new(transport) as Box<dyn Transport>)
+        })
+    })
+}
+
+pub struct SoupBinTcpClient<T> {
+    stream: Box<dyn Transport>,
+    parser: ParserFn<T>,
+    packet_sender: Sender<PacketData<T>>,
+    delivery_policy: DeliveryPolicy<T>,
+    empty_payload_policy: EmptyPayloadPolicy,
+    read_buf: ReadBuffer,
+    buffer_policy: BufferPolicy,
+    length_prefix: LengthPrefix,
+    max_packet_len: usize,
+    current_sequence: Sequence,
+    last_server_activity: std::time::Instant,
+    last_heartbeat_sent: std::time::Instant,
+    last_known_timestamp: UnixNanoseconds,
+    current_trace: Option<data_types::tracing::TraceData>,
+    feed_type: DataFeedType,
+    config: ReconnectConfig,
+    reconnect_attempts: u32,
+    backoff: Box<dyn BackoffStrategy>,
+    event_sender: Option<Sender<(DataFeedType, ConnectionEvent)>>,
+    just_sent_login: bool,
+    logged_in: bool,
+    heartbeat_interval_secs: u64,
+    pending_server_heartbeat: bool,
+    stats: ClientStats,
+    raw_tap: Option<RawTap>,
+    sequence_extractor: Option<SequenceExtractor>,
+    connector: TransportConnector,
+    write_buf: Vec<u8>,
+    auto_flush_interval: Option<std::time::Duration>,
+    last_flush: std::time::Instant,
+}
+
+impl<T> fmt::Debug for SoupBinTcpClient<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SoupBinTcpClient")
+            .field("packet_sender", &self.packet_sender)
+            .field("current_sequence", &self.current_sequence)
+            .field("heartbeat_interval_secs", &self.heartbeat_interval_secs)
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ReconnectConfig {
+    host: String,
+    port: u16,
+    username: String,
+    password: String,
+    session: String,
+    max_attempts: u32,
+}
+
+impl<T> SoupBinTcpClient<T> {
+    pub async fn connect(
+        config: SoupBinTcpConfig,
+        sender: Sender<PacketData<T>>,
+        parser: ParserFn<T>,
+    ) -> io::Result<Self> {
+        Self::connect_with_retry_config(
+            config,
+            sender,
+            parser,
+            None,
+            DEFAULT_MAX_RECONNECT_ATTEMPTS,
+            DEFAULT_RECONNECT_DELAY_MS,
+        )
+        .await
+    }
+
+    /// Connect with optional event channel for feed status notifications
+    pub async fn connect_with_events(
+        config: SoupBinTcpConfig,
+        sender: Sender<PacketData<T>>,
+        parser: ParserFn<T>,
+        event_sender: Sender<(DataFeedType, ConnectionEvent)>,
+    ) -> io::Result<Self> {
+        Self::connect_with_retry_config(
+            config,
+            sender,
+            parser,
+            Some(event_sender),
+            DEFAULT_MAX_RECONNECT_ATTEMPTS,
+            DEFAULT_RECONNECT_DELAY_MS,
+        )
+        .await
+    }
+
+    /// Connect and wait up to `login_timeout` for the server to accept the login.
+    ///
+    /// A Login Rejected answer fails with [`ProtocolError::LoginRejected`] (kind
+    /// `PermissionDenied`), no answer in time with `TimedOut`. Sequenced packets that arrive
+    /// right behind the Login Accepted are already forwarded to `sender`.
+    pub async fn connect_and_login(
+        config: SoupBinTcpConfig,
+        sender: Sender<PacketData<T>>,
+        parser: ParserFn<T>,
+        login_timeout: std::time::Duration,
+    ) -> io::Result<Self> {
+        let mut client = Self::connect(config, sender, parser).await?;
+        client.await_login(login_timeout).await?;
+        Ok(client)
+    }
+
+    async fn await_login(&mut self, login_timeout: std::time::Duration) -> io::Result<()> {
+        let deadline = std::time::Instant::now() + login_timeout;
+
+        loop {
+            // a Login Rejected surfaces here as an error
+            self.drain_buffered().await?;
+            if self.logged_in {
+                return Ok(());
+            }
+
+            if !self.read_into_buffer(Some(deadline)).await? {
+                return Err(if std::time::Instant::now() >= deadline {
+                    io::Error::new(
+                        io::ErrorKind::TimedOut,
+                        format!("no login response within {login_timeout:?}"),
+                    )
+                } else {
+                    io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "connection closed before login response",
+                    )
+                });
+            }
+        }
+    }
+
+    async fn connect_with_retry_config(
+        config: SoupBinTcpConfig,
+        sender: Sender<PacketData<T>>,
+        parser: ParserFn<T>,
+        event_sender: Option<Sender<(DataFeedType, ConnectionEvent)>>,
+        max_reconnect_attempts: u32,
+        initial_delay_ms: u64,
+    ) -> io::Result<Self> {
+        let addr = format!("{}:{}", config.host, config.port);
+        let stream = NetworkTransport::connect(&addr).await?;
+
+        Self::from_transport(
+            config,
+            Box::new(stream),
+            sender,
+            parser,
+            event_sender,
+            max_reconnect_attempts,
+            initial_delay_ms,
+        )
+        .await
+    }
+
+    /// Log in over an already established transport, e.g. a
+    /// [`MockTransport`](crate::net::mock_transport::MockTransport) in tests.
+    ///
+    /// Reconnects and [`reset_for_new_session`](Self::reset_for_new_session) still dial
+    /// `config.host:config.port` over the network transport unless
+    /// [`set_transport_connector`](Self::set_transport_connector) replaces it.
+    pub async fn connect_with_transport(
+        config: SoupBinTcpConfig,
+        transport: Box<dyn Transport>,
+        sender: Sender<PacketData<T>>,
+        parser: ParserFn<T>,
+    ) -> io::Result<Self> {
+        Self::from_transport(
+            config,
+            transport,
+            sender,
+            parser,
+            None,
+            DEFAULT_MAX_RECONNECT_ATTEMPTS,
+            DEFAULT_RECONNECT_DELAY_MS,
+        )
+        .await
+    }
+
+    async fn from_transport(
+        config: SoupBinTcpConfig,
+        stream: Box<dyn Transport>,
+        sender: Sender<PacketData<T>>,
+        parser: ParserFn<T>,
+        event_sender: Option<Sender<(DataFeedType, ConnectionEvent)>>,
+        max_reconnect_attempts: u32,
+        initial_delay_ms: u64,
+    ) -> io::Result<Self> {
+        let reconnect_config = ReconnectConfig {
+            host: config.host.to_string(),
+            port: config.port,
+            username: config.username.to_string(),
+            password: config.password.to_string(),
+            session: config.start_session.to_string(),
+            max_attempts: max_reconnect_attempts,
+        };
+        let backoff = ExponentialBackoff::new(
+            std::time::Duration::from_millis(initial_delay_ms),
+            std::time::Duration::from_millis(MAX_RECONNECT_DELAY_MS),
+            2.0,
+            RECONNECT_JITTER,
+        );
+
+        let feed_type = config.feed_type;
+
+        let buffer_policy = config.buffer_policy;
+        let read_buf = ReadBuffer::with_capacity(buffer_policy.initial);
+
+        let now = std::time::Instant::now();
+
+        let mut client = Self {
+            stream,
+            parser,
+            read_buf,
+            buffer_policy,
+            length_prefix: config.length_prefix,
+            max_packet_len: config.max_packet_len,
+            current_sequence: config.start_sequence.prev(),
+            last_server_activity: now,
+            last_heartbeat_sent: now,
+            last_known_timestamp: UnixNanoseconds(0),
+            current_trace: None,
+            feed_type,
+            config: reconnect_config,
+            reconnect_attempts: 0,
+            backoff: Box::new(backoff),
+            packet_sender: sender,
+            delivery_policy: DeliveryPolicy::default(),
+            empty_payload_policy: EmptyPayloadPolicy::default(),
+            event_sender,
+            just_sent_login: false,
+            logged_in: false,
+            heartbeat_interval_secs: DEFAULT_HEARTBEAT_INTERVAL_SECS,
+            pending_server_heartbeat: false,
+            stats: ClientStats::default(),
+            raw_tap: None,
+            sequence_extractor: None,
+            connector: network_connector(),
+            write_buf: Vec::new(),
+            auto_flush_interval: None,
+            last_flush: now,
+        };
+
+        client
+            .send_login(
+                &config.username,
+                &config.password,
+                &config.start_session,
+                config.start_sequence,
+            )
+            .await?;
+
+        client.send_event(ConnectionEvent::Connected).await;
+
+        Ok(client)
+    }
+
+    pub fn current_sequence(&self) -> Sequence {
+        self.current_sequence
+    }
+
+    pub fn feed_type(&self) -> &DataFeedType {
+        &self.feed_type
+    }
+
+    /// Read buffer reallocation counters for this client.
+    pub fn stats(&self) -> ClientStats {
+        self.stats
+    }
+
+    /// Buffer reallocation counters reported by the underlying transport.
+    pub fn transport_stats(&self) -> TransportStats {
+        self.stream.stats()
+    }
+
+    /// Replace the reconnect backoff; the default is an [`ExponentialBackoff`] starting at the
+    /// configured initial delay, doubling per attempt and shortened by up to
+    /// [`RECONNECT_JITTER`].
+    pub fn set_backoff_strategy(&mut self, backoff: Box<dyn BackoffStrategy>) {
+        self.backoff = backoff;
+    }
+
+    /// Choose what happens to parsed packets when the packet channel is full; the default is
+    /// [`DeliveryPolicy::Block`].
+    pub fn set_delivery_policy(&mut self, policy: DeliveryPolicy<T>) {
+        self.delivery_policy = policy;
+    }
+
+    /// Choose how Sequenced Data packets without payload are handled; the default is
+    /// [`EmptyPayloadPolicy::Skip`].
+    pub fn set_empty_payload_policy(&mut self, policy: EmptyPayloadPolicy) {
+        self.empty_payload_policy = policy;
+    }
+
+    /// Replace how reconnects and [`reset_for_new_session`](Self::reset_for_new_session) open
+    /// a transport; the default dials [`NetworkTransport`]. Tests use it to hand over another
+    /// [`MockTransport`](crate::net::mock_transport::MockTransport).
+    pub fn set_transport_connector(&mut self, connector: TransportConnector) {
+        self.connector = connector;
+    }
+
+    /// Install (or remove) a tap called with the raw bytes of each read, e.g. a
+    /// [`RawRecorder`](super::recorder::RawRecorder) capturing the session for replay.
+    ///
+    /// The tap runs inline on the read path, so it must not block.
+    pub fn set_raw_tap(&mut self, tap: Option<RawTap>) {
+        self.raw_tap = tap;
+    }
+
+    /// Enable (or disable) gap detection: each payload's own sequence, as read by
+    /// `extractor`, is compared with the expected next sequence. A later one emits
+    /// [`ConnectionEvent::SequenceGap`] before the client adopts the payload's sequence; an
+    /// earlier one is a duplicate and is dropped, except a restart at 1, which emits
+    /// [`ConnectionEvent::SequenceReset`] and resyncs.
+    pub fn set_sequence_extractor(&mut self, extractor: Option<SequenceExtractor>) {
+        self.sequence_extractor = extractor;
+    }
+
+    /// Queue already framed bytes (e.g. [`ClientPacket::UnsequencedData`]) to be written in
+    /// one go by [`flush_now`](Self::flush_now), or once the auto-flush interval has passed.
+    pub async fn write_buffered(&mut self, bytes: &[u8]) -> io::Result<()> {
+        self.write_buf.extend_from_slice(bytes);
+        self.flush_if_due().await
+    }
+
+    /// Write and flush everything queued by [`write_buffered`](Self::write_buffered).
+    pub async fn flush_now(&mut self) -> io::Result<()> {
+        if !self.write_buf.is_empty() {
+            self.stream.write_all(&self.write_buf).await?;
+            self.stream.flush().await?;
+            self.write_buf.clear();
+        }
+        self.last_flush = std::time::Instant::now();
+        Ok(())
+    }
+
+    /// Flush buffered writes once `interval` has passed since the last flush, checked on each
+    /// [`write_buffered`](Self::write_buffered) and pump iteration. `None` (the default)
+    /// leaves flushing to [`flush_now`](Self::flush_now).
+    pub fn set_auto_flush_interval(&mut self, interval: Option<std::time::Duration>) {
+        self.auto_flush_interval = interval;
+    }
+
+    async fn flush_if_due(&mut self) -> io::Result<()> {
+        match self.auto_flush_interval {
+            Some(interval) if self.last_flush.elapsed() >= interval => self.flush_now().await,
+            _ => Ok(()),
+        }
+    }
+
+    pub async fn pump_packets(&mut self) -> io::Result<()> {
+        loop {
+            // non-blocking heartbeat sending
+            self.try_send_heartbeats();
+            self.flush_if_due().await?;
+
+            // batch process all buffered packets
+            self.drain_buffered().await?;
+
+            self.manage_read_buffer();
+
+            if !self.read_into_buffer(None).await? {
+                // no more data available right now
+                return Ok(());
+            }
+        }
+    }
+
+    /// Like [`pump_packets`](Self::pump_packets), but returns `Ok(())` once `deadline` has
+    /// passed so the caller can service other feeds.
+    ///
+    /// Complete packets already in the read buffer are always processed before returning; a
+    /// read still waiting at the deadline is abandoned without losing data.
+    pub async fn pump_until(&mut self, deadline: std::time::Instant) -> io::Result<()> {
+        loop {
+            self.try_send_heartbeats();
+            self.flush_if_due().await?;
+
+            self.drain_buffered().await?;
+
+            if std::time::Instant::now() >= deadline {
+                return Ok(());
+            }
+
+            self.manage_read_buffer();
+
+            if !self.read_into_buffer(Some(deadline)).await? {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Read once from the transport into the read buffer, reconnecting on recoverable errors.
+    ///
+    /// Returns `false` if the transport had no data, or `deadline` passed before it did.
+    async fn read_into_buffer(&mut self, deadline: Option<std::time::Instant>) -> io::Result<bool> {
+        // Create a span for this TCP read operation
+        let read_span = tracing::trace_span!(
+            "tcp_read",
+            feed_type = ?self.feed_type,
+            seq = self.current_sequence.next().0
+        );
+        let _guard = read_span.enter();
+
+        let read = read_bytes_fast(self.stream.as_mut(), &mut self.read_buf);
+        let result = match deadline {
+            // transport reads are cancel safe, nothing is lost if the deadline wins
+            Some(deadline) => match tokio::time::timeout_at(deadline.into(), read).await {
+                Ok(result) => result,
+                Err(_elapsed) => return Ok(false),
+            },
+            None => read.await,
+        };
+
+        match result {
+            Ok((0, _)) => Ok(false),
+            Ok((n, trace_data)) => {
+                if let Some(tap) = &mut self.raw_tap {
+                    // the transport appends what it read to the end of the buffer
+                    let start = self.read_buf.len().saturating_sub(n);
+                    tap(&self.read_buf[start..]);
+                }
+                self.current_trace = Some(trace_data);
+                // process multiple complete packets in the next loop iteration
+                Ok(true)
+            }
+            Err(e) if self.is_reconnectable_error(&e) => {
+                self.try_reconnect().await?;
+                Ok(true)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Shrink the read buffer if it is too large and mostly empty, then make sure there is
+    /// enough spare capacity for the next read, both as set by the [`BufferPolicy`].
+    fn manage_read_buffer(&mut self) {
+        let policy = self.buffer_policy;
+
+        // if buffer > policy.max and is mostly empty, shrink it
+        if self.read_buf.capacity() > policy.max && self.read_buf.len() < policy.shrink {
+            // For BytesMut, create a new buffer with appropriate capacity
+            let new_capacity =
+                std::cmp::max(policy.initial, self.read_buf.len() + policy.min_spare);
+            let mut new_buf = ReadBuffer::with_capacity(new_capacity);
+            new_buf.extend_from_slice(&self.read_buf[..]);
+            self.read_buf = new_buf;
+            self.stats.buffer_shrink_count += 1;
+        }
+
+        // reserve space if needed
+        if self.read_buf.capacity() - self.read_buf.len() < policy.min_spare {
+            let capacity = self.read_buf.capacity();
+            self.read_buf.reserve(policy.min_spare);
+            if self.read_buf.capacity() > capacity {
+                self.stats.buffer_grow_count += 1;
+            }
+        }
+    }
+
+    /// Parse and enqueue every complete frame already sitting in the read buffer
+    /// without issuing new reads.
+    ///
+    /// Returns the number of frames flushed. Trailing partial frames stay buffered.
+    pub async fn drain_buffered(&mut self) -> io::Result<usize> {
+        let mut flushed = 0;
+
+        while let Some((packet_type, packet_bytes)) = self.try_parse_packet()? {
+            self.process_packet(packet_type, packet_bytes).await?;
+            flushed += 1;
+        }
+
+        Ok(flushed)
+    }
+
+    /// Flush buffered packets and log out of the session.
+    ///
+    /// Returns the number of frames flushed before the logout request was sent.
+    pub async fn close(mut self) -> io::Result<usize> {
+        let flushed = self.drain_buffered().await?;
+        self.send_packet(ClientPacket::LogoutRequest).await?;
+        Ok(flushed)
+    }
+
+    /// Reconnect and log in to a new session, e.g. the next trading day after
+    /// [`ServerPacket::EndOfSession`].
+    ///
+    /// Keeps the packet channel, event channel and parser; resets sequence tracking, reconnect
+    /// attempts and buffer counters, then emits [`ConnectionEvent::Connected`].
+    pub async fn reset_for_new_session(
+        &mut self,
+        session: &str,
+        start_sequence: impl Into<Sequence>,
+    ) -> io::Result<()> {
+        let start_sequence = start_sequence.into();
+        self.stream = self.dial().await?;
+
+        let now = std::time::Instant::now();
+        self.read_buf.clear();
+        // buffered writes belonged to the old session
+        self.write_buf.clear();
+        self.current_sequence = start_sequence.prev();
+        self.last_server_activity = now;
+        self.last_heartbeat_sent = now;
+        self.current_trace = None;
+        self.reconnect_attempts = 0;
+        self.pending_server_heartbeat = false;
+        self.stats = ClientStats::default();
+        self.config.session = session.to_string();
+
+        let username = self.config.username.clone();
+        let password = self.config.password.clone();
+        self.send_login(&username, &password, session, start_sequence)
+            .await?;
+
+        self.send_event(ConnectionEvent::Connected).await;
+
+        Ok(())
+    }
+
+    async fn send_login(
+        &mut self,
+        username: &str,
+        password: &str,
+        session_id: &str,
+        sequence_number: Sequence,
+    ) -> io::Result<()> {
+        let sequence_number = sequence_number.as_wire_string();
+        let packet = ClientPacket::login_request(username, password, session_id, &sequence_number)?;
+
+        let result = self.send_packet(packet).await;
+
+        // immediate auth failure detection
+        self.just_sent_login = true;
+        self.logged_in = false;
+
+        result
+    }
+
+    /// Non-blocking feed connection event notification
+    async fn send_event(&self, event: ConnectionEvent) {
+        if let Some(ref tx) = self.event_sender {
+            let _ = tx.send((self.feed_type, event));
+        }
+    }
+
+    /// Non-blocking attempt to send pending heartbeats using try_write
+    /// to avoid blocking data processing if socket buffer is full
+    #[inline]
+    fn try_send_heartbeats(&mut self) {
+        // Length=1, Type='R'
+        let packet = b"\x00\x01R";
+
+        // check if need to send heartbeat
+        let need_periodic =
+            self.last_heartbeat_sent.elapsed().as_secs() >= self.heartbeat_interval_secs;
+        let need_response = self.pending_server_heartbeat;
+
+        if need_periodic || need_response {
+            match self.stream.try_write(packet) {
+                Ok(n) if n == packet.len() => {
+                    self.last_heartbeat_sent = std::time::Instant::now();
+                    self.pending_server_heartbeat = false;
+                    debug!("Sent heartbeat (non-blocking)");
+                }
+                Ok(_) => {
+                    // partial write - will retry next iteration
+                }
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                    // socket full, try next iteration
+                    // data processing continues without blocking
+                }
+                Err(_) => {
+                    // other errors - ignore for now, will be caught on next read
+                }
+            }
+        }
+    }
+
+    #[inline]
+    /// Write `packet` and flush immediately, after anything still buffered.
+    async fn send_packet(&mut self, packet: ClientPacket<'_>) -> io::Result<()> {
+        self.flush_now().await?;
+        let bytes = packet.to_bytes();
+        self.stream.write_all(&bytes).await?;
+        self.stream.flush().await?;
+        self.last_heartbeat_sent = std::time::Instant::now();
+        Ok(())
+    }
+
+    /// Parse a packet from the read buffer.
+    ///
+    /// Returns the packet type and the complete packet bytes (including header). Zero-length
+    /// frames carry no packet type and are skipped.
+    #[inline]
+    fn try_parse_packet(&mut self) -> io::Result<Option<(u8, Bytes)>> {
+        loop {
+            // reject a bogus length up front rather than buffering toward it
+            self.length_prefix
+                .check_declared_len(&self.read_buf, self.max_packet_len)?;
+            let Some(total_len) = self.length_prefix.complete_frame_len(&self.read_buf) else {
+                return Ok(None);
+            };
+
+            // hand out the parsed prefix itself: shares the read buffer's allocation, no copy
+            let packet_bytes = self.read_buf.split_to(total_len).freeze();
+
+            match packet_bytes.get(self.length_prefix.size()) {
+                Some(&packet_type) => return Ok(Some((packet_type, packet_bytes))),
+                None => warn!("Skipping zero-length {:?} packet", self.feed_type),
+            }
+        }
+    }
+
+    /// Parse up to `max` complete packets from the read buffer in one pass, appending
+    /// `(packet_type, packet_bytes)` to `out`.
+    ///
+    /// Framing matches [`try_parse_packet`](Self::try_parse_packet), but the buffer is only
+    /// advanced once at the end. Returns the number of packets appended, or
+    /// [`ProtocolError::PacketTooLarge`] without consuming anything.
+    pub fn try_parse_packets_into(
+        &mut self,
+        out: &mut Vec<(u8, Bytes)>,
+        max: usize,
+    ) -> io::Result<usize> {
+        let buf = &self.read_buf[..];
+        let mut cursor = 0;
+        let mut parsed = 0;
+        let mut frames = Vec::new();
+
+        let prefix_len = self.length_prefix.size();
+
+        while parsed < max {
+            let remaining = &buf[cursor..];
+            self.length_prefix
+                .check_declared_len(remaining, self.max_packet_len)?;
+            let Some(total_len) = self.length_prefix.complete_frame_len(remaining) else {
+                break;
+            };
+
+            // zero-length frames carry no packet type and are skipped
+            if total_len > prefix_len {
+                frames.push((remaining[prefix_len], cursor..cursor + total_len));
+                parsed += 1;
+            }
+
+            cursor += total_len;
+        }
+
+        // remove all parsed data from read buffer at once; packets are zero-copy slices of it
+        let consumed = self.read_buf.split_to(cursor).freeze();
+        out.extend(
+            frames
+                .into_iter()
+                .map(|(packet_type, range)| (packet_type, consumed.slice(range))),
+        );
+
+        Ok(parsed)
+    }
+
+    #[inline]
+    #[tracing::instrument(
+        skip(self, packet_bytes),
+        fields(
+            seq = self.current_sequence.next().0,
+            feed_type = ?self.feed_type,
+            packet_type = %format!("{}", packet_type as char)
+        ),
+        name = "parse_packet"
+    )]
+    async fn process_packet(&mut self, packet_type: u8, packet_bytes: Bytes) -> io::Result<()> {
+        self.last_server_activity = std::time::Instant::now();
+        self.just_sent_login = false;
+
+        if packet_type == b'S' {
+            self.current_sequence = self.current_sequence.next();
+
+            let payload = &packet_bytes[self.length_prefix.header_len()..];
+            if payload.is_empty() {
+                return match self.empty_payload_policy {
+                    EmptyPayloadPolicy::Skip => Ok(()),
+                    EmptyPayloadPolicy::Error => Err(ProtocolError::EmptySequencedData {
+                        sequence: self.current_sequence.0,
+                    }
+                    .into()),
+                };
+            }
+
+            if let Some(found) = self.sequence_extractor.as_ref().and_then(|f| f(payload)) {
+                let expected = self.current_sequence;
+                match GapDetector::with_last_seen(expected.prev().0).observe(found) {
+                    SequenceCheck::InOrder => {}
+                    SequenceCheck::Gap { .. } => {
+                        warn!(
+                            "Sequence gap on {:?} feed: expected {}, payload carries {}",
+                            self.feed_type, expected, found
+                        );
+                        self.current_sequence = Sequence(found);
+                        self.stats.sequence_gaps += 1;
+                        self.send_event(ConnectionEvent::SequenceGap {
+                            expected,
+                            found: Sequence(found),
+                        })
+                        .await;
+                    }
+                    SequenceCheck::Reset => {
+                        warn!(
+                            "Sequence reset on {:?} feed: expected {}, payload carries {}",
+                            self.feed_type, expected, found
+                        );
+                        self.current_sequence = Sequence(found);
+                        self.stats.sequence_resets += 1;
+                        self.send_event(ConnectionEvent::SequenceReset {
+                            expected,
+                            found: Sequence(found),
+                        })
+                        .await;
+                    }
+                    SequenceCheck::Duplicate => {
+                        debug!(
+                            "Dropping duplicate on {:?} feed: expected {}, payload carries {}",
+                            self.feed_type, expected, found
+                        );
+                        self.current_sequence = expected.prev();
+                        self.stats.duplicate_packets += 1;
+                        return Ok(());
+                    }
+                }
+            }
+
+            let context = PacketContext {
+                feed_type: Some(&self.feed_type),
+                last_timestamp: Some(self.last_known_timestamp),
+                sequence: Some(self.current_sequence.0),
+            };
+
+            let parsed = self
+                .parser
+                .parse(payload, context)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+
+            // // update last known timestamp
+            // if parsed.msg_type == MessageType::Seconds {
+            //     if let itch_message::messages::body::MessageBody::Seconds(ref seconds_msg) =
+            //         parsed.body
+            //     {
+            //         self.last_known_timestamp = seconds_msg.timestamp;
+            //     }
+            // }
+
+            // Use the trace data captured during TCP read, or create new if missing
+            let trace_data = self
+                .current_trace
+                .clone()
+                .unwrap_or_else(data_types::tracing::TraceData::with_current_context);
+
+            return self.deliver((
+                self.current_sequence.0,
+                packet_bytes,
+                parsed,
+                Some(trace_data),
+            ));
+        }
+
+        let payload = &packet_bytes[self.length_prefix.header_len()..];
+        let packet = ServerPacket::parse(packet_type, payload);
+
+        match packet {
+            ServerPacket::LoginAccepted {
+                session,
+                sequence_number,
+            } => {
+                if let Ok(seq) = sequence_number.parse::<Sequence>() {
+                    info!(
+                        "Login accepted: session='{}', server will start from sequence {}",
+                        session, seq
+                    );
+                    // the server reports the next sequence it will send
+                    self.current_sequence = seq.prev();
+                }
+                self.reconnect_attempts = 0;
+                self.logged_in = true;
+
+                self.send_event(ConnectionEvent::LoggedIn {
+                    session: session.to_string(),
+                    start_sequence: self.current_sequence.next(),
+                })
+                .await;
+            }
+            ServerPacket::LoginRejected { reason } => {
+                return Err(ProtocolError::LoginRejected { reason }.into());
+            }
+            ServerPacket::ServerHeartbeat => {
+                debug!("Received server heartbeat");
+                self.pending_server_heartbeat = true;
+            }
+            ServerPacket::EndOfSession => {
+                return Err(io::Error::new(
+                    io::ErrorKind::ConnectionAborted,
+                    "Server ended session",
+                ));
+            }
+            ServerPacket::Debug(_) => {
+                // ignored
+            }
+            ServerPacket::Unknown { packet_type, .. } => {
+                warn!(
+                    "Ignoring unknown {:?} packet type 0x{:02x}, payload: {}",
+                    self.feed_type,
+                    packet_type,
+                    packet.unknown_payload_hex().unwrap_or_default()
+                );
+            }
+            ServerPacket::SequencedData(_) => unreachable!(),
+        }
+
+        Ok(())
+    }
+
+    /// Send a parsed packet to the consumer, handling a full channel per the [`DeliveryPolicy`].
+    #[inline]
+    fn deliver(&mut self, packet: PacketData<T>) -> io::Result<()> {
+        let disconnected = || io::Error::new(io::ErrorKind::BrokenPipe, "Disconnected");
+
+        let packet = match self.packet_sender.try_send(packet) {
+            Ok(()) => return Ok(()),
+            Err(TrySendError::Disconnected(_)) => return Err(disconnected()),
+            Err(TrySendError::Full(packet)) => packet,
+        };
+
+        match &self.delivery_policy {
+            // apply backpressure by blocking
+            DeliveryPolicy::Block => self.packet_sender.send(packet).map_err(|_| disconnected()),
+            DeliveryPolicy::DropNewest => {
+                self.stats.dropped_packets += 1;
+                Ok(())
+            }
+            DeliveryPolicy::DropOldest(receiver) => {
+                let mut packet = packet;
+                loop {
+                    let evicted = receiver.try_recv().is_ok();
+                    if evicted {
+                        self.stats.dropped_packets += 1;
+                    }
+                    // the consumer may have made room itself, or refilled it in between
+                    match self.packet_sender.try_send(packet) {
+                        Ok(()) => return Ok(()),
+                        // nothing left to evict, e.g. a zero-capacity channel: drop this one
+                        Err(TrySendError::Full(_)) if !evicted => {
+                            self.stats.dropped_packets += 1;
+                            return Ok(());
+                        }
+                        Err(TrySendError::Full(unsent)) => packet = unsent,
+                        Err(TrySendError::Disconnected(_)) => return Err(disconnected()),
+                    }
+                }
+            }
+        }
+    }
+
+    async fn try_reconnect(&mut self) -> io::Result<()> {
+        let attempt = self.reconnect_attempts.saturating_add(1);
+        let delay = self.backoff.next_delay(attempt);
+
+        self.send_event(ConnectionEvent::Reconnecting {
+            attempt,
+            delay_ms: u64::try_from(delay.as_millis()).unwrap_or(u64::MAX),
+        })
+        .await;
+
+        if self.reconnect_attempts >= self.config.max_attempts {
+            let reason = format!(
+                "Max reconnection attempts ({}) exceeded",
+                self.config.max_attempts
+            );
+            self.send_event(ConnectionEvent::Disconnected {
+                reason: Some(reason.clone()),
+            })
+            .await;
+            return Err(io::Error::new(io::ErrorKind::ConnectionAborted, reason));
+        }
+
+        self.reconnect_attempts += 1;
+
+        tokio::time::sleep(delay).await;
+
+        match self.dial().await {
+            Ok(new_stream) => {
+                self.stream = new_stream;
+                self.read_buf.clear();
+                self.write_buf.clear();
+                self.pending_server_heartbeat = false;
+
+                let next_sequence = self.current_sequence.next();
+                info!(
+                    "Reconnecting: requesting session '{}' starting from sequence {}",
+                    self.config.session, next_sequence
+                );
+                let username = self.config.username.clone();
+                let password = self.config.password.clone();
+                let session = self.config.session.clone();
+                self.send_login(&username, &password, &session, next_sequence)
+                    .await?;
+
+                self.last_server_activity = std::time::Instant::now();
+
+                self.send_event(ConnectionEvent::Reconnected).await;
+
+                Ok(())
+            }
+            Err(e) => {
+                error!(
+                    "Reconnection attempt {} failed for {:?} feed: {:?}",
+                    self.reconnect_attempts, self.feed_type, e
+                );
+                Err(e)
+            }
+        }
+    }
+
+    /// Open a transport to the configured address through the installed connector.
+    async fn dial(&mut self) -> io::Result<Box<dyn Transport>> {
+        let addr = format!("{}:{}", self.config.host, self.config.port);
+        (self.connector)(addr).await
+    }
+
+    fn is_reconnectable_error(&self, e: &io::Error) -> bool {
+        matches!(
+            e.kind(),
+            io::ErrorKind::ConnectionReset
+                | io::ErrorKind::ConnectionAborted
+                | io::ErrorKind::BrokenPipe
+                | io::ErrorKind::NotConnected
+                | io::ErrorKind::UnexpectedEof
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soupbintcp::{backoff::ConstantBackoff, soupbintcp_packet::LoginRejectReason};
+    use crossbeam_channel::{Receiver, unbounded};
+    use tokio::net::{TcpListener, TcpStream};
+
+    /// Reconnect backoff without jitter, so tests can assert exact delays.
+    fn exact_backoff(initial_ms: u64) -> Box<dyn BackoffStrategy> {
+        Box::new(ExponentialBackoff::new(
+            std::time::Duration::from_millis(initial_ms),
+            std::time::Duration::from_millis(MAX_RECONNECT_DELAY_MS),
+            2.0,
+            0.0,
+        ))
+    }
+
+    struct RawParser;
+
+    impl PacketParser<Vec<u8>> for RawParser {
+        fn parse(&self, bytes: &[u8], _context: PacketContext) -> io::Result<Vec<u8>> {
+            Ok(bytes.to_vec())
+        }
+    }
+
+    async fn connected_client() -> (
+        SoupBinTcpClient<Vec<u8>>,
+        Receiver<PacketData<Vec<u8>>>,
+        TcpStream,
+    ) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        connect_client(&listener).await
+    }
+
+    fn test_config(listener: &TcpListener) -> SoupBinTcpConfig {
+        SoupBinTcpConfig {
+            host: "127.0.0.1".to_string(),
+            port: listener.local_addr().unwrap().port(),
+            username: "user".to_string(),
+            password: "pass".to_string(),
+            feed_type: DataFeedType::Itch,
+            start_sequence: Sequence::MIN,
+            start_session: "".to_string(),
+            buffer_policy: BufferPolicy::default(),
+            length_prefix: LengthPrefix::default(),
+            max_packet_len: DEFAULT_MAX_PACKET_LEN,
+        }
+    }
+
+    async fn connect_client(
+        listener: &TcpListener,
+    ) -> (
+        SoupBinTcpClient<Vec<u8>>,
+        Receiver<PacketData<Vec<u8>>>,
+        TcpStream,
+    ) {
+        connect_client_with_config(listener, test_config(listener)).await
+    }
+
+    async fn connect_client_with_config(
+        listener: &TcpListener,
+        config: SoupBinTcpConfig,
+    ) -> (
+        SoupBinTcpClient<Vec<u8>>,
+        Receiver<PacketData<Vec<u8>>>,
+        TcpStream,
+    ) {
+        let (tx, rx) = unbounded();
+
+        let (client, accepted) = tokio::join!(
+            SoupBinTcpClient::connect(config, tx, Box::new(RawParser)),
+            listener.accept()
+        );
+
+        (client.unwrap(), rx, accepted.unwrap().0)
+    }
+
+    fn sequenced_frame(payload: &[u8]) -> Vec<u8> {
+        let mut frame = ((payload.len() + 1) as u16).to_be_bytes().to_vec();
+        frame.push(b'S');
+        frame.extend_from_slice(payload);
+        frame
+    }
+
+    #[tokio::test]
+    async fn test_reset_for_new_session_logs_in_again() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let (mut client, _rx, mut server) = connect_client(&listener).await;
+
+        let mut login = [0u8; 49];
+        server.read_exact(&mut login).await.unwrap();
+        assert_eq!(&login[19..29], b"          ");
+
+        server.write_all(&[0x00, 0x01, b'Z']).await.unwrap();
+        let err = client.pump_packets().await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::ConnectionAborted);
+
+        let (reset, accepted) = tokio::join!(
+            client.reset_for_new_session("DAY2", 1u64),
+            listener.accept()
+        );
+        reset.unwrap();
+        let mut server = accepted.unwrap().0;
+
+        server.read_exact(&mut login).await.unwrap();
+        assert_eq!(login[2], b'L');
+        assert_eq!(&login[19..29], b"DAY2      ");
+        assert_eq!(&login[29..49], b"                   1");
+        assert_eq!(client.current_sequence(), Sequence(0));
+        assert_eq!(client.stats(), ClientStats::default());
+    }
+
+    #[cfg(all(feature = "mio_transport", not(feature = "tokio_transport")))]
+    #[tokio::test]
+    async fn test_peer_close_triggers_reconnect() {
+        use tokio::io::AsyncReadExt;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let config = test_config(&listener);
+        let (tx, _rx) = unbounded();
+        let (event_tx, event_rx) = unbounded();
+
+        // accept, read the login, then hang up; twice, so the single allowed reconnect is used up
+        let server = tokio::spawn(async move {
+            for _ in 0..2 {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut login = [0u8; 49];
+                socket.read_exact(&mut login).await.unwrap();
+                assert_eq!(login[2], b'L');
+            }
+        });
+
+        let mut client = SoupBinTcpClient::connect_with_retry_config(
+            config,
+            tx,
+            Box::new(RawParser),
+            Some(event_tx),
+            1,
+            1,
+        )
+        .await
+        .unwrap();
+        client.set_backoff_strategy(exact_backoff(1));
+
+        let err = client.pump_packets().await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::ConnectionAborted);
+        server.await.unwrap();
+
+        let events: Vec<_> = event_rx.try_iter().map(|(_, event)| event).collect();
+        assert_eq!(
+            events,
+            [
+                ConnectionEvent::Connected,
+                ConnectionEvent::Reconnecting {
+                    attempt: 1,
+                    delay_ms: 1
+                },
+                ConnectionEvent::Reconnected,
+                ConnectionEvent::Reconnecting {
+                    attempt: 2,
+                    delay_ms: 2
+                },
+                ConnectionEvent::Disconnected {
+                    reason: Some("Max reconnection attempts (1) exceeded".to_string())
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_config_builder_builds_with_defaults() {
+        let config = SoupBinTcpConfig::builder()
+            .host("127.0.0.1")
+            .port(9000)
+            .username("user01")
+            .password("secret")
+            .feed_type(DataFeedType::Itch)
+            .start_session("SESS1")
+            .build()
+            .unwrap();
+
+        assert_eq!(config.host, "127.0.0.1");
+        assert_eq!(config.port, 9000);
+        assert_eq!(config.username, "user01");
+        assert_eq!(config.start_sequence, Sequence::MIN);
+        assert_eq!(config.start_session, "SESS1");
+        assert_eq!(config.buffer_policy, BufferPolicy::default());
+        assert_eq!(config.length_prefix, LengthPrefix::U16);
+        assert_eq!(config.max_packet_len, DEFAULT_MAX_PACKET_LEN);
+    }
+
+    #[test]
+    fn test_config_builder_rejects_long_username_and_missing_fields() {
+        let builder = SoupBinTcpConfig::builder()
+            .host("127.0.0.1")
+            .port(9000)
+            .password("secret")
+            .feed_type(DataFeedType::Mdf);
+
+        let err = builder.clone().username("toolong").build().unwrap_err();
+        assert_eq!(
+            err,
+            ProtocolError::FieldTooLong {
+                field: "username",
+                len: 7,
+                width: ClientPacket::USERNAME_WIDTH,
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "username is 7 bytes, exceeds field width of 6"
+        );
+
+        let err = builder
+            .clone()
+            .username("user")
+            .start_session("SESSION_TOO_LONG")
+            .build()
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ProtocolError::FieldTooLong {
+                field: "session_id",
+                ..
+            }
+        ));
+
+        assert_eq!(
+            builder.build().unwrap_err(),
+            ProtocolError::MissingField { field: "username" }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_logged_in_event_follows_connected() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let config = test_config(&listener);
+        let (tx, _rx) = unbounded();
+        let (event_tx, event_rx) = unbounded();
+
+        let (client, accepted) = tokio::join!(
+            SoupBinTcpClient::connect_with_events(config, tx, Box::new(RawParser), event_tx),
+            listener.accept()
+        );
+        let mut client = client.unwrap();
+        let mut server = accepted.unwrap().0;
+
+        // socket up, but not authenticated yet
+        assert_eq!(
+            event_rx
+                .try_iter()
+                .map(|(_, event)| event)
+                .collect::<Vec<_>>(),
+            [ConnectionEvent::Connected]
+        );
+
+        let mut login = [0u8; 49];
+        server.read_exact(&mut login).await.unwrap();
+
+        let mut accepted = vec![0x00, 31, b'A'];
+        accepted.extend_from_slice(b"SESS1     ");
+        accepted.extend_from_slice(b"                   5");
+        server.write_all(&accepted).await.unwrap();
+
+        let deadline = std::time::Instant::now() + std::time::Duration::from_millis(100);
+        client.pump_until(deadline).await.unwrap();
+
+        assert_eq!(
+            event_rx
+                .try_iter()
+                .map(|(_, event)| event)
+                .collect::<Vec<_>>(),
+            [ConnectionEvent::LoggedIn {
+                session: "SESS1".to_string(),
+                start_sequence: Sequence(5),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reconnecting_events_report_attempt_and_backoff() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let config = test_config(&listener);
+        let (tx, _rx) = unbounded();
+        let (event_tx, event_rx) = unbounded();
+
+        let (client, accepted) = tokio::join!(
+            SoupBinTcpClient::connect_with_retry_config(
+                config,
+                tx,
+                Box::new(RawParser),
+                Some(event_tx),
+                3,
+                1,
+            ),
+            listener.accept()
+        );
+        let mut client = client.unwrap();
+        client.set_backoff_strategy(exact_backoff(1));
+        drop(accepted.unwrap());
+        // nothing listening any more, so every attempt is refused
+        drop(listener);
+
+        for _ in 0..3 {
+            assert!(client.try_reconnect().await.is_err());
+        }
+        let err = client.try_reconnect().await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::ConnectionAborted);
+
+        let events: Vec<_> = event_rx.try_iter().map(|(_, event)| event).collect();
+        assert_eq!(
+            events,
+            [
+                ConnectionEvent::Connected,
+                ConnectionEvent::Reconnecting {
+                    attempt: 1,
+                    delay_ms: 1
+                },
+                ConnectionEvent::Reconnecting {
+                    attempt: 2,
+                    delay_ms: 2
+                },
+                ConnectionEvent::Reconnecting {
+                    attempt: 3,
+                    delay_ms: 4
+                },
+                ConnectionEvent::Reconnecting {
+                    attempt: 4,
+                    delay_ms: 8
+                },
+                ConnectionEvent::Disconnected {
+                    reason: Some("Max reconnection attempts (3) exceeded".to_string())
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_constant_backoff_uses_equal_delays() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let config = test_config(&listener);
+        let (tx, _rx) = unbounded();
+        let (event_tx, event_rx) = unbounded();
+
+        let (client, accepted) = tokio::join!(
+            SoupBinTcpClient::connect_with_events(config, tx, Box::new(RawParser), event_tx),
+            listener.accept()
+        );
+        let mut client = client.unwrap();
+        client.set_backoff_strategy(Box::new(ConstantBackoff(std::time::Duration::from_millis(
+            3,
+        ))));
+        drop(accepted.unwrap());
+        drop(listener);
+
+        for _ in 0..3 {
+            assert!(client.try_reconnect().await.is_err());
+        }
+
+        let delays: Vec<_> = event_rx
+            .try_iter()
+            .filter_map(|(_, event)| match event {
+                ConnectionEvent::Reconnecting { delay_ms, .. } => Some(delay_ms),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(delays, [3, 3, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_default_backoff_is_jittered() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let config = test_config(&listener);
+        let (tx, _rx) = unbounded();
+        let (event_tx, event_rx) = unbounded();
+
+        let (client, accepted) = tokio::join!(
+            SoupBinTcpClient::connect_with_retry_config(
+                config,
+                tx,
+                Box::new(RawParser),
+                Some(event_tx),
+                20,
+                100,
+            ),
+            listener.accept()
+        );
+        let mut client = client.unwrap();
+        drop(accepted.unwrap());
+        drop(listener);
+
+        // the first delay is 100ms shortened by up to RECONNECT_JITTER
+        let delays: Vec<_> = (0..20)
+            .map(|_| client.backoff.next_delay(1).as_millis() as u64)
+            .collect();
+        assert!(delays.iter().all(|d| (80..=100).contains(d)));
+        assert!(delays.iter().any(|d| *d != delays[0]));
+
+        assert!(client.try_reconnect().await.is_err());
+        let delay = event_rx.try_iter().find_map(|(_, event)| match event {
+            ConnectionEvent::Reconnecting { delay_ms, .. } => Some(delay_ms),
+            _ => None,
+        });
+        assert!(delay.is_some_and(|d| (80..=100).contains(&d)));
+    }
+
+    #[cfg(feature = "file_transport")]
+    #[tokio::test]
+    async fn test_file_replay_matches_live_parsing() {
+        use crate::net::file_transport::FileReplayTransport;
+        use tokio::io::AsyncWriteExt;
+
+        let mut capture = Vec::new();
+        for payload in [&b"first"[..], b"second", b"third"] {
+            capture.extend_from_slice(&sequenced_frame(payload));
+        }
+        capture.extend_from_slice(&[0x00, 0x01, b'H']);
+
+        async fn parse_all(
+            client: &mut SoupBinTcpClient<Vec<u8>>,
+            transport: &mut impl Transport,
+            total: usize,
+        ) -> Vec<(u8, Bytes)> {
+            let mut read = 0;
+            let mut packets = Vec::new();
+            while read < total {
+                let (n, _) = transport.read_bytes(&mut client.read_buf).await.unwrap();
+                assert!(n > 0, "unexpected EOF");
+                read += n;
+                while let Some(packet) = client.try_parse_packet().unwrap() {
+                    packets.push(packet);
+                }
+            }
+            packets
+        }
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+        let (live_stream, accepted) =
+            tokio::join!(NetworkTransport::connect(&addr), listener.accept());
+        let mut live_stream = live_stream.unwrap();
+        accepted.unwrap().0.write_all(&capture).await.unwrap();
+
+        let (mut live_client, _rx, _server) = connected_client().await;
+        let live = parse_all(&mut live_client, &mut live_stream, capture.len()).await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("capture.bin");
+        std::fs::write(&path, &capture).unwrap();
+        let mut replay = FileReplayTransport::open(&path).await.unwrap();
+
+        let (mut replay_client, _rx, _server) = connected_client().await;
+        let replayed = parse_all(&mut replay_client, &mut replay, capture.len()).await;
+
+        assert_eq!(live.len(), 4);
+        assert_eq!(replayed, live);
+        let (n, _) = replay
+            .read_bytes(&mut replay_client.read_buf)
+            .await
+            .unwrap();
+        assert_eq!(n, 0);
+    }
+
+    #[tokio::test]
+    async fn test_manage_read_buffer_counts_shrink_and_grow() {
+        let (mut client, _rx, _server) = connected_client().await;
+
+        client.read_buf = ReadBuffer::with_capacity(MAX_BUFFER_CAPACITY * 2);
+        client.read_buf.extend_from_slice(b"partial");
+
+        client.manage_read_buffer();
+        assert_eq!(client.stats().buffer_shrink_count, 1);
+        assert!(client.read_buf.capacity() <= MAX_BUFFER_CAPACITY);
+        assert_eq!(&client.read_buf[..], b"partial");
+
+        let filler = vec![0u8; client.read_buf.capacity() - client.read_buf.len()];
+        client.read_buf.extend_from_slice(&filler);
+
+        client.manage_read_buffer();
+        assert_eq!(client.stats().buffer_grow_count, 1);
+        assert_eq!(client.stats().buffer_shrink_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_custom_buffer_policy_shrinks_at_configured_threshold() {
+        let policy = BufferPolicy {
+            initial: 1024,
+            max: 16 * 1024,
+            min_spare: 256,
+            shrink: 4096,
+        };
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let config = SoupBinTcpConfig {
+            buffer_policy: policy,
+            ..test_config(&listener)
+        };
+        let (mut client, _rx, _server) = connect_client_with_config(&listener, config).await;
+        assert_eq!(client.read_buf.capacity(), policy.initial);
+
+        // above max but holding more than the shrink threshold: kept as is
+        client.read_buf = ReadBuffer::with_capacity(policy.max * 2);
+        client.read_buf.extend_from_slice(&[0u8; 5000]);
+        client.manage_read_buffer();
+        assert_eq!(client.stats().buffer_shrink_count, 0);
+
+        // below the threshold: shrunk to hold the data plus min_spare, well under the default max
+        client.read_buf.truncate(3000);
+        client.manage_read_buffer();
+        assert_eq!(client.stats().buffer_shrink_count, 1);
+        assert!(client.read_buf.capacity() <= policy.max);
+        assert!(client.read_buf.capacity() - client.read_buf.len() >= policy.min_spare);
+        assert_eq!(client.read_buf.len(), 3000);
+    }
+
+    #[tokio::test]
+    async fn test_pump_until_returns_after_deadline_with_data_pending() {
+        use tokio::io::AsyncWriteExt;
+
+        let (mut client, rx, mut server) = connected_client().await;
+
+        // keep the feed busy so pump_packets alone would never return
+        let feeder = tokio::spawn(async move {
+            let frame = sequenced_frame(b"tick");
+            while server.write_all(&frame).await.is_ok() {
+                tokio::task::yield_now().await;
+            }
+        });
+
+        let budget = std::time::Duration::from_millis(50);
+        let start = std::time::Instant::now();
+        client.pump_until(start + budget).await.unwrap();
+        let elapsed = start.elapsed();
+
+        assert!(elapsed >= budget);
+        assert!(elapsed < budget * 10, "pump_until overran: {elapsed:?}");
+        assert!(rx.try_iter().count() > 0);
+        // everything complete was flushed before returning
+        assert!(client.try_parse_packet().unwrap().is_none());
+
+        feeder.abort();
+    }
+
+    #[tokio::test]
+    async fn test_try_parse_packets_into_extracts_batch() {
+        let (mut client, _rx, _server) = connected_client().await;
+
+        let frames = [
+            sequenced_frame(b"one"),
+            sequenced_frame(b"two"),
+            vec![0x00, 0x01, b'H'],
+        ];
+        for frame in &frames {
+            client.read_buf.extend_from_slice(frame);
+        }
+        // trailing partial frame stays buffered
+        client.read_buf.extend_from_slice(&[0x00, 0x05, b'S', b'x']);
+
+        let mut out = Vec::new();
+        assert_eq!(client.try_parse_packets_into(&mut out, 16).unwrap(), 3);
+        assert_eq!(out.len(), 3);
+        for ((packet_type, bytes), frame) in out.iter().zip(&frames) {
+            assert_eq!(*packet_type, frame[2]);
+            assert_eq!(&bytes[..], &frame[..]);
+        }
+        assert_eq!(&client.read_buf[..], &[0x00, 0x05, b'S', b'x']);
+
+        // max bounds the batch
+        client.read_buf.clear();
+        for frame in &frames {
+            client.read_buf.extend_from_slice(frame);
+        }
+        out.clear();
+        assert_eq!(client.try_parse_packets_into(&mut out, 2).unwrap(), 2);
+        assert_eq!(client.try_parse_packet().unwrap().unwrap().0, b'H');
+    }
+
+    #[tokio::test]
+    async fn test_parsed_packets_share_buffer_and_keep_spare_capacity() {
+        let (mut client, _rx, _server) = connected_client().await;
+        let policy = BufferPolicy::default();
+
+        for round in 0..64u8 {
+            let payload = vec![round; 300];
+            let frame = sequenced_frame(&payload);
+            client.manage_read_buffer();
+            assert!(client.read_buf.capacity() - client.read_buf.len() >= policy.min_spare);
+
+            let spare_start = client.read_buf.as_ptr() as usize + client.read_buf.len();
+            client.read_buf.extend_from_slice(&frame);
+
+            let (packet_type, bytes) = client.try_parse_packet().unwrap().unwrap();
+            assert_eq!(packet_type, b'S');
+            assert_eq!(&bytes[..], &frame[..]);
+            // no copy: the packet points into the read buffer's allocation
+            assert_eq!(bytes.as_ptr() as usize, spare_start);
+            assert!(client.read_buf.is_empty());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_drain_buffered_flushes_complete_frames() {
+        let (mut client, rx, _server) = connected_client().await;
+
+        client
+            .read_buf
+            .extend_from_slice(&sequenced_frame(b"first"));
+        client
+            .read_buf
+            .extend_from_slice(&sequenced_frame(b"second"));
+        // partial frame must stay buffered
+        client.read_buf.extend_from_slice(&[0x00, 0x05, b'S']);
+
+        assert_eq!(client.drain_buffered().await.unwrap(), 2);
+        assert_eq!(client.read_buf.len(), 3);
+
+        let received: Vec<_> = rx.try_iter().collect();
+        assert_eq!(received.len(), 2);
+        assert_eq!(received[0].0, 1);
+        assert_eq!(received[0].2, b"first");
+        assert_eq!(received[1].0, 2);
+        assert_eq!(received[1].2, b"second");
+    }
+
+    #[test]
+    fn test_complete_frame_len_for_each_prefix() {
+        let frame = [0x00, 0x02, b'S', b'x'];
+        assert_eq!(LengthPrefix::U16.complete_frame_len(&frame), Some(4));
+        assert_eq!(LengthPrefix::U16.complete_frame_len(&frame[..3]), None);
+        assert_eq!(LengthPrefix::U16.complete_frame_len(&frame[..1]), None);
+
+        let frame = [0x00, 0x00, 0x00, 0x02, b'S', b'x'];
+        assert_eq!(LengthPrefix::U32.complete_frame_len(&frame), Some(6));
+        assert_eq!(LengthPrefix::U32.complete_frame_len(&frame[..5]), None);
+        assert_eq!(LengthPrefix::U32.complete_frame_len(&frame[..3]), None);
+        // a huge declared length just waits for more data
+        assert_eq!(
+            LengthPrefix::U32.complete_frame_len(&[0xff, 0xff, 0xff, 0xff, b'S']),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_parse_packets_with_u16_length_prefix_skips_zero_length() {
+        let (mut client, _rx, _server) = connected_client().await;
+        client.read_buf.extend_from_slice(&[0x00, 0x00]);
+        client
+            .read_buf
+            .extend_from_slice(&sequenced_frame(b"gamma"));
+
+        let (packet_type, bytes) = client.try_parse_packet().unwrap().unwrap();
+        assert_eq!(packet_type, b'S');
+        assert_eq!(&bytes[..], &sequenced_frame(b"gamma")[..]);
+        assert!(client.try_parse_packet().unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_parse_packets_with_u32_length_prefix() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let config = SoupBinTcpConfig {
+            length_prefix: LengthPrefix::U32,
+            ..test_config(&listener)
+        };
+        let (mut client, rx, _server) = connect_client_with_config(&listener, config).await;
+
+        let frame = |packet_type: u8, payload: &[u8]| {
+            let mut frame = ((payload.len() + 1) as u32).to_be_bytes().to_vec();
+            frame.push(packet_type);
+            frame.extend_from_slice(payload);
+            frame
+        };
+        let frames = [
+            frame(b'S', b"alpha"),
+            frame(b'S', b"beta"),
+            frame(b'H', b""),
+        ];
+        client.read_buf.extend_from_slice(&frames[0]);
+        // zero-length frame is skipped
+        client.read_buf.extend_from_slice(&[0x00, 0x00, 0x00, 0x00]);
+        client.read_buf.extend_from_slice(&frames[1]);
+        client.read_buf.extend_from_slice(&frames[2]);
+        // trailing partial frame stays buffered
+        client
+            .read_buf
+            .extend_from_slice(&[0x00, 0x00, 0x00, 0x05, b'S']);
+
+        let mut out = Vec::new();
+        assert_eq!(client.try_parse_packets_into(&mut out, 16).unwrap(), 3);
+        for ((packet_type, bytes), frame) in out.iter().zip(&frames) {
+            assert_eq!(*packet_type, frame[4]);
+            assert_eq!(&bytes[..], &frame[..]);
+        }
+        assert_eq!(&client.read_buf[..], &[0x00, 0x00, 0x00, 0x05, b'S']);
+
+        client.read_buf.clear();
+        for frame in &frames {
+            client.read_buf.extend_from_slice(frame);
+        }
+        assert_eq!(client.drain_buffered().await.unwrap(), 3);
+        assert!(client.read_buf.is_empty());
+
+        let received: Vec<_> = rx.try_iter().collect();
+        assert_eq!(received.len(), 2);
+        assert_eq!(received[0].2, b"alpha");
+        assert_eq!(received[1].2, b"beta");
+    }
+
+    #[tokio::test]
+    async fn test_raw_tap_receives_exactly_the_bytes_read() {
+        use std::sync::{Arc, Mutex};
+        use tokio::io::AsyncWriteExt;
+
+        let (mut client, rx, mut server) = connected_client().await;
+
+        let tapped = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&tapped);
+        client.set_raw_tap(Some(Box::new(move |bytes: &[u8]| {
+            sink.lock().unwrap().extend_from_slice(bytes)
+        })));
+
+        let mut sent = sequenced_frame(b"first");
+        sent.extend_from_slice(&sequenced_frame(b"second"));
+        // partial frame is still tapped even though it is not parsed yet
+        sent.extend_from_slice(&[0x00, 0x05, b'S']);
+        server.write_all(&sent).await.unwrap();
+
+        let deadline = std::time::Instant::now() + std::time::Duration::from_millis(100);
+        client.pump_until(deadline).await.unwrap();
+
+        assert_eq!(*tapped.lock().unwrap(), sent);
+        assert_eq!(rx.try_iter().count(), 2);
+
+        client.set_raw_tap(None);
+        // completes the partial frame
+        server.write_all(b"tail").await.unwrap();
+        let deadline = std::time::Instant::now() + std::time::Duration::from_millis(100);
+        client.pump_until(deadline).await.unwrap();
+        assert_eq!(tapped.lock().unwrap().len(), sent.len());
+    }
+
+    /// Mock server that reads the login request and answers with `response`, if any.
+    async fn login_server(listener: TcpListener, response: Option<Vec<u8>>) -> TcpStream {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let (mut server, _) = listener.accept().await.unwrap();
+        let mut login = [0u8; 49];
+        server.read_exact(&mut login).await.unwrap();
+        assert_eq!(login[2], b'L');
+        if let Some(response) = response {
+            server.write_all(&response).await.unwrap();
+        }
+        server
+    }
+
+    #[tokio::test]
+    async fn test_connect_and_login_waits_for_login_accepted() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let config = test_config(&listener);
+
+        let mut response = vec![0x00, 31, b'A'];
+        response.extend_from_slice(b"SESS1     ");
+        response.extend_from_slice(b"                   7");
+        // data right behind the login reply is delivered too
+        response.extend_from_slice(&sequenced_frame(b"first"));
+
+        let (tx, rx) = unbounded();
+        let (client, _server) = tokio::join!(
+            SoupBinTcpClient::connect_and_login(
+                config,
+                tx,
+                Box::new(RawParser),
+                std::time::Duration::from_secs(5)
+            ),
+            login_server(listener, Some(response))
+        );
+
+        let client = client.unwrap();
+        assert!(client.logged_in);
+        assert_eq!(client.current_sequence(), Sequence(7));
+        let received: Vec<_> = rx.try_iter().collect();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].0, 7);
+    }
+
+    #[tokio::test]
+    async fn test_connect_and_login_fails_on_login_rejected() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let config = test_config(&listener);
+
+        let (tx, _rx) = unbounded();
+        let (client, _server) = tokio::join!(
+            SoupBinTcpClient::connect_and_login(
+                config,
+                tx,
+                Box::new(RawParser),
+                std::time::Duration::from_secs(5)
+            ),
+            login_server(listener, Some(vec![0x00, 0x02, b'J', b'A']))
+        );
+
+        let err = client.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+        let inner = err
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<ProtocolError>())
+            .expect("protocol error");
+        assert_eq!(
+            *inner,
+            ProtocolError::LoginRejected {
+                reason: LoginRejectReason::NotAuthorized
+            }
+        );
+        assert_eq!(err.to_string(), "login rejected: not authorized");
+    }
+
+    #[tokio::test]
+    async fn test_connect_and_login_times_out_without_response() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let config = test_config(&listener);
+
+        let (tx, _rx) = unbounded();
+        let (client, _server) = tokio::join!(
+            SoupBinTcpClient::connect_and_login(
+                config,
+                tx,
+                Box::new(RawParser),
+                std::time::Duration::from_millis(100)
+            ),
+            login_server(listener, None)
+        );
+
+        assert_eq!(client.unwrap_err().kind(), io::ErrorKind::TimedOut);
+    }
+
+    async fn client_with_channel(
+        capacity: usize,
+    ) -> (
+        SoupBinTcpClient<Vec<u8>>,
+        Receiver<PacketData<Vec<u8>>>,
+        TcpStream,
+    ) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let (tx, rx) = crossbeam_channel::bounded(capacity);
+        let (client, accepted) = tokio::join!(
+            SoupBinTcpClient::connect(test_config(&listener), tx, Box::new(RawParser)),
+            listener.accept()
+        );
+        (client.unwrap(), rx, accepted.unwrap().0)
+    }
+
+    fn buffer_frames(client: &mut SoupBinTcpClient<Vec<u8>>, payloads: &[&[u8]]) {
+        for payload in payloads {
+            client.read_buf.extend_from_slice(&sequenced_frame(payload));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_delivery_policy_drop_newest_on_full_channel() {
+        let (mut client, rx, _server) = client_with_channel(2).await;
+        client.set_delivery_policy(DeliveryPolicy::DropNewest);
+
+        buffer_frames(&mut client, &[b"one", b"two", b"three"]);
+        assert_eq!(client.drain_buffered().await.unwrap(), 3);
+
+        let received: Vec<_> = rx.try_iter().map(|p| (p.0, p.2)).collect();
+        assert_eq!(received, [(1, b"one".to_vec()), (2, b"two".to_vec())]);
+        assert_eq!(client.stats().dropped_packets, 1);
+        // the sequence still advances past the dropped packet
+        assert_eq!(client.current_sequence(), Sequence(3));
+    }
+
+    #[tokio::test]
+    async fn test_delivery_policy_drop_oldest_on_full_channel() {
+        let (mut client, rx, _server) = client_with_channel(2).await;
+        client.set_delivery_policy(DeliveryPolicy::DropOldest(rx.clone()));
+
+        buffer_frames(&mut client, &[b"one", b"two", b"three", b"four"]);
+        assert_eq!(client.drain_buffered().await.unwrap(), 4);
+
+        let received: Vec<_> = rx.try_iter().map(|p| (p.0, p.2)).collect();
+        assert_eq!(received, [(3, b"three".to_vec()), (4, b"four".to_vec())]);
+        assert_eq!(client.stats().dropped_packets, 2);
+    }
+
+    #[tokio::test]
+    async fn test_delivery_policy_drop_oldest_on_zero_capacity_channel() {
+        let (mut client, rx, _server) = client_with_channel(0).await;
+        client.set_delivery_policy(DeliveryPolicy::DropOldest(rx.clone()));
+
+        buffer_frames(&mut client, &[b"one", b"two"]);
+        assert_eq!(client.drain_buffered().await.unwrap(), 2);
+
+        assert_eq!(client.stats().dropped_packets, 2);
+        assert_eq!(client.current_sequence(), Sequence(2));
+    }
+
+    #[tokio::test]
+    async fn test_delivery_policy_block_waits_for_consumer() {
+        let (mut client, rx, _server) = client_with_channel(1).await;
+        assert!(matches!(client.delivery_policy, DeliveryPolicy::Block));
+
+        let consumer = std::thread::spawn(move || {
+            std::thread::sleep(std::time::Duration::from_millis(50));
+            rx.iter().take(3).map(|p| p.0).collect::<Vec<_>>()
+        });
+
+        buffer_frames(&mut client, &[b"one", b"two", b"three"]);
+        assert_eq!(client.drain_buffered().await.unwrap(), 3);
+
+        assert_eq!(consumer.join().unwrap(), [1, 2, 3]);
+        assert_eq!(client.stats().dropped_packets, 0);
+    }
+
+    fn mock_config() -> SoupBinTcpConfig {
+        SoupBinTcpConfig {
+            host: "127.0.0.1".to_string(),
+            // nothing listens here, so reconnects are refused straight away
+            port: 1,
+            username: "user".to_string(),
+            password: "pass".to_string(),
+            feed_type: DataFeedType::Itch,
+            start_sequence: Sequence::MIN,
+            start_session: "".to_string(),
+            buffer_policy: BufferPolicy::default(),
+            length_prefix: LengthPrefix::default(),
+            max_packet_len: DEFAULT_MAX_PACKET_LEN,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mock_transport_login_data_heartbeat_exchange() {
+        use crate::net::mock_transport::MockTransport;
+
+        let mut accepted = vec![0x00, 31, b'A'];
+        accepted.extend_from_slice(b"SESS1     ");
+        accepted.extend_from_slice(b"                   1");
+        let transport = MockTransport::new()
+            .with_read(accepted)
+            .with_read(sequenced_frame(b"first"))
+            .with_read(sequenced_frame(b"second"))
+            .with_read(vec![0x00, 0x01, b'H']);
+        let written = transport.written();
+
+        let (tx, rx) = unbounded();
+        let mut client = SoupBinTcpClient::connect_with_transport(
+            mock_config(),
+            Box::new(transport),
+            tx,
+            Box::new(RawParser),
+        )
+        .await
+        .unwrap();
+
+        {
+            let written = written.lock().unwrap();
+            assert_eq!(written.len(), 49);
+            assert_eq!(&written[..3], &[0x00, 47, b'L']);
+        }
+
+        client.pump_packets().await.unwrap();
+        assert!(client.logged_in);
+        assert_eq!(client.current_sequence(), Sequence(2));
+        let payloads: Vec<_> = rx.try_iter().map(|p| p.2).collect();
+        assert_eq!(payloads, [b"first".to_vec(), b"second".to_vec()]);
+
+        // the server heartbeat is answered on the next pump
+        client.pump_packets().await.unwrap();
+        assert_eq!(&written.lock().unwrap()[49..], &[0x00, 0x01, b'R']);
+    }
+
+    #[tokio::test]
+    async fn test_mock_transport_error_triggers_reconnect() {
+        use crate::net::mock_transport::MockTransport;
+
+        let transport = MockTransport::new()
+            .with_read(sequenced_frame(b"first"))
+            .with_error(io::ErrorKind::ConnectionReset);
+
+        let (tx, rx) = unbounded();
+        let mut client = SoupBinTcpClient::connect_with_transport(
+            mock_config(),
+            Box::new(transport),
+            tx,
+            Box::new(RawParser),
+        )
+        .await
+        .unwrap();
+        client.set_backoff_strategy(Box::new(ConstantBackoff(std::time::Duration::ZERO)));
+        client.set_transport_connector(Box::new(|_| {
+            Box::pin(async { Err(io::ErrorKind::ConnectionRefused.into()) })
+        }));
+
+        let err = client.pump_packets().await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::ConnectionRefused);
+        assert_eq!(client.reconnect_attempts, 1);
+        assert_eq!(rx.try_iter().count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_mock_transport_reconnects_through_connector() {
+        use crate::net::mock_transport::MockTransport;
+
+        let transport = MockTransport::new()
+            .with_read(sequenced_frame(b"first"))
+            .with_error(io::ErrorKind::ConnectionReset);
+        let replacement = MockTransport::new().with_read(sequenced_frame(b"second"));
+        let written = replacement.written();
+        let mut replacement = Some(replacement);
+
+        let (tx, rx) = unbounded();
+        let (event_tx, event_rx) = unbounded();
+        let mut client = SoupBinTcpClient::connect_with_transport(
+            mock_config(),
+            Box::new(transport),
+            tx,
+            Box::new(RawParser),
+        )
+        .await
+        .unwrap();
+        client.event_sender = Some(event_tx);
+        client.set_backoff_strategy(Box::new(ConstantBackoff(std::time::Duration::ZERO)));
+        client.set_transport_connector(Box::new(move |addr| {
+            assert_eq!(addr, "127.0.0.1:1");
+            let next = replacement.take();
+            Box::pin(async move {
+                let transport = next.ok_or(io::ErrorKind::ConnectionRefused)?;
+                Ok(Box::new(transport) as Box<dyn Transport>)
+            })
+        }));
+
+        client.pump_packets().await.unwrap();
+
+        let payloads: Vec<_> = rx.try_iter().map(|p| p.2).collect();
+        assert_eq!(payloads, [b"first".to_vec(), b"second".to_vec()]);
+        assert_eq!(client.current_sequence(), Sequence(2));
+        // the new transport logged in again, resuming after sequence 1
+        let login = written.lock().unwrap().clone();
+        assert_eq!(login[2], b'L');
+        assert!(login.ends_with(b"                   2"));
+        assert_eq!(
+            event_rx
+                .try_iter()
+                .map(|(_, event)| event)
+                .collect::<Vec<_>>(),
+            [
+                ConnectionEvent::Reconnecting {
+                    attempt: 1,
+                    delay_ms: 0
+                },
+                ConnectionEvent::Reconnected,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_write_buffered_coalesces_writes() {
+        use crate::net::mock_transport::MockTransport;
+        use std::sync::atomic::Ordering;
+
+        let transport = MockTransport::new();
+        let written = transport.written();
+        let write_calls = transport.write_calls();
+
+        let (tx, _rx) = unbounded();
+        let mut client = SoupBinTcpClient::connect_with_transport(
+            mock_config(),
+            Box::new(transport),
+            tx,
+            Box::new(RawParser),
+        )
+        .await
+        .unwrap();
+        // the login is written straight away
+        assert_eq!(write_calls.load(Ordering::Relaxed), 1);
+
+        let frames: Vec<_> = [&b"one"[..], b"two", b"three"]
+            .into_iter()
+            .map(|data| ClientPacket::UnsequencedData(data).to_bytes())
+            .collect();
+        for frame in &frames {
+            client.write_buffered(frame).await.unwrap();
+        }
+        assert_eq!(write_calls.load(Ordering::Relaxed), 1);
+
+        client.flush_now().await.unwrap();
+        assert_eq!(write_calls.load(Ordering::Relaxed), 2);
+        assert_eq!(&written.lock().unwrap()[49..], frames.concat());
+
+        // nothing buffered, nothing written
+        client.flush_now().await.unwrap();
+        assert_eq!(write_calls.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn test_auto_flush_and_logout_flush_buffered_writes() {
+        use crate::net::mock_transport::MockTransport;
+        use std::sync::atomic::Ordering;
+
+        let transport = MockTransport::new();
+        let written = transport.written();
+        let write_calls = transport.write_calls();
+
+        let (tx, _rx) = unbounded();
+        let mut client = SoupBinTcpClient::connect_with_transport(
+            mock_config(),
+            Box::new(transport),
+            tx,
+            Box::new(RawParser),
+        )
+        .await
+        .unwrap();
+
+        client.set_auto_flush_interval(Some(std::time::Duration::ZERO));
+        client.write_buffered(b"now").await.unwrap();
+        assert_eq!(write_calls.load(Ordering::Relaxed), 2);
+
+        client.set_auto_flush_interval(None);
+        client.write_buffered(b"later").await.unwrap();
+        assert_eq!(write_calls.load(Ordering::Relaxed), 2);
+
+        // buffered bytes go out ahead of the logout request
+        client.close().await.unwrap();
+        assert_eq!(&written.lock().unwrap()[49..], b"nowlater\x00\x01O");
+    }
+
+    #[tokio::test]
+    async fn test_parser_sees_current_sequence() {
+        use crate::net::mock_transport::MockTransport;
+
+        struct SequenceEcho;
+
+        impl PacketParser<Vec<u8>> for SequenceEcho {
+            fn parse(&self, _bytes: &[u8], context: PacketContext) -> io::Result<Vec<u8>> {
+                Ok(context.sequence.unwrap().to_be_bytes().to_vec())
+            }
+        }
+
+        let transport = MockTransport::new()
+            .with_read(sequenced_frame(b"a"))
+            .with_read(sequenced_frame(b"b"));
+        let (tx, rx) = unbounded();
+        let mut client = SoupBinTcpClient::connect_with_transport(
+            SoupBinTcpConfig {
+                start_sequence: Sequence(41),
+                ..mock_config()
+            },
+            Box::new(transport),
+            tx,
+            Box::new(SequenceEcho),
+        )
+        .await
+        .unwrap();
+
+        client.pump_packets().await.unwrap();
+        let echoed: Vec<_> = rx
+            .try_iter()
+            .map(|p| u64::from_be_bytes(p.2.try_into().unwrap()))
+            .collect();
+        assert_eq!(echoed, [41, 42]);
+    }
+
+    #[tokio::test]
+    async fn test_empty_sequenced_payload_policies() {
+        use crate::net::mock_transport::MockTransport;
+
+        let script = || {
+            MockTransport::new()
+                .with_read(sequenced_frame(b"first"))
+                .with_read(sequenced_frame(b""))
+                .with_read(sequenced_frame(b"third"))
+        };
+
+        let (tx, rx) = unbounded();
+        let mut client = SoupBinTcpClient::connect_with_transport(
+            mock_config(),
+            Box::new(script()),
+            tx,
+            Box::new(RawParser),
+        )
+        .await
+        .unwrap();
+        client.pump_packets().await.unwrap();
+        let received: Vec<_> = rx.try_iter().map(|p| (p.0, p.2)).collect();
+        assert_eq!(received, [(1, b"first".to_vec()), (3, b"third".to_vec())]);
+
+        let (tx, rx) = unbounded();
+        let mut client = SoupBinTcpClient::connect_with_transport(
+            mock_config(),
+            Box::new(script()),
+            tx,
+            Box::new(RawParser),
+        )
+        .await
+        .unwrap();
+        client.set_empty_payload_policy(EmptyPayloadPolicy::Error);
+        let err = client.pump_packets().await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            err.to_string(),
+            "sequenced data packet 2 has an empty payload"
+        );
+        assert_eq!(rx.try_iter().count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_sequence_gap_emits_event_and_resyncs() {
+        use crate::net::mock_transport::MockTransport;
+
+        // payloads start with their own one-byte sequence number; 3 is skipped
+        let transport = MockTransport::new()
+            .with_read(sequenced_frame(&[1, b'a']))
+            .with_read(sequenced_frame(&[2, b'b']))
+            .with_read(sequenced_frame(&[4, b'd']))
+            .with_read(sequenced_frame(&[5, b'e']));
+
+        let (tx, rx) = unbounded();
+        let (event_tx, event_rx) = unbounded();
+        let mut client = SoupBinTcpClient::connect_with_transport(
+            mock_config(),
+            Box::new(transport),
+            tx,
+            Box::new(RawParser),
+        )
+        .await
+        .unwrap();
+        client.event_sender = Some(event_tx);
+        client.set_sequence_extractor(Some(Box::new(|payload| {
+            payload.first().map(|&seq| seq as u64)
+        })));
+
+        client.pump_packets().await.unwrap();
+
+        assert_eq!(
+            event_rx
+                .try_iter()
+                .map(|(_, event)| event)
+                .collect::<Vec<_>>(),
+            [ConnectionEvent::SequenceGap {
+                expected: Sequence(3),
+                found: Sequence(4),
+            }]
+        );
+        assert_eq!(client.stats().sequence_gaps, 1);
+        assert_eq!(client.current_sequence(), Sequence(5));
+        let sequences: Vec<_> = rx.try_iter().map(|p| p.0).collect();
+        assert_eq!(sequences, [1, 2, 4, 5]);
+    }
+
+    #[tokio::test]
+    async fn test_earlier_payload_sequence_is_a_duplicate() {
+        use crate::net::mock_transport::MockTransport;
+
+        // 2 is repeated and replayed later; neither is a gap nor moves the sequence back
+        let transport = MockTransport::new()
+            .with_read(sequenced_frame(&[1, b'a']))
+            .with_read(sequenced_frame(&[2, b'b']))
+            .with_read(sequenced_frame(&[2, b'b']))
+            .with_read(sequenced_frame(&[3, b'c']))
+            .with_read(sequenced_frame(&[2, b'b']))
+            .with_read(sequenced_frame(&[4, b'd']));
+
+        let (tx, rx) = unbounded();
+        let (event_tx, event_rx) = unbounded();
+        let mut client = SoupBinTcpClient::connect_with_transport(
+            mock_config(),
+            Box::new(transport),
+            tx,
+            Box::new(RawParser),
+        )
+        .await
+        .unwrap();
+        client.event_sender = Some(event_tx);
+        client.set_sequence_extractor(Some(Box::new(|payload| {
+            payload.first().map(|&seq| seq as u64)
+        })));
+
+        client.pump_packets().await.unwrap();
+
+        assert_eq!(event_rx.try_iter().count(), 0);
+        assert_eq!(client.stats().sequence_gaps, 0);
+        assert_eq!(client.stats().duplicate_packets, 2);
+        assert_eq!(client.current_sequence(), Sequence(4));
+        let sequences: Vec<_> = rx.try_iter().map(|p| p.0).collect();
+        assert_eq!(sequences, [1, 2, 3, 4]);
+    }
+
+    #[tokio::test]
+    async fn test_payload_sequence_restart_resyncs() {
+        use crate::net::mock_transport::MockTransport;
+
+        // the venue restarts its payload sequences at 1 after 3
+        let transport = MockTransport::new()
+            .with_read(sequenced_frame(&[1, b'a']))
+            .with_read(sequenced_frame(&[2, b'b']))
+            .with_read(sequenced_frame(&[3, b'c']))
+            .with_read(sequenced_frame(&[1, b'x']))
+            .with_read(sequenced_frame(&[2, b'y']));
+
+        let (tx, rx) = unbounded();
+        let (event_tx, event_rx) = unbounded();
+        let mut client = SoupBinTcpClient::connect_with_transport(
+            mock_config(),
+            Box::new(transport),
+            tx,
+            Box::new(RawParser),
+        )
+        .await
+        .unwrap();
+        client.event_sender = Some(event_tx);
+        client.set_sequence_extractor(Some(Box::new(|payload| {
+            payload.first().map(|&seq| seq as u64)
+        })));
+
+        client.pump_packets().await.unwrap();
+
+        assert_eq!(
+            event_rx
+                .try_iter()
+                .map(|(_, event)| event)
+                .collect::<Vec<_>>(),
+            [ConnectionEvent::SequenceReset {
+                expected: Sequence(4),
+                found: Sequence(1),
+            }]
+        );
+        assert_eq!(client.stats().sequence_resets, 1);
+        assert_eq!(client.stats().duplicate_packets, 0);
+        assert_eq!(client.current_sequence(), Sequence(2));
+        let payloads: Vec<_> = rx.try_iter().map(|p| p.1[4]).collect();
+        assert_eq!(payloads, [b'a', b'b', b'c', b'x', b'y']);
+    }
+
+    #[tokio::test]
+    async fn test_sequence_gap_detection_is_opt_in() {
+        use crate::net::mock_transport::MockTransport;
+
+        let transport = MockTransport::new()
+            .with_read(sequenced_frame(&[1, b'a']))
+            .with_read(sequenced_frame(&[4, b'd']));
+
+        let (tx, rx) = unbounded();
+        let mut client = SoupBinTcpClient::connect_with_transport(
+            mock_config(),
+            Box::new(transport),
+            tx,
+            Box::new(RawParser),
+        )
+        .await
+        .unwrap();
+
+        client.pump_packets().await.unwrap();
+        assert_eq!(client.stats().sequence_gaps, 0);
+        let sequences: Vec<_> = rx.try_iter().map(|p| p.0).collect();
+        assert_eq!(sequences, [1, 2]);
+    }
+
+    #[test]
+    fn test_connection_events_serialize_to_json() {
+        let events = [
+            (ConnectionEvent::Connected, r#"{"type":"Connected"}"#),
+            (
+                ConnectionEvent::LoggedIn {
+                    session: "SESS1".to_string(),
+                    start_sequence: Sequence(7),
+                },
+                r#"{"type":"LoggedIn","session":"SESS1","start_sequence":7}"#,
+            ),
+            (
+                ConnectionEvent::Reconnecting {
+                    attempt: 2,
+                    delay_ms: 500,
+                },
+                r#"{"type":"Reconnecting","attempt":2,"delay_ms":500}"#,
+            ),
+            (ConnectionEvent::Reconnected, r#"{"type":"Reconnected"}"#),
+            (
+                ConnectionEvent::Disconnected {
+                    reason: Some("gone".to_string()),
+                },
+                r#"{"type":"Disconnected","reason":"gone"}"#,
+            ),
+            (
+                ConnectionEvent::SequenceGap {
+                    expected: Sequence(3),
+                    found: Sequence(5),
+                },
+                r#"{"type":"SequenceGap","expected":3,"found":5}"#,
+            ),
+            (
+                ConnectionEvent::SequenceReset {
+                    expected: Sequence(9),
+                    found: Sequence(1),
+                },
+                r#"{"type":"SequenceReset","expected":9,"found":1}"#,
+            ),
+        ];
+
+        for (event, json) in events {
+            assert_eq!(serde_json::to_string(&event).unwrap(), json);
+            assert_eq!(
+                serde_json::from_str::<ConnectionEvent>(json).unwrap(),
+                event
+            );
+        }
+    }
+
+    #[test]
+    fn test_feed_status_round_trips() {
+        let status = FeedStatus {
+            feed: DataFeedType::Itch,
+            event: ConnectionEvent::Reconnected,
+            at: UnixNanoseconds(1_700_000_000_000_000_000),
+        };
+        let json = serde_json::to_string(&status).unwrap();
+        assert_eq!(
+            json,
+            r#"{"feed":"ITCH","event":{"type":"Reconnected"},"at":1700000000000000000}"#
+        );
+        assert_eq!(serde_json::from_str::<FeedStatus>(&json).unwrap(), status);
+
+        assert!(
+            FeedStatus::now(DataFeedType::Mdf, ConnectionEvent::Connected)
+                .at
+                .0
+                > 0
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bogus_packet_length_fails_immediately() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let config = SoupBinTcpConfig {
+            length_prefix: LengthPrefix::U32,
+            max_packet_len: 1024,
+            ..test_config(&listener)
+        };
+        let (mut client, rx, _server) = connect_client_with_config(&listener, config).await;
+
+        let mut good = 6u32.to_be_bytes().to_vec();
+        good.extend_from_slice(b"Sfirst");
+        client.read_buf.extend_from_slice(&good);
+        // a corrupt prefix declaring ~4 GiB, followed by only a few bytes
+        client
+            .read_buf
+            .extend_from_slice(&[0xff, 0xff, 0xff, 0xf0, b'S', 1, 2]);
+
+        let err = client.drain_buffered().await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            err.to_string(),
+            "declared packet length 4294967280 exceeds maximum of 1024"
+        );
+        // frames before the corrupt one were still delivered
+        assert_eq!(rx.try_iter().count(), 1);
+
+        let mut out = Vec::new();
+        assert!(client.try_parse_packets_into(&mut out, 16).is_err());
+        assert!(out.is_empty());
+        assert_eq!(client.read_buf.len(), 7);
+    }
+
+    #[tokio::test]
+    async fn test_default_max_accepts_any_u16_frame() {
+        let (mut client, rx, _server) = connected_client().await;
+
+        // largest frame a u16 prefix can declare
+        let frame = sequenced_frame(&vec![b'x'; u16::MAX as usize - 1]);
+        client.read_buf.extend_from_slice(&frame);
+
+        client.drain_buffered().await.unwrap();
+        assert_eq!(rx.try_iter().count(), 1);
+    }
+}
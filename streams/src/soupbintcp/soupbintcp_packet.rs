@@ -0,0 +1,516 @@
+use super::error::ProtocolError;
+use std::fmt;
+
+/// Server to client SoupBinTCP packet types.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ServerPacket<'a> {
+    Debug(&'a [u8]),
+
+    /// Sent in response to [`ClientPacket::LoginRequest`].
+    LoginAccepted {
+        /// The session ID assigned to the client.
+        /// 10 bytes, space-padded.
+        session: &'a str,
+        /// The start sequence number for the session.
+        /// 20 bytes, space-padded.
+        sequence_number: &'a str,
+    },
+
+    LoginRejected {
+        reason: LoginRejectReason,
+    },
+
+    /// Actual market data payload to parse
+    SequencedData(&'a [u8]),
+
+    /// If client receive this, need to send [`ClientPacket::ClientHeartbeat`]
+    ServerHeartbeat,
+
+    EndOfSession,
+
+    /// Unknown packet type.
+    Unknown {
+        packet_type: u8,
+        payload: &'a [u8],
+    },
+}
+
+/// Reject reason code carried by [`ServerPacket::LoginRejected`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoginRejectReason {
+    /// `'A'`: invalid username or password.
+    NotAuthorized,
+    /// `'S'`: the requested session is not available.
+    SessionNotAvailable,
+    /// Any other code.
+    Unknown(u8),
+}
+
+impl LoginRejectReason {
+    pub const fn from_u8(code: u8) -> Self {
+        match code {
+            b'A' => LoginRejectReason::NotAuthorized,
+            b'S' => LoginRejectReason::SessionNotAvailable,
+            code => LoginRejectReason::Unknown(code),
+        }
+    }
+
+    /// The reason code as sent on the wire.
+    pub const fn as_u8(self) -> u8 {
+        match self {
+            LoginRejectReason::NotAuthorized => b'A',
+            LoginRejectReason::SessionNotAvailable => b'S',
+            LoginRejectReason::Unknown(code) => code,
+        }
+    }
+}
+
+impl From<u8> for LoginRejectReason {
+    fn from(code: u8) -> Self {
+        Self::from_u8(code)
+    }
+}
+
+impl fmt::Display for LoginRejectReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoginRejectReason::NotAuthorized => f.write_str("not authorized"),
+            LoginRejectReason::SessionNotAvailable => f.write_str("session not available"),
+            LoginRejectReason::Unknown(code) => write!(f, "unknown reason code 0x{code:02x}"),
+        }
+    }
+}
+
+/// Client to server SoupBinTCP packet types.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClientPacket<'a> {
+    LoginRequest {
+        username: &'a str,
+        password: &'a str,
+        /// The session ID requested by the client.
+        session_id: &'a str,
+        /// The start sequence number for the session. Min is 1.
+        sequence_number: &'a str,
+    },
+
+    LogoutRequest,
+    /// Sent in response to [`ServerPacket::ServerHeartbeat`] or periodically by the client
+    ClientHeartbeat,
+    UnsequencedData(&'a [u8]),
+}
+
+impl<'a> ServerPacket<'a> {
+    pub fn parse(packet_type: u8, payload: &'a [u8]) -> Self {
+        match packet_type {
+            b'+' => ServerPacket::Debug(payload),
+            b'A' => {
+                if payload.len() >= 30 {
+                    match (
+                        std::str::from_utf8(&payload[0..10]),
+                        std::str::from_utf8(&payload[10..30]),
+                    ) {
+                        (Ok(session), Ok(sequence_number)) => ServerPacket::LoginAccepted {
+                            session: session.trim(),
+                            sequence_number: sequence_number.trim(),
+                        },
+                        _ => ServerPacket::Unknown {
+                            packet_type,
+                            payload,
+                        },
+                    }
+                } else {
+                    ServerPacket::Unknown {
+                        packet_type,
+                        payload,
+                    }
+                }
+            }
+            b'J' => {
+                if !payload.is_empty() {
+                    ServerPacket::LoginRejected {
+                        reason: LoginRejectReason::from_u8(payload[0]),
+                    }
+                } else {
+                    ServerPacket::Unknown {
+                        packet_type,
+                        payload,
+                    }
+                }
+            }
+            b'S' => ServerPacket::SequencedData(payload),
+            b'H' => ServerPacket::ServerHeartbeat,
+            b'Z' => ServerPacket::EndOfSession,
+            _ => ServerPacket::Unknown {
+                packet_type,
+                payload,
+            },
+        }
+    }
+
+    /// Hex dump of an [`ServerPacket::Unknown`] payload for logging; `None` for other packets.
+    pub fn unknown_payload_hex(&self) -> Option<String> {
+        match self {
+            ServerPacket::Unknown { payload, .. } => Some(data_types::utils::to_hex(payload)),
+            _ => None,
+        }
+    }
+
+    /// Payload bytes shown in hex by [`describe`](Self::describe).
+    pub const DESCRIBE_HEX_BYTES: usize = 16;
+
+    /// One-line summary for logs, e.g. `LoginAccepted session=SESS1 sequence=42`.
+    ///
+    /// Payloads are shown as hex of their first [`DESCRIBE_HEX_BYTES`](Self::DESCRIBE_HEX_BYTES)
+    /// bytes; the output is not meant to be parsed.
+    pub fn describe(&self) -> String {
+        fn hex_prefix(payload: &[u8]) -> String {
+            let shown = &payload[..payload.len().min(ServerPacket::DESCRIBE_HEX_BYTES)];
+            let ellipsis = if shown.len() < payload.len() {
+                ".."
+            } else {
+                ""
+            };
+            format!("{}{ellipsis}", data_types::utils::to_hex(shown))
+        }
+
+        match self {
+            ServerPacket::Debug(text) => format!("Debug {:?}", String::from_utf8_lossy(text)),
+            ServerPacket::LoginAccepted {
+                session,
+                sequence_number,
+            } => format!("LoginAccepted session={session} sequence={sequence_number}"),
+            ServerPacket::LoginRejected { reason } => format!("LoginRejected reason={reason}"),
+            ServerPacket::SequencedData(payload) => format!(
+                "SequencedData len={} data={}",
+                payload.len(),
+                hex_prefix(payload)
+            ),
+            ServerPacket::ServerHeartbeat => "ServerHeartbeat".to_string(),
+            ServerPacket::EndOfSession => "EndOfSession".to_string(),
+            ServerPacket::Unknown {
+                packet_type,
+                payload,
+            } => format!(
+                "Unknown type=0x{packet_type:02x} len={} data={}",
+                payload.len(),
+                hex_prefix(payload)
+            ),
+        }
+    }
+}
+
+impl<'a> ClientPacket<'a> {
+    pub const USERNAME_WIDTH: usize = 6;
+    pub const PASSWORD_WIDTH: usize = 10;
+    pub const SESSION_ID_WIDTH: usize = 10;
+    pub const SEQUENCE_NUMBER_WIDTH: usize = 20;
+
+    /// Largest payload whose packet length still fits the u16 length field.
+    pub const MAX_PAYLOAD_LEN: usize = u16::MAX as usize - 1;
+
+    /// Build a [`ClientPacket::LoginRequest`], rejecting values that would be truncated on the
+    /// wire.
+    pub fn login_request(
+        username: &'a str,
+        password: &'a str,
+        session_id: &'a str,
+        sequence_number: &'a str,
+    ) -> Result<Self, ProtocolError> {
+        let packet = ClientPacket::LoginRequest {
+            username,
+            password,
+            session_id,
+            sequence_number,
+        };
+        packet.validate()?;
+        Ok(packet)
+    }
+
+    /// Check that every field fits its width on the wire.
+    pub fn validate(&self) -> Result<(), ProtocolError> {
+        match self {
+            ClientPacket::LoginRequest {
+                username,
+                password,
+                session_id,
+                sequence_number,
+            } => {
+                Self::check_width("username", username.len(), Self::USERNAME_WIDTH)?;
+                Self::check_width("password", password.len(), Self::PASSWORD_WIDTH)?;
+                Self::check_width("session_id", session_id.len(), Self::SESSION_ID_WIDTH)?;
+                Self::check_width(
+                    "sequence_number",
+                    sequence_number.len(),
+                    Self::SEQUENCE_NUMBER_WIDTH,
+                )
+            }
+            ClientPacket::UnsequencedData(data) => {
+                Self::check_width("payload", data.len(), Self::MAX_PAYLOAD_LEN)
+            }
+            ClientPacket::LogoutRequest | ClientPacket::ClientHeartbeat => Ok(()),
+        }
+    }
+
+    /// Serialize the packet, rejecting fields that [`to_bytes`](Self::to_bytes) would truncate.
+    pub fn try_to_bytes(&self) -> Result<Vec<u8>, ProtocolError> {
+        self.validate()?;
+        Ok(self.to_bytes())
+    }
+
+    /// Serialize the packet.
+    ///
+    /// Over-long fields are truncated to their width in release builds and panic in debug
+    /// builds; prefer [`try_to_bytes`](Self::try_to_bytes) for unvalidated input.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        debug_assert!(
+            self.validate().is_ok(),
+            "client packet field truncated: {:?}",
+            self.validate()
+        );
+
+        match self {
+            ClientPacket::LoginRequest {
+                username,
+                password,
+                session_id,
+                sequence_number,
+            } => {
+                // 2 (len) + 1 (type) + 46 (payload)
+                let mut buf = Vec::with_capacity(49);
+                buf.extend_from_slice(&47u16.to_be_bytes());
+                buf.push(b'L');
+
+                Self::write_padded_left(&mut buf, username.as_bytes(), Self::USERNAME_WIDTH);
+                Self::write_padded_left(&mut buf, password.as_bytes(), Self::PASSWORD_WIDTH);
+                Self::write_padded_left(&mut buf, session_id.as_bytes(), Self::SESSION_ID_WIDTH);
+                Self::write_padded_right(
+                    &mut buf,
+                    sequence_number.as_bytes(),
+                    Self::SEQUENCE_NUMBER_WIDTH,
+                );
+
+                buf
+            }
+            ClientPacket::LogoutRequest => Self::wrap_packet(b'O', &[]),
+            ClientPacket::ClientHeartbeat => Self::wrap_packet(b'R', &[]),
+            ClientPacket::UnsequencedData(data) => Self::wrap_packet(b'U', data),
+        }
+    }
+
+    #[inline]
+    fn check_width(field: &'static str, len: usize, width: usize) -> Result<(), ProtocolError> {
+        if len > width {
+            return Err(ProtocolError::FieldTooLong { field, len, width });
+        }
+        Ok(())
+    }
+
+    fn wrap_packet(packet_type: u8, payload: &[u8]) -> Vec<u8> {
+        // type byte + payload length
+        let packet_len = 1 + payload.len();
+        let mut packet = Vec::with_capacity(2 + packet_len);
+
+        // length field (big-endian u16)
+        packet.extend_from_slice(&(packet_len as u16).to_be_bytes());
+
+        packet.push(packet_type);
+
+        packet.extend_from_slice(payload);
+
+        packet
+    }
+
+    #[inline]
+    fn write_padded_left(buf: &mut Vec<u8>, data: &[u8], width: usize) {
+        let len = data.len().min(width);
+        buf.extend_from_slice(&data[..len]);
+        buf.resize(buf.len() + (width - len), b' ');
+    }
+
+    #[inline]
+    fn write_padded_right(buf: &mut Vec<u8>, data: &[u8], width: usize) {
+        let len = data.len().min(width);
+        buf.resize(buf.len() + (width - len), b' ');
+        buf.extend_from_slice(&data[..len]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_login_reject_reason_codes() {
+        assert_eq!(
+            LoginRejectReason::from_u8(b'A'),
+            LoginRejectReason::NotAuthorized
+        );
+        assert_eq!(
+            LoginRejectReason::from_u8(b'S'),
+            LoginRejectReason::SessionNotAvailable
+        );
+        assert_eq!(
+            LoginRejectReason::from_u8(b'X'),
+            LoginRejectReason::Unknown(b'X')
+        );
+        for code in [b'A', b'S', b'X', 0] {
+            assert_eq!(LoginRejectReason::from(code).as_u8(), code);
+        }
+
+        assert_eq!(
+            LoginRejectReason::NotAuthorized.to_string(),
+            "not authorized"
+        );
+        assert_eq!(
+            LoginRejectReason::SessionNotAvailable.to_string(),
+            "session not available"
+        );
+        assert_eq!(
+            LoginRejectReason::Unknown(b'X').to_string(),
+            "unknown reason code 0x58"
+        );
+
+        assert_eq!(
+            ServerPacket::parse(b'J', b"S"),
+            ServerPacket::LoginRejected {
+                reason: LoginRejectReason::SessionNotAvailable
+            }
+        );
+    }
+
+    #[test]
+    fn test_describe_summarises_packets() {
+        let payload = format!("{:<10}{:>20}", "SESS1", 42);
+        let packet = ServerPacket::parse(b'A', payload.as_bytes());
+        assert_eq!(packet.describe(), "LoginAccepted session=SESS1 sequence=42");
+
+        assert_eq!(
+            ServerPacket::parse(b'J', b"A").describe(),
+            "LoginRejected reason=not authorized"
+        );
+        assert_eq!(
+            ServerPacket::parse(b'S', &[0xab; 20]).describe(),
+            format!("SequencedData len=20 data={}..", "ab".repeat(16))
+        );
+        assert_eq!(
+            ServerPacket::parse(b'?', &[0x01, 0x02]).describe(),
+            "Unknown type=0x3f len=2 data=0102"
+        );
+        assert_eq!(ServerPacket::parse(b'H', &[]).describe(), "ServerHeartbeat");
+    }
+
+    #[test]
+    fn test_unknown_payload_hex() {
+        let packet = ServerPacket::parse(b'?', &[0xde, 0xad, 0x01]);
+        assert_eq!(packet.unknown_payload_hex().as_deref(), Some("dead01"));
+        assert_eq!(ServerPacket::parse(b'H', &[]).unknown_payload_hex(), None);
+    }
+
+    #[test]
+    fn test_login_request_builds_padded_packet() {
+        let packet = ClientPacket::login_request("user", "secret", "", "1").unwrap();
+        let bytes = packet.to_bytes();
+
+        assert_eq!(bytes.len(), 49);
+        assert_eq!(&bytes[..3], &[0, 47, b'L']);
+        assert_eq!(&bytes[3..9], b"user  ");
+        assert_eq!(&bytes[9..19], b"secret    ");
+        assert_eq!(&bytes[19..29], b"          ");
+        assert_eq!(&bytes[29..49], b"                   1");
+    }
+
+    #[test]
+    fn test_login_request_rejects_long_password() {
+        let err = ClientPacket::login_request("user", "much-too-long", "", "1").unwrap_err();
+        assert_eq!(
+            err,
+            ProtocolError::FieldTooLong {
+                field: "password",
+                len: 13,
+                width: 10,
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "password is 13 bytes, exceeds field width of 10"
+        );
+    }
+
+    #[test]
+    fn test_try_to_bytes_rejects_instead_of_truncating() {
+        let packet = ClientPacket::LoginRequest {
+            username: "nine_char",
+            password: "pass",
+            session_id: "",
+            sequence_number: "1",
+        };
+        assert_eq!(
+            packet.try_to_bytes().unwrap_err(),
+            ProtocolError::FieldTooLong {
+                field: "username",
+                len: 9,
+                width: ClientPacket::USERNAME_WIDTH,
+            }
+        );
+
+        let packet = ClientPacket::LoginRequest {
+            username: "user",
+            password: "pass",
+            session_id: "SESSION_ID_11",
+            sequence_number: "1",
+        };
+        assert!(matches!(
+            packet.try_to_bytes(),
+            Err(ProtocolError::FieldTooLong {
+                field: "session_id",
+                ..
+            })
+        ));
+
+        let packet = ClientPacket::LoginRequest {
+            username: "user",
+            password: "pass",
+            session_id: "",
+            sequence_number: "123456789012345678901",
+        };
+        assert!(matches!(
+            packet.try_to_bytes(),
+            Err(ProtocolError::FieldTooLong {
+                field: "sequence_number",
+                ..
+            })
+        ));
+
+        let payload = vec![0u8; ClientPacket::MAX_PAYLOAD_LEN + 1];
+        assert!(matches!(
+            ClientPacket::UnsequencedData(&payload).try_to_bytes(),
+            Err(ProtocolError::FieldTooLong {
+                field: "payload",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_try_to_bytes_matches_to_bytes_when_valid() {
+        let packet = ClientPacket::login_request("user", "pass", "SESS", "42").unwrap();
+        assert_eq!(packet.try_to_bytes().unwrap(), packet.to_bytes());
+        assert_eq!(
+            ClientPacket::ClientHeartbeat.try_to_bytes().unwrap(),
+            b"\x00\x01R"
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "client packet field truncated")]
+    fn test_to_bytes_asserts_on_over_length_field() {
+        let packet = ClientPacket::LoginRequest {
+            username: "nine_char",
+            password: "pass",
+            session_id: "",
+            sequence_number: "1",
+        };
+        let _ = packet.to_bytes();
+    }
+}
@@ -0,0 +1,8 @@
+pub use tokio_util::sync::CancellationToken;
+pub mod error;
+pub use error::{ShutdownError, ShutdownResult, TaskError, TaskErrorKind, TaskResult};
+pub use task_manager::TaskManager;
+pub use tasks::RunnableTask;
+pub mod core_allocator;
+pub mod task_manager;
+pub mod tasks;
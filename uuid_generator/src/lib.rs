@@ -1 +1,350 @@
-pub type Hello = String;
+use serde::{Deserialize, Serialize};
+use std::{
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use uuid::{ContextV7, Timestamp, Uuid};
+use uuid_simd::UuidExt;
+
+pub mod serde_bytes;
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(transparent)]
+pub struct UUID(pub Uuid);
+
+impl Default for UUID {
+    fn default() -> Self {
+        Self::new_v7()
+    }
+}
+
+impl UUID {
+    pub fn new_v4() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    pub fn new_v7() -> Self {
+        Self(Uuid::now_v7())
+    }
+
+    pub fn new_v7_with_timestamp(ts: Timestamp) -> Self {
+        Self(Uuid::new_v7(ts))
+    }
+
+    /// v7 UUID whose random bits come from `rng`, so a seeded RNG gives reproducible IDs.
+    ///
+    /// Meant for tests; production IDs should use [`new_v7`](Self::new_v7) and the thread
+    /// RNG's entropy.
+    #[cfg(feature = "seeded")]
+    pub fn new_v7_seeded(ts: Timestamp, rng: &mut impl rand::RngCore) -> Self {
+        let (secs, nanos) = ts.to_unix();
+        let millis = secs * 1000 + u64::from(nanos / 1_000_000);
+
+        let mut random = [0u8; 10];
+        rng.fill_bytes(&mut random);
+        Self(uuid::Builder::from_unix_timestamp_millis(millis, &random).into_uuid())
+    }
+
+    /// Order by embedded timestamp when both are v7, breaking ties by bytes.
+    ///
+    /// Any other pair, including a v7 against a v4, falls back to the derived byte order, so
+    /// v4 values land wherever their random leading bytes put them among the v7 ones.
+    pub fn cmp_by_time(&self, other: &UUID) -> std::cmp::Ordering {
+        match (self.v7_timestamp(), other.v7_timestamp()) {
+            (Some(a), Some(b)) => a.cmp(&b).then_with(|| self.cmp(other)),
+            _ => self.cmp(other),
+        }
+    }
+
+    fn v7_timestamp(&self) -> Option<(u64, u32)> {
+        if self.0.get_version_num() != 7 {
+            return None;
+        }
+        self.0.get_timestamp().map(|ts| ts.to_unix())
+    }
+
+    pub fn simple(&self) -> String {
+        self.0.format_simple().to_string()
+    }
+
+    pub fn hyphenated(&self) -> String {
+        self.0.format_hyphenated().to_string()
+    }
+}
+
+impl FromStr for UUID {
+    type Err = uuid_simd::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(UUID(Uuid::parse(s.as_bytes())?))
+    }
+}
+
+pub fn batch_uuid_v4(size: usize) -> Vec<UUID> {
+    let mut out = Vec::new();
+    batch_uuid_v4_into(&mut out, size);
+    out
+}
+
+/// Append `size` v4 UUIDs to `out`, reserving exactly that much, so callers can reuse one
+/// buffer across batches.
+pub fn batch_uuid_v4_into(out: &mut Vec<UUID>, size: usize) {
+    out.reserve_exact(size);
+    out.extend((0..size).map(|_| UUID::new_v4()));
+}
+
+pub fn batch_uuid_v7(size: usize) -> Vec<UUID> {
+    let mut out = Vec::new();
+    batch_uuid_v7_into(&mut out, size);
+    out
+}
+
+/// Append `size` v7 UUIDs sharing the current timestamp to `out`, reserving exactly that
+/// much.
+pub fn batch_uuid_v7_into(out: &mut Vec<UUID>, size: usize) {
+    let context = ContextV7::new();
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Time went backwards");
+
+    let secs = now.as_secs();
+    let nanos = now.subsec_nanos();
+
+    let ts = Timestamp::from_unix(&context, secs, nanos);
+
+    out.reserve_exact(size);
+    out.extend((0..size).map(|_| UUID::new_v7_with_timestamp(ts)));
+}
+
+/// Error from the batch parsers.
+#[derive(Debug, thiserror::Error)]
+pub enum BatchParseError {
+    /// The buffer does not hold a whole number of 16-byte UUIDs.
+    #[error("buffer length {len} is not a multiple of 16")]
+    MisalignedLength { len: usize },
+
+    /// The UUID at `index` could not be parsed.
+    #[error("invalid UUID at index {index}: {source}")]
+    InvalidUuid {
+        index: usize,
+        source: uuid_simd::Error,
+    },
+}
+
+/// Parse a buffer of back-to-back 16-byte binary UUIDs, e.g. a column loaded from a file.
+pub fn parse_batch_bytes(buf: &[u8]) -> Result<Vec<UUID>, BatchParseError> {
+    let (chunks, rest) = buf.as_chunks::<16>();
+    if !rest.is_empty() {
+        return Err(BatchParseError::MisalignedLength { len: buf.len() });
+    }
+
+    Ok(chunks
+        .iter()
+        .map(|bytes| UUID(Uuid::from_bytes(*bytes)))
+        .collect())
+}
+
+/// Parse hyphenated UUID strings, failing on the first invalid one.
+pub fn parse_batch_hyphenated(lines: &[&str]) -> Result<Vec<UUID>, BatchParseError> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            Uuid::parse_hyphenated(line.as_bytes())
+                .map(UUID)
+                .map_err(|source| BatchParseError::InvalidUuid { index, source })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_uuid_v4_creation() {
+        let uuid = UUID::new_v4();
+        assert_eq!(uuid.0.get_version_num(), 4, "UUID version should be 4");
+    }
+
+    #[test]
+    fn test_uuid_v7_creation() {
+        let uuid = UUID::new_v7();
+        assert_eq!(uuid.0.get_version_num(), 7, "UUID version should be 7");
+    }
+
+    #[test]
+    fn test_uuid_v7_with_timestamp() {
+        let context = ContextV7::new();
+        let ts = Timestamp::from_unix(&context, 1_700_000_000, 123456789);
+        let uuid = UUID::new_v7_with_timestamp(ts);
+        assert_eq!(uuid.0.get_version_num(), 7);
+    }
+
+    #[test]
+    fn test_uuid_default() {
+        let uuid: UUID = Default::default();
+        assert_eq!(
+            uuid.0.get_version_num(),
+            7,
+            "Default UUID should be version 7"
+        );
+    }
+
+    #[test]
+    fn test_uuid_formatting() {
+        let uuid = UUID::new_v4();
+        let simple = uuid.simple();
+        let hyphenated = uuid.hyphenated();
+
+        assert_eq!(simple.len(), 32, "Simple format should have 32 characters");
+        assert_eq!(
+            hyphenated.len(),
+            36,
+            "Hyphenated format should have 36 characters"
+        );
+    }
+
+    #[test]
+    fn test_uuid_from_str() {
+        let uuid = UUID::new_v4();
+        let s = uuid.hyphenated();
+        let parsed = UUID::from_str(&s).expect("Parsing should succeed");
+        assert_eq!(uuid, parsed, "Parsed UUID should match original");
+    }
+
+    #[test]
+    fn test_batch_uuid_v4_unique() {
+        let uuids = batch_uuid_v4(1000);
+        let set: HashSet<_> = uuids.iter().collect();
+        assert_eq!(
+            set.len(),
+            uuids.len(),
+            "All UUIDs in batch_v4 should be unique"
+        );
+    }
+
+    #[test]
+    fn test_batch_uuid_v7_unique() {
+        let uuids = batch_uuid_v7(1000);
+        let set: HashSet<_> = uuids.iter().collect();
+        assert_eq!(
+            set.len(),
+            uuids.len(),
+            "All UUIDs in batch_v7 should be unique"
+        );
+    }
+
+    #[test]
+    fn test_batch_uuid_into_extends_existing_buffer() {
+        let mut out = batch_uuid_v4(2);
+        out.shrink_to_fit();
+
+        batch_uuid_v4_into(&mut out, 100);
+        assert_eq!(out.len(), 102);
+        assert_eq!(out.capacity(), 102);
+
+        batch_uuid_v7_into(&mut out, 50);
+        assert_eq!(out.len(), 152);
+
+        let set: HashSet<_> = out.iter().collect();
+        assert_eq!(set.len(), out.len());
+        assert!(out[102..].iter().all(|uuid| uuid.0.get_version_num() == 7));
+    }
+
+    #[test]
+    fn test_parse_uuid_versions() {
+        let uuids = [
+            // v4
+            ("df5bb533-99ea-4e39-b35e-919509bce87f", 4),
+            ("DF5BB533-99EA-4E39-B35E-919509BCE87F", 4),
+            ("df5bb53399ea4e39b35e919509bce87f", 4),
+            ("DF5BB53399EA4E39B35E919509BCE87F", 4),
+            // v7
+            ("019a7cd5-4565-7e58-a414-5f49e76c5949", 7),
+            ("019A7CD5-4565-7E58-A414-5F49E76C5949", 7),
+            ("019a7cd545657e58a4145f49e76c5949", 7),
+            ("019A7CD545657E58A4145F49E76C5949", 7),
+        ];
+
+        for &(s, expected_version) in &uuids {
+            let parsed =
+                UUID::from_str(s).unwrap_or_else(|_| panic!("Parsing '{}' should succeed", s));
+            assert_eq!(
+                parsed.0.get_version_num(),
+                expected_version,
+                "UUID '{}' should be version {}",
+                s,
+                expected_version
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_batch_bytes() {
+        let uuids = batch_uuid_v4(3);
+        let buf: Vec<u8> = uuids.iter().flat_map(|uuid| *uuid.0.as_bytes()).collect();
+
+        assert_eq!(parse_batch_bytes(&buf).unwrap(), uuids);
+        assert!(parse_batch_bytes(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_batch_bytes_rejects_misaligned_buffer() {
+        let err = parse_batch_bytes(&[0u8; 33]).unwrap_err();
+        assert!(matches!(err, BatchParseError::MisalignedLength { len: 33 }));
+        assert_eq!(err.to_string(), "buffer length 33 is not a multiple of 16");
+    }
+
+    #[test]
+    fn test_parse_batch_hyphenated() {
+        let uuids = batch_uuid_v7(2);
+        let strings: Vec<_> = uuids.iter().map(UUID::hyphenated).collect();
+        let lines: Vec<&str> = strings.iter().map(String::as_str).collect();
+        assert_eq!(parse_batch_hyphenated(&lines).unwrap(), uuids);
+
+        let err =
+            parse_batch_hyphenated(&[lines[0], "df5bb53399ea4e39b35e919509bce87f"]).unwrap_err();
+        assert!(matches!(err, BatchParseError::InvalidUuid { index: 1, .. }));
+    }
+
+    #[cfg(feature = "seeded")]
+    #[test]
+    fn test_new_v7_seeded_is_reproducible() {
+        use rand::{SeedableRng, rngs::StdRng};
+
+        let ts = Timestamp::from_unix(ContextV7::new(), 1_700_000_000, 123_456_789);
+        let first = UUID::new_v7_seeded(ts, &mut StdRng::seed_from_u64(42));
+        let second = UUID::new_v7_seeded(ts, &mut StdRng::seed_from_u64(42));
+        let other = UUID::new_v7_seeded(ts, &mut StdRng::seed_from_u64(7));
+
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+        assert_eq!(first.0.get_version_num(), 7);
+        assert_eq!(
+            first.0.get_timestamp().unwrap().to_unix(),
+            (1_700_000_000, 123_000_000)
+        );
+    }
+
+    #[test]
+    fn test_cmp_by_time_mixed_versions() {
+        use std::cmp::Ordering;
+
+        let context = ContextV7::new();
+        let early = UUID::new_v7_with_timestamp(Timestamp::from_unix(&context, 1_700_000_000, 0));
+        let late = UUID::new_v7_with_timestamp(Timestamp::from_unix(&context, 1_700_000_001, 0));
+        let v4 = UUID::from_str("df5bb533-99ea-4e39-b35e-919509bce87f").unwrap();
+
+        assert_eq!(early.cmp_by_time(&late), Ordering::Less);
+        assert_eq!(late.cmp_by_time(&early), Ordering::Greater);
+        assert_eq!(early.cmp_by_time(&early), Ordering::Equal);
+        assert_eq!(v4.cmp_by_time(&early), v4.cmp(&early));
+
+        let mut ids = vec![v4, late, early];
+        ids.sort_by(UUID::cmp_by_time);
+        assert_eq!(ids, [early, late, v4]);
+    }
+}
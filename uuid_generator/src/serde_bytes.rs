@@ -0,0 +1,63 @@
+//! Serialize a [`UUID`] as a fixed 16-byte array regardless of format.
+//!
+//! `UUID`'s default serde form is a string in human-readable formats. Use this module for
+//! on-disk formats that must always store exactly 16 bytes:
+//!
+//! ```rust,ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Record {
+//!     #[serde(with = "uuid_generator::serde_bytes")]
+//!     id: UUID,
+//! }
+//! ```
+
+use crate::UUID;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use uuid::Uuid;
+
+pub fn serialize<S: Serializer>(uuid: &UUID, serializer: S) -> Result<S::Ok, S::Error> {
+    uuid.0.as_bytes().serialize(serializer)
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<UUID, D::Error> {
+    <[u8; 16]>::deserialize(deserializer).map(|bytes| UUID(Uuid::from_bytes(bytes)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        #[serde(with = "crate::serde_bytes")]
+        id: UUID,
+    }
+
+    #[test]
+    fn test_bincode_round_trip_is_16_bytes() {
+        let record = Record { id: UUID::new_v7() };
+
+        let encoded = bincode::serialize(&record).unwrap();
+        assert_eq!(encoded.len(), 16);
+        assert_eq!(&encoded[..], record.id.0.as_bytes());
+
+        let decoded: Record = bincode::deserialize(&encoded).unwrap();
+        assert_eq!(decoded, record);
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let id = UUID::new_v4();
+
+        // default form stays a string
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, format!("\"{}\"", id.hyphenated()));
+        assert_eq!(serde_json::from_str::<UUID>(&json).unwrap(), id);
+
+        // with the helper the bytes are used even in JSON
+        let record = Record { id };
+        let json = serde_json::to_string(&record).unwrap();
+        assert!(json.starts_with("{\"id\":["));
+        assert_eq!(serde_json::from_str::<Record>(&json).unwrap(), record);
+    }
+}